@@ -0,0 +1,90 @@
+;; Example rigor plugin: flags every `sleep` identifier in a test file.
+;;
+;; Rather than parse JSON, it scans the input for AST leaves of the form
+;;   "text":"sleep","line":<n>,"column":<n>
+;; and reports each as `no-sleep/sleep-call`. rigor loads .wat directly; a real plugin
+;; would usually be compiled to .wasm from Rust, AssemblyScript or similar.
+
+(module
+  (import "rigor" "report_issue"
+    (func $report (param i32 i32 i32 i32 i32 i32 i32)))
+
+  (memory (export "memory") 1)
+
+  ;; Bump allocator for the input; everything below 1024 is static data
+  (global $heap (mut i32) (i32.const 1024))
+  ;; Read position for $number
+  (global $cursor (mut i32) (i32.const 0))
+
+  (data (i32.const 0) "\"text\":\"sleep\",\"line\":")                      ;; 22 bytes
+  (data (i32.const 32) ",\"column\":")                                    ;; 10 bytes
+  (data (i32.const 64) "sleep-call")                                      ;; 10 bytes
+  (data (i32.const 96) "sleep() slows tests down; use fake timers instead") ;; 49 bytes
+
+  (func (export "rigor_alloc") (param $len i32) (result i32)
+    (local $ptr i32)
+    (local $end i32)
+    (local.set $ptr (global.get $heap))
+    (local.set $end (i32.add (local.get $ptr) (local.get $len)))
+    (block $fits
+      (loop $grow
+        (br_if $fits
+          (i32.le_u (local.get $end) (i32.mul (memory.size) (i32.const 65536))))
+        (if (i32.eq (memory.grow (i32.const 1)) (i32.const -1))
+          (then (unreachable)))
+        (br $grow)))
+    (global.set $heap (local.get $end))
+    (local.get $ptr))
+
+  ;; 1 if the $n bytes at $a and $b are equal
+  (func $equal (param $a i32) (param $b i32) (param $n i32) (result i32)
+    (block $differ
+      (loop $next
+        (if (i32.eqz (local.get $n))
+          (then (return (i32.const 1))))
+        (br_if $differ
+          (i32.ne (i32.load8_u (local.get $a)) (i32.load8_u (local.get $b))))
+        (local.set $a (i32.add (local.get $a) (i32.const 1)))
+        (local.set $b (i32.add (local.get $b) (i32.const 1)))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (br $next)))
+    (i32.const 0))
+
+  ;; Decimal number at $cursor; leaves $cursor after its last digit
+  (func $number (result i32)
+    (local $value i32)
+    (local $digit i32)
+    (block $done
+      (loop $next
+        (local.set $digit
+          (i32.sub (i32.load8_u (global.get $cursor)) (i32.const 48)))
+        (br_if $done (i32.gt_u (local.get $digit) (i32.const 9)))
+        (local.set $value
+          (i32.add (i32.mul (local.get $value) (i32.const 10)) (local.get $digit)))
+        (global.set $cursor (i32.add (global.get $cursor) (i32.const 1)))
+        (br $next)))
+    (local.get $value))
+
+  (func (export "rigor_analyze") (param $ptr i32) (param $len i32) (result i32)
+    (local $last i32)
+    (local $line i32)
+    (local.set $last
+      (i32.sub (i32.add (local.get $ptr) (local.get $len)) (i32.const 22)))
+    (block $done
+      (loop $scan
+        (br_if $done (i32.gt_u (local.get $ptr) (local.get $last)))
+        (if (call $equal (local.get $ptr) (i32.const 0) (i32.const 22))
+          (then
+            (global.set $cursor (i32.add (local.get $ptr) (i32.const 22)))
+            (local.set $line (call $number))
+            (if (call $equal (global.get $cursor) (i32.const 32) (i32.const 10))
+              (then
+                (global.set $cursor (i32.add (global.get $cursor) (i32.const 10)))
+                (call $report
+                  (i32.const 64) (i32.const 10)
+                  (i32.const 96) (i32.const 49)
+                  (local.get $line) (call $number)
+                  (i32.const 1))))))
+        (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+        (br $scan)))
+    (i32.const 0)))
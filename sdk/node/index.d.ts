@@ -104,6 +104,8 @@ export interface AnalysisResult {
   framework: string;
   testType: string;
   sourceFile?: string;
+  /** Workspace package the file belongs to (monorepos only) */
+  package?: string;
 }
 
 /** Options for the analyze function */
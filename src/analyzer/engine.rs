@@ -1,10 +1,17 @@
 //! Analysis engine - orchestrates all rules
 
-use crate::config::{Config, RuleSeverity, SourceMappingMode};
+use crate::config::{Config, EffectiveConfig, RuleSeverity, SourceMappingMode};
+use crate::coverage::CoverageReport;
 use crate::detector::{FrameworkDetector, SourceMapper};
+use crate::mutation::MutationResult;
 use crate::parser::{IgnoreDirectives, SourceFileParser, TestFileParser, TypeScriptParser};
-use crate::{issue_in_test_range, AnalysisResult, Issue, Score, ScoreBreakdown, TestScore};
+use crate::plugin::PluginsRule;
+use crate::{
+    issue_in_test_range, registry, AnalysisResult, Issue, Rule, Score, ScoreBreakdown, TestScore,
+};
 use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tree_sitter::Tree;
@@ -32,15 +39,51 @@ fn scale_no_source_categories(breakdown: &mut ScoreBreakdown) {
 use super::rules::{
     AiSmellsRule, AnalysisRule, AssertionIntentRule, AssertionQualityRule,
     AsyncErrorMishandlingRule, AsyncPatternsRule, BehavioralCompletenessRule,
-    BoundaryConditionsRule, BoundarySpecificityRule, CouplingAnalysisRule, DebugCodeRule,
-    ErrorCoverageRule, ExcessiveSetupRule, FlakyPatternsRule, ImplementationCouplingRule,
-    IncompleteMockVerificationRule, InputVarietyRule, MissingCleanupRule, MockAbuseRule,
-    MutationResistantRule, NamingQualityRule, ReactTestingLibraryRule, RedundantTestRule,
-    ReturnPathCoverageRule, SideEffectVerificationRule, StateVerificationRule, TestComplexityRule,
-    TestIsolationRule, TrivialAssertionRule, TypeAssertionAbuseRule, UnreachableTestCodeRule,
-    VacuousTestRule,
+    BoundaryConditionsRule, BoundarySpecificityRule, CouplingAnalysisRule, CustomRulesRule,
+    DebugCodeRule, ErrorCoverageRule, ExcessiveSetupRule, FlakyPatternsRule,
+    ImplementationCouplingRule, IncompleteMockVerificationRule, InputVarietyRule,
+    MissingCleanupRule, MockAbuseRule, MutationResistantRule, NamingQualityRule,
+    ReactTestingLibraryRule, RedundantTestRule, ReturnPathCoverageRule, SideEffectVerificationRule,
+    StateVerificationRule, SurvivingMutantRule, TestComplexityRule, TestIsolationRule,
+    TrivialAssertionRule, TypeAssertionAbuseRule, UnreachableTestCodeRule, VacuousTestRule,
+    WeightlessAssertionRule,
 };
-use super::ScoreCalculator;
+use super::{ScoreCalculator, ScoringModel};
+
+/// False when every rule `analyzer` reports is configured `off` for the file, so running it
+/// would only produce issues that get dropped
+fn analyzer_enabled(
+    analyzer: &str,
+    config: Option<&Config>,
+    effective: Option<&EffectiveConfig>,
+) -> bool {
+    let (Some(config), Some(effective)) = (config, effective) else {
+        return true;
+    };
+    let mut rules = registry::RULES
+        .iter()
+        .filter(|info| info.analyzer == analyzer)
+        .peekable();
+    rules.peek().is_none()
+        || rules.any(|info| {
+            let severity = effective
+                .rule_severity(info.id)
+                .or_else(|| config.rule_severity(info.id));
+            severity != Some(RuleSeverity::Off)
+        })
+}
+
+/// `rule`'s options in the file's effective config, or its defaults
+fn rule_options<T: DeserializeOwned + Default>(
+    effective: Option<&EffectiveConfig>,
+    rule: Rule,
+) -> T {
+    effective.map_or_else(T::default, |e| e.rule_options(rule.id()))
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
 
 /// Main analysis engine that orchestrates all rules
 pub struct AnalysisEngine {
@@ -48,6 +91,12 @@ pub struct AnalysisEngine {
     analyze_source: bool,
     /// Project root for source mapping
     project_root: Option<PathBuf>,
+    /// Runtime coverage report (from --coverage) for coverage-aware rules
+    coverage: Option<CoverageReport>,
+    /// Imported mutation results (from --mutation-report), keyed by canonical source path
+    mutation_results: HashMap<PathBuf, MutationResult>,
+    /// Assertion mutation results (from --mutate-tests), keyed by canonical test path
+    assertion_results: HashMap<PathBuf, MutationResult>,
 }
 
 impl AnalysisEngine {
@@ -56,6 +105,9 @@ impl AnalysisEngine {
         Self {
             analyze_source: true,
             project_root: None,
+            coverage: None,
+            mutation_results: HashMap::new(),
+            assertion_results: HashMap::new(),
         }
     }
 
@@ -71,6 +123,47 @@ impl AnalysisEngine {
         self
     }
 
+    /// Set a coverage report; error, boundary and return-path rules use the
+    /// mapped source file's uncovered lines/functions to confirm their findings
+    pub fn with_coverage(mut self, report: CoverageReport) -> Self {
+        self.coverage = Some(report);
+        self
+    }
+
+    /// Set mutation results (e.g. an imported Stryker report); undetected mutants in a
+    /// test file's mapped source become `surviving-mutant` issues
+    pub fn with_mutation_results(mut self, results: Vec<MutationResult>) -> Self {
+        self.mutation_results = results
+            .into_iter()
+            .map(|r| (canonical(&r.source_path), r))
+            .collect();
+        self
+    }
+
+    /// Set assertion mutation results (from `--mutate-tests`, one per test file); inverted
+    /// assertions the tests still pass with become `weightless-assertion` issues
+    pub fn with_assertion_results(mut self, results: Vec<MutationResult>) -> Self {
+        self.assertion_results = results
+            .into_iter()
+            .map(|r| (canonical(&r.source_path), r))
+            .collect();
+        self
+    }
+
+    /// Path for messages: relative to the project root (or working directory) when inside it
+    fn display_path(&self, path: &Path) -> String {
+        let base = self
+            .project_root
+            .clone()
+            .or_else(|| std::env::current_dir().ok());
+        let path = canonical(path);
+        base.map(|b| canonical(&b))
+            .and_then(|b| path.strip_prefix(b).ok().map(Path::to_path_buf))
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
     /// Apply config to filter and adjust issue severity
     fn apply_config_to_issues(
         &self,
@@ -88,7 +181,7 @@ impl AnalysisEngine {
         let mut out = Vec::with_capacity(issues.len());
         for mut issue in issues {
             let rule_id = issue.rule.to_string();
-            match effective.rules.get(&rule_id) {
+            match effective.rule_severity(&rule_id) {
                 Some(RuleSeverity::Off) => continue,
                 Some(rs) => {
                     if let Some(sev) = rs.to_severity() {
@@ -273,6 +366,15 @@ impl AnalysisEngine {
             stats.function_coverage = Some(source_parser.calculate_coverage(src_tree, source));
         }
 
+        // Coverage only applies when the mapped source is being analyzed
+        let file_coverage = match (&self.coverage, &source_file, source_content_ref) {
+            (Some(report), Some(src_path), Some(_)) => report.get_file_coverage(src_path),
+            _ => None,
+        };
+
+        let effective = config.map(|c| c.effective_for_file(test_path));
+        let effective_ref = effective.as_ref();
+
         let assertion_rule = AssertionQualityRule::new();
         let error_rule =
             if let (Some(ref content), Some(st)) = (source_content_ref, source_tree_ref) {
@@ -282,17 +384,26 @@ impl AnalysisEngine {
             } else {
                 ErrorCoverageRule::new().with_test_type(test_type)
             };
+        let error_rule = match file_coverage {
+            Some(fc) => error_rule.with_coverage(fc),
+            None => error_rule,
+        };
         let boundary_rule =
             if let (Some(ref content), Some(st)) = (source_content_ref, source_tree_ref) {
                 BoundaryConditionsRule::new().with_source(content.to_string(), st.clone())
             } else {
                 BoundaryConditionsRule::new()
             };
+        let boundary_rule = match file_coverage {
+            Some(fc) => boundary_rule.with_coverage(fc),
+            None => boundary_rule,
+        };
         let isolation_rule = TestIsolationRule::new();
         let variety_rule = InputVarietyRule::new();
         let debug_rule = DebugCodeRule::new();
         let flaky_rule = FlakyPatternsRule::new().with_framework(framework);
-        let mock_rule = MockAbuseRule::new();
+        let mock_rule =
+            MockAbuseRule::new().with_options(rule_options(effective_ref, Rule::MockAbuse));
         let naming_rule = NamingQualityRule::new();
         let async_rule = AsyncPatternsRule::new();
         let rtl_rule = ReactTestingLibraryRule::new();
@@ -307,6 +418,10 @@ impl AnalysisEngine {
             } else {
                 ReturnPathCoverageRule::new()
             };
+        let return_path_rule = match file_coverage {
+            Some(fc) => return_path_rule.with_coverage(fc),
+            None => return_path_rule,
+        };
         let behavioral_completeness_rule =
             if let (Some(ref content), Some(st)) = (source_content_ref, source_tree_ref) {
                 BehavioralCompletenessRule::new().with_source(content.to_string(), st.clone())
@@ -320,48 +435,96 @@ impl AnalysisEngine {
                 SideEffectVerificationRule::new()
             };
         let ai_smells_rule = AiSmellsRule::new().with_test_type(test_type);
-        let test_complexity_rule = TestComplexityRule::new();
+        let test_complexity_rule = TestComplexityRule::new()
+            .with_options(rule_options(effective_ref, Rule::TestComplexity));
         let vacuous_test_rule = VacuousTestRule::new();
         let incomplete_mock_rule = IncompleteMockVerificationRule::new();
         let async_error_rule = AsyncErrorMishandlingRule::new();
-        let excessive_setup_rule = ExcessiveSetupRule::new();
+        let excessive_setup_rule = ExcessiveSetupRule::new()
+            .with_options(rule_options(effective_ref, Rule::ExcessiveSetup));
         let implementation_coupling_rule = ImplementationCouplingRule::new();
-        let redundant_test_rule = RedundantTestRule::new();
+        let redundant_test_rule =
+            RedundantTestRule::new().with_options(rule_options(effective_ref, Rule::RedundantTest));
         let unreachable_test_rule = UnreachableTestCodeRule::new();
         let type_assertion_rule = TypeAssertionAbuseRule::new();
         let missing_cleanup_rule = MissingCleanupRule::new();
+        let surviving_mutant_rule = match source_file
+            .as_ref()
+            .and_then(|p| self.mutation_results.get(&canonical(p)))
+        {
+            Some(result) => SurvivingMutantRule::new()
+                .with_result(result, self.display_path(&result.source_path)),
+            None => SurvivingMutantRule::new(),
+        };
+        let surviving_mutant_rule = match (source_content_ref, source_tree_ref) {
+            (Some(content), Some(st)) => {
+                surviving_mutant_rule.with_source(content.to_string(), st.clone())
+            }
+            _ => surviving_mutant_rule,
+        };
+        let weightless_assertion_rule = match self.assertion_results.get(&canonical(test_path)) {
+            Some(result) => WeightlessAssertionRule::new().with_result(result),
+            None => WeightlessAssertionRule::new(),
+        };
 
+        let custom_rules =
+            CustomRulesRule::new(config.map_or(&[][..], |c| &c.custom_rules), framework);
+        let plugins = config.map_or(&[][..], |c| &c.plugins);
+        let mut plugins_rule = PluginsRule::new(plugins, self.display_path(test_path), framework);
+        if let (false, Some(content), Some(st)) =
+            (plugins.is_empty(), source_content_ref, source_tree_ref)
+        {
+            plugins_rule = plugins_rule.with_source_exports(
+                SourceFileParser::new(content)
+                    .extract_exports(st)
+                    .into_iter()
+                    .map(|export| export.name)
+                    .collect(),
+            );
+        }
+
+        let analyzers: [&dyn AnalysisRule; 34] = [
+            &assertion_rule,
+            &error_rule,
+            &boundary_rule,
+            &isolation_rule,
+            &variety_rule,
+            &debug_rule,
+            &flaky_rule,
+            &mock_rule,
+            &naming_rule,
+            &async_rule,
+            &rtl_rule,
+            &mutation_resistant_rule,
+            &boundary_specificity_rule,
+            &state_verification_rule,
+            &assertion_intent_rule,
+            &trivial_assertion_rule,
+            &return_path_rule,
+            &behavioral_completeness_rule,
+            &side_effect_rule,
+            &ai_smells_rule,
+            &test_complexity_rule,
+            &vacuous_test_rule,
+            &incomplete_mock_rule,
+            &async_error_rule,
+            &excessive_setup_rule,
+            &implementation_coupling_rule,
+            &redundant_test_rule,
+            &unreachable_test_rule,
+            &type_assertion_rule,
+            &missing_cleanup_rule,
+            &surviving_mutant_rule,
+            &weightless_assertion_rule,
+            &custom_rules,
+            &plugins_rule,
+        ];
         let mut issues = Vec::new();
-        issues.extend(assertion_rule.analyze(&tests, source, tree));
-        issues.extend(error_rule.analyze(&tests, source, tree));
-        issues.extend(boundary_rule.analyze(&tests, source, tree));
-        issues.extend(isolation_rule.analyze(&tests, source, tree));
-        issues.extend(variety_rule.analyze(&tests, source, tree));
-        issues.extend(debug_rule.analyze(&tests, source, tree));
-        issues.extend(flaky_rule.analyze(&tests, source, tree));
-        issues.extend(mock_rule.analyze(&tests, source, tree));
-        issues.extend(naming_rule.analyze(&tests, source, tree));
-        issues.extend(async_rule.analyze(&tests, source, tree));
-        issues.extend(rtl_rule.analyze(&tests, source, tree));
-        issues.extend(mutation_resistant_rule.analyze(&tests, source, tree));
-        issues.extend(boundary_specificity_rule.analyze(&tests, source, tree));
-        issues.extend(state_verification_rule.analyze(&tests, source, tree));
-        issues.extend(assertion_intent_rule.analyze(&tests, source, tree));
-        issues.extend(trivial_assertion_rule.analyze(&tests, source, tree));
-        issues.extend(return_path_rule.analyze(&tests, source, tree));
-        issues.extend(behavioral_completeness_rule.analyze(&tests, source, tree));
-        issues.extend(side_effect_rule.analyze(&tests, source, tree));
-        issues.extend(ai_smells_rule.analyze(&tests, source, tree));
-        issues.extend(test_complexity_rule.analyze(&tests, source, tree));
-        issues.extend(vacuous_test_rule.analyze(&tests, source, tree));
-        issues.extend(incomplete_mock_rule.analyze(&tests, source, tree));
-        issues.extend(async_error_rule.analyze(&tests, source, tree));
-        issues.extend(excessive_setup_rule.analyze(&tests, source, tree));
-        issues.extend(implementation_coupling_rule.analyze(&tests, source, tree));
-        issues.extend(redundant_test_rule.analyze(&tests, source, tree));
-        issues.extend(unreachable_test_rule.analyze(&tests, source, tree));
-        issues.extend(type_assertion_rule.analyze(&tests, source, tree));
-        issues.extend(missing_cleanup_rule.analyze(&tests, source, tree));
+        for analyzer in analyzers {
+            if analyzer_enabled(analyzer.name(), config, effective_ref) {
+                issues.extend(analyzer.analyze(&tests, source, tree));
+            }
+        }
 
         if let Some(ref fc) = stats.function_coverage {
             let coupling_rule =
@@ -377,6 +540,12 @@ impl AnalysisEngine {
 
         let issues = self.apply_config_to_issues(issues, config, test_path);
 
+        // Validated when the config was loaded
+        let scoring = config
+            .map(ScoringModel::for_config)
+            .transpose()?
+            .unwrap_or_default();
+
         let mut breakdown = ScoreCalculator::calculate_breakdown(
             &tests,
             &issues,
@@ -386,6 +555,7 @@ impl AnalysisEngine {
             &isolation_rule,
             &variety_rule,
             &ai_smells_rule,
+            &scoring,
         );
 
         // Fix P1.3: "No source = free points"
@@ -394,11 +564,11 @@ impl AnalysisEngine {
             scale_no_source_categories(&mut breakdown);
         }
 
-        let score = ScoreCalculator::calculate_weighted(&breakdown, test_type);
-        let score = ScoreCalculator::apply_issue_penalty(score, &issues);
+        let score = ScoreCalculator::calculate_weighted(&breakdown, test_type, &scoring);
+        let score = ScoreCalculator::apply_issue_penalty(score, &issues, &scoring);
 
         let mut transparent_breakdown = Some(ScoreCalculator::build_transparent_breakdown(
-            &breakdown, &issues, test_type,
+            &breakdown, &issues, test_type, &scoring,
         ));
 
         let test_scores: Vec<TestScore> = tests
@@ -418,6 +588,7 @@ impl AnalysisEngine {
                     &isolation_rule,
                     &variety_rule,
                     &ai_smells_rule,
+                    &scoring,
                 );
 
                 // Apply no-source proportional scaling to per-test breakdown too
@@ -425,8 +596,10 @@ impl AnalysisEngine {
                     scale_no_source_categories(&mut breakdown_t);
                 }
 
-                let score_t = ScoreCalculator::calculate_weighted(&breakdown_t, test_type);
-                let mut score_t = ScoreCalculator::apply_issue_penalty(score_t, &issues_for_test);
+                let score_t =
+                    ScoreCalculator::calculate_weighted(&breakdown_t, test_type, &scoring);
+                let mut score_t =
+                    ScoreCalculator::apply_issue_penalty(score_t, &issues_for_test, &scoring);
 
                 // Fix: A test with zero assertions is essentially worthless.
                 // Cap its score to prevent it from inflating the file average.
@@ -515,6 +688,7 @@ impl AnalysisEngine {
             framework,
             test_type,
             source_file,
+            package: None,
         })
     }
 
@@ -540,6 +714,19 @@ impl AnalysisEngine {
 
     /// Get aggregate stats from multiple results
     pub fn aggregate_stats(results: &[AnalysisResult]) -> AggregateStats {
+        Self::aggregate(&results.iter().collect::<Vec<_>>())
+    }
+
+    /// Aggregate stats for the results assigned to workspace package `package`
+    pub fn package_stats(results: &[AnalysisResult], package: &str) -> AggregateStats {
+        let in_package: Vec<&AnalysisResult> = results
+            .iter()
+            .filter(|r| r.package.as_deref() == Some(package))
+            .collect();
+        Self::aggregate(&in_package)
+    }
+
+    fn aggregate(results: &[&AnalysisResult]) -> AggregateStats {
         if results.is_empty() {
             return AggregateStats::default();
         }
@@ -567,6 +754,7 @@ impl AnalysisEngine {
             average_score: Score::new(avg_score),
             total_tests,
             total_issues,
+            packages: Vec::new(),
         }
     }
 }
@@ -588,6 +776,29 @@ pub struct AggregateStats {
     pub total_tests: usize,
     /// Total number of issues found
     pub total_issues: usize,
+    /// Per-package stats when analyzing a monorepo workspace (empty otherwise)
+    pub packages: Vec<PackageStats>,
+}
+
+/// Aggregate statistics for one workspace package
+#[derive(Debug)]
+pub struct PackageStats {
+    /// Package name
+    pub name: String,
+    /// Package directory relative to the workspace root
+    pub path: PathBuf,
+    /// Stats over the package's files
+    pub stats: AggregateStats,
+    /// Minimum average score for the package, from its config or `--threshold`
+    pub threshold: Option<u8>,
+}
+
+impl PackageStats {
+    /// True unless the package's average score is below its threshold
+    pub fn passed(&self) -> bool {
+        self.threshold
+            .is_none_or(|threshold| self.stats.average_score.value >= threshold)
+    }
 }
 
 impl Default for Score {
@@ -846,7 +1057,7 @@ mod tests {
 
         // Now analyze with config that turns off trivial-assertion
         let mut rules = HashMap::new();
-        rules.insert("trivial-assertion".to_string(), RuleSeverity::Off);
+        rules.insert("trivial-assertion".to_string(), RuleSeverity::Off.into());
         let config = Config {
             rules,
             ..Config::default()
@@ -863,6 +1074,202 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_analyzer_skipped_when_all_its_rules_are_off() {
+        let mut rules = HashMap::new();
+        rules.insert("debug-code".to_string(), RuleSeverity::Off.into());
+        let config = Config {
+            rules,
+            ..Config::default()
+        };
+        let effective = config.effective_for_file(Path::new("a.test.ts"));
+        // debug-code analyzer also reports focused-test
+        assert!(analyzer_enabled(
+            "debug-code",
+            Some(&config),
+            Some(&effective)
+        ));
+
+        let mut config = config;
+        config
+            .rules
+            .insert("focused-test".to_string(), RuleSeverity::Off.into());
+        let effective = config.effective_for_file(Path::new("a.test.ts"));
+        assert!(!analyzer_enabled(
+            "debug-code",
+            Some(&config),
+            Some(&effective)
+        ));
+        assert!(analyzer_enabled(
+            "coupling",
+            Some(&config),
+            Some(&effective)
+        ));
+        assert!(analyzer_enabled("debug-code", None, None));
+    }
+
+    #[test]
+    fn test_custom_rules_flow_through_config_and_ignores() {
+        let file = make_test_file(
+            r#"
+            it('creates users', () => {
+                resetDatabase();
+                // rigor-ignore-next-line engine-test-no-reset-db
+                resetDatabase();
+                expect(createUser()).toEqual({ id: 1 });
+            });
+        "#,
+        );
+        let mut config: Config = serde_json::from_str(
+            r#"{ "customRules": [{
+                "id": "engine-test-no-reset-db",
+                "query": "(call_expression function: (identifier) @fn (#eq? @fn \"resetDatabase\")) @match",
+                "inside": "test",
+                "message": "resetDatabase() inside a test body"
+            }] }"#,
+        )
+        .unwrap();
+        let engine = AnalysisEngine::new().without_source_analysis();
+        let custom: Vec<usize> = engine
+            .analyze(file.path(), Some(&config))
+            .unwrap()
+            .issues
+            .iter()
+            .filter(|i| i.rule.to_string() == "engine-test-no-reset-db")
+            .map(|i| i.location.line)
+            .collect();
+        assert_eq!(custom, vec![3]);
+
+        config.rules.insert(
+            "engine-test-no-reset-db".to_string(),
+            RuleSeverity::Off.into(),
+        );
+        let result = engine.analyze(file.path(), Some(&config)).unwrap();
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| i.rule.to_string() == "engine-test-no-reset-db"));
+    }
+
+    #[test]
+    fn test_assertion_results_flow_through_config_and_ignores() {
+        let content = r#"
+            it('totals', () => {
+                expect(total()).toBe(3);
+                // rigor-ignore-next-line weightless-assertion
+                expect(count()).toBe(1);
+                expect(items()).toHaveLength(2);
+            });
+        "#;
+        let file = make_test_file(content);
+        let runs = crate::mutation::generate_assertion_mutations(file.path(), content)
+            .into_iter()
+            .map(|mutation| crate::mutation::MutationRun {
+                mutation,
+                status: crate::mutation::MutantStatus::Survived,
+                stdout: String::new(),
+                stderr: String::new(),
+                duration: std::time::Duration::ZERO,
+                cached: false,
+            })
+            .collect();
+        let engine = AnalysisEngine::new()
+            .without_source_analysis()
+            .with_assertion_results(vec![MutationResult::from_runs(file.path(), runs)]);
+
+        let weightless = |result: &AnalysisResult| -> Vec<(usize, crate::Severity)> {
+            result
+                .issues
+                .iter()
+                .filter(|i| i.rule == crate::Rule::WeightlessAssertion)
+                .map(|i| (i.location.line, i.severity))
+                .collect()
+        };
+        let baseline = AnalysisEngine::new()
+            .without_source_analysis()
+            .analyze(file.path(), None)
+            .unwrap();
+        let result = engine.analyze(file.path(), None).unwrap();
+        assert_eq!(
+            weightless(&result),
+            vec![(3, crate::Severity::Warning), (6, crate::Severity::Warning)]
+        );
+        assert!(result.score.value < baseline.score.value);
+
+        let mut rules = HashMap::new();
+        rules.insert(
+            "weightless-assertion".to_string(),
+            RuleSeverity::Error.into(),
+        );
+        let config = Config {
+            rules,
+            ..Config::default()
+        };
+        let result = engine.analyze(file.path(), Some(&config)).unwrap();
+        assert_eq!(
+            weightless(&result),
+            vec![(3, crate::Severity::Error), (6, crate::Severity::Error)]
+        );
+
+        let mut rules = HashMap::new();
+        rules.insert("weightless-assertion".to_string(), RuleSeverity::Off.into());
+        let config = Config {
+            rules,
+            ..Config::default()
+        };
+        let result = engine.analyze(file.path(), Some(&config)).unwrap();
+        assert!(weightless(&result).is_empty());
+        assert_eq!(result.score.value, baseline.score.value);
+    }
+
+    #[test]
+    fn test_custom_rule_category_comes_from_the_files_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir(root.join(".git")).unwrap();
+        let test = "it('waits', () => {\n  sleep(10);\n  expect(done()).toBe(true);\n});\n";
+        for (package, category) in [("a", "Test Isolation"), ("b", "Input Variety")] {
+            std::fs::create_dir(root.join(package)).unwrap();
+            std::fs::write(
+                root.join(package).join(".rigorrc.json"),
+                format!(
+                    r#"{{ "customRules": [{{
+                        "id": "engine-test-no-sleep",
+                        "query": "(call_expression function: (identifier) @fn (#eq? @fn \"sleep\")) @match",
+                        "message": "sleep() in a test",
+                        "category": "{}"
+                    }}] }}"#,
+                    category
+                ),
+            )
+            .unwrap();
+            std::fs::write(root.join(package).join("wait.test.ts"), test).unwrap();
+        }
+
+        // Resolving b last leaves its category in the process-wide registry
+        let resolver = crate::config::ConfigResolver::cascading(None);
+        let file_a = root.join("a").join("wait.test.ts");
+        let file_b = root.join("b").join("wait.test.ts");
+        let config_a = resolver.for_file(&file_a).unwrap();
+        let config_b = resolver.for_file(&file_b).unwrap();
+
+        let engine = AnalysisEngine::new().without_source_analysis();
+        let a = engine.analyze(&file_a, Some(&config_a)).unwrap();
+        let b = engine.analyze(&file_b, Some(&config_b)).unwrap();
+        assert!(a.breakdown.test_isolation < b.breakdown.test_isolation);
+        assert!(a.breakdown.input_variety > b.breakdown.input_variety);
+        let custom = |result: &AnalysisResult| {
+            result
+                .transparent_breakdown
+                .as_ref()
+                .unwrap()
+                .custom_categories["engine-test-no-sleep"]
+                .clone()
+        };
+        assert_eq!(custom(&a), "Test Isolation");
+        assert_eq!(custom(&b), "Input Variety");
+    }
+
     #[test]
     fn test_apply_config_override_severity() {
         let file = make_test_file(
@@ -876,7 +1283,7 @@ mod tests {
         );
 
         let mut rules = HashMap::new();
-        rules.insert("trivial-assertion".to_string(), RuleSeverity::Info);
+        rules.insert("trivial-assertion".to_string(), RuleSeverity::Info.into());
         let config = Config {
             rules,
             ..Config::default()
@@ -1120,6 +1527,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_coverage_escalates_unexecuted_throw() {
+        let dir = tempfile::tempdir().unwrap();
+        let src_path = dir.path().join("age.ts");
+        std::fs::write(
+            &src_path,
+            "export function parseAge(input: string): number {\n  const n = Number(input);\n  if (Number.isNaN(n)) {\n    throw new RangeError('not a number');\n  }\n  return n;\n}\n",
+        )
+        .unwrap();
+        let test_path = dir.path().join("age.test.ts");
+        std::fs::write(
+            &test_path,
+            "import { parseAge } from './age';\ndescribe('parseAge', () => {\n  it('parses a number', () => {\n    expect(parseAge('42')).toBe(42);\n  });\n});\n",
+        )
+        .unwrap();
+
+        let engine = AnalysisEngine::new().with_project_root(dir.path().to_path_buf());
+        let without = engine.analyze(&test_path, None).unwrap();
+        let static_issue = without
+            .issues
+            .iter()
+            .find(|i| i.rule == crate::Rule::MissingErrorTest && i.message.contains("parseAge"))
+            .expect("static analysis should flag the untested throw");
+        assert_eq!(static_issue.severity, crate::Severity::Warning);
+
+        let mut file_coverage = crate::coverage::FileCoverage {
+            path: src_path.clone(),
+            ..Default::default()
+        };
+        file_coverage.lines.insert(2, 1);
+        file_coverage.lines.insert(4, 0);
+        let mut report = CoverageReport::default();
+        report.files.insert(src_path, file_coverage);
+
+        let engine = AnalysisEngine::new()
+            .with_project_root(dir.path().to_path_buf())
+            .with_coverage(report);
+        let with = engine.analyze(&test_path, None).unwrap();
+        let confirmed = with
+            .issues
+            .iter()
+            .find(|i| i.rule == crate::Rule::MissingErrorTest && i.message.contains("parseAge"))
+            .unwrap();
+        assert_eq!(confirmed.severity, crate::Severity::Error);
+        assert!(with.breakdown.error_coverage <= without.breakdown.error_coverage);
+    }
+
     /// Verify the scale_no_source_categories helper produces expected values.
     #[test]
     fn test_scale_no_source_categories() {
@@ -5,4 +5,4 @@ pub mod rules;
 pub mod scoring;
 
 pub use engine::AnalysisEngine;
-pub use scoring::ScoreCalculator;
+pub use scoring::{ScoreCalculator, ScoringModel};
@@ -1,6 +1,7 @@
 //! Boundary conditions analysis rule
 
 use super::AnalysisRule;
+use crate::coverage::{FileCoverage, UncoveredCode};
 use crate::parser::SourceFileParser;
 use crate::{Issue, Location, Rule, Severity, TestCase};
 use regex::Regex;
@@ -11,6 +12,8 @@ use tree_sitter::Tree;
 pub struct BoundaryConditionsRule {
     source_content: Option<String>,
     source_tree: Option<Tree>,
+    /// Code the suite never executes (from a coverage report)
+    uncovered: UncoveredCode,
 }
 
 impl BoundaryConditionsRule {
@@ -18,6 +21,7 @@ impl BoundaryConditionsRule {
         Self {
             source_content: None,
             source_tree: None,
+            uncovered: UncoveredCode::default(),
         }
     }
 
@@ -28,6 +32,12 @@ impl BoundaryConditionsRule {
         self
     }
 
+    /// Set runtime coverage for the source file; comparisons it never executes become errors
+    pub fn with_coverage(mut self, coverage: &FileCoverage) -> Self {
+        self.uncovered = UncoveredCode::new(coverage);
+        self
+    }
+
     /// Returns true if `num` appears as a standalone integer in `text`
     /// (not adjacent to another digit on either side).
     fn is_standalone_number(text: &str, num: i64) -> bool {
@@ -215,12 +225,26 @@ impl AnalysisRule for BoundaryConditionsRule {
                         } else {
                             boundary.context.clone()
                         };
+                        let never_executed = self
+                            .uncovered
+                            .contains(boundary.location.line, &boundary.context);
+                        let (severity, coverage_note) = if never_executed {
+                            (
+                                Severity::Error,
+                                format!(
+                                    " (coverage: source line {} is never executed)",
+                                    boundary.location.line
+                                ),
+                            )
+                        } else {
+                            (Severity::Warning, String::new())
+                        };
                         issues.push(Issue {
                             rule: Rule::MissingBoundaryTest,
-                            severity: Severity::Warning,
+                            severity,
                             message: format!(
-                                "Boundary condition '{} {}'{} may not be fully tested",
-                                boundary.operator, value, context
+                                "Boundary condition '{} {}'{} may not be fully tested{}",
+                                boundary.operator, value, context, coverage_note
                             ),
                             location: Location::new(1, 1),
                             suggestion: Some(format!(
@@ -279,10 +303,16 @@ impl AnalysisRule for BoundaryConditionsRule {
         let total_tests = tests.len().max(1);
         let mut score: i32 = 25;
 
+        // Boundaries confirmed unexecuted by coverage count double
         let missing = issues
             .iter()
-            .filter(|i| i.rule == Rule::MissingBoundaryTest && i.severity == Severity::Warning)
-            .count();
+            .filter(|i| i.rule == Rule::MissingBoundaryTest)
+            .map(|i| match i.severity {
+                Severity::Error => 2,
+                Severity::Warning => 1,
+                Severity::Info => 0,
+            })
+            .sum::<usize>();
         let edge_warn = issues.iter().any(|i| {
             i.rule == Rule::MissingBoundaryTest
                 && i.severity == Severity::Info
@@ -536,6 +566,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uncovered_boundary_reported_as_error() {
+        let source_content = r#"
+function canVote(age: number): boolean {
+    return age >= 18;
+}
+
+function isSenior(age: number): boolean {
+    return age >= 65;
+}
+"#;
+        let mut parser = crate::parser::TypeScriptParser::new().unwrap();
+        let source_tree = parser.parse(source_content).unwrap();
+        let mut coverage = FileCoverage::default();
+        coverage.lines.insert(3, 4);
+        coverage.lines.insert(7, 0);
+        coverage.functions.insert("canVote".to_string(), 4);
+        coverage.functions.insert("isSenior".to_string(), 0);
+
+        let rule = BoundaryConditionsRule::new()
+            .with_source(source_content.to_string(), source_tree)
+            .with_coverage(&coverage);
+        let tests = vec![make_test(
+            "votes",
+            vec![make_assertion("expect(canVote(30)).toBe(true)")],
+        )];
+        let tree = parser.parse("test").unwrap();
+        let issues = rule.analyze(&tests, "", &tree);
+
+        let senior = issues.iter().find(|i| i.message.contains(">= 65")).unwrap();
+        assert_eq!(senior.severity, Severity::Error);
+        assert!(senior.message.contains("never executed"));
+
+        let vote = issues.iter().find(|i| i.message.contains(">= 18")).unwrap();
+        assert_eq!(vote.severity, Severity::Warning);
+    }
+
     #[test]
     fn boundary_check_does_not_match_18_inside_180() {
         // 180 must NOT satisfy a >= 18 boundary check
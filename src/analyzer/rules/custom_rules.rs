@@ -0,0 +1,214 @@
+//! Project rules declared in config (`customRules`): each is a tree-sitter query whose
+//! matches become issues, optionally restricted to test bodies or hooks.
+
+use super::AnalysisRule;
+use crate::config::{CustomRule, CustomRuleScope};
+use crate::parser::global_query_cache;
+use crate::{registry, Issue, Location, Rule, TestCase, TestFramework};
+use tree_sitter::{Node, Tree};
+
+const TEST_FUNCTIONS: &[&str] = &["it", "test", "fit", "xit", "xtest", "specify"];
+const HOOK_FUNCTIONS: &[&str] = &[
+    "beforeEach",
+    "afterEach",
+    "beforeAll",
+    "afterAll",
+    "before",
+    "after",
+];
+
+/// Runs the `customRules` that apply to the detected framework
+pub struct CustomRulesRule {
+    rules: Vec<(Rule, CustomRule)>,
+}
+
+impl CustomRulesRule {
+    pub fn new(rules: &[CustomRule], framework: TestFramework) -> Self {
+        let rules = rules
+            .iter()
+            .filter(|r| r.frameworks.is_empty() || r.frameworks.contains(&framework))
+            // Registered when the config loaded; scoring reads the category from the file's config
+            .map(|r| (Rule::Custom(registry::RuleId::new(&r.id)), r.clone()))
+            .collect();
+        Self { rules }
+    }
+}
+
+impl AnalysisRule for CustomRulesRule {
+    fn name(&self) -> &'static str {
+        "custom-rules"
+    }
+
+    fn analyze(&self, _tests: &[TestCase], source: &str, tree: &Tree) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let cache = global_query_cache();
+        for (rule, custom) in &self.rules {
+            let Some(severity) = custom.severity.to_severity() else {
+                continue;
+            };
+            let Ok(matches) = cache.run_query(source, tree, &custom.query) else {
+                continue;
+            };
+            for caps in matches {
+                let Some(cap) = caps
+                    .iter()
+                    .find(|c| c.name == "match")
+                    .or_else(|| caps.first())
+                else {
+                    continue;
+                };
+                let Some(node) = tree
+                    .root_node()
+                    .descendant_for_byte_range(cap.start_byte, cap.end_byte)
+                else {
+                    continue;
+                };
+                let in_scope = match custom.inside {
+                    CustomRuleScope::Anywhere => true,
+                    CustomRuleScope::Test => inside_callback_of(node, source, TEST_FUNCTIONS),
+                    CustomRuleScope::Hook => inside_callback_of(node, source, HOOK_FUNCTIONS),
+                };
+                if !in_scope
+                    || issues.iter().any(|i: &Issue| {
+                        i.rule == *rule
+                            && i.location.line == cap.start_point.0
+                            && i.location.column == cap.start_point.1
+                    })
+                {
+                    continue;
+                }
+                let mut location = Location::new(cap.start_point.0, cap.start_point.1);
+                location.end_line = Some(cap.end_point.0);
+                location.end_column = Some(cap.end_point.1);
+                issues.push(Issue {
+                    rule: *rule,
+                    severity,
+                    message: custom.message.clone(),
+                    location,
+                    suggestion: custom.suggestion.clone(),
+                    fix: None,
+                });
+            }
+        }
+        issues
+    }
+
+    fn calculate_score(&self, _tests: &[TestCase], _issues: &[Issue]) -> u8 {
+        25
+    }
+}
+
+/// True if `node` lies inside a function passed to a call of one of `callees`
+/// (`it(...)`, `it.only(...)`, `it.each(table)(...)` all count as `it`)
+fn inside_callback_of(node: Node, source: &str, callees: &[&str]) -> bool {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        let is_function = matches!(
+            current.kind(),
+            "arrow_function" | "function_expression" | "function"
+        );
+        if is_function && parent.kind() == "arguments" {
+            let call = parent.parent().filter(|c| c.kind() == "call_expression");
+            let callee = call
+                .and_then(|c| c.child_by_field_name("function"))
+                .and_then(|f| callee_root(f, source));
+            if callee.is_some_and(|name| callees.contains(&name)) {
+                return true;
+            }
+        }
+        current = parent;
+    }
+    false
+}
+
+/// Leftmost identifier of a callee: `it` for `it`, `it.only` and `it.each([...])`
+fn callee_root<'a>(node: Node, source: &'a str) -> Option<&'a str> {
+    match node.kind() {
+        "identifier" => source.get(node.byte_range()),
+        "member_expression" => callee_root(node.child_by_field_name("object")?, source),
+        "call_expression" => callee_root(node.child_by_field_name("function")?, source),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSeverity;
+    use crate::parser::TypeScriptParser;
+    use crate::Severity;
+
+    fn custom(id: &str, query: &str, inside: CustomRuleScope) -> CustomRule {
+        CustomRule {
+            id: id.to_string(),
+            query: query.to_string(),
+            inside,
+            frameworks: Vec::new(),
+            message: "Do not reset the database inside a test".to_string(),
+            suggestion: None,
+            severity: RuleSeverity::Error,
+            category: None,
+        }
+    }
+
+    fn analyze(rule: &CustomRulesRule, source: &str) -> Vec<Issue> {
+        let mut parser = TypeScriptParser::new().unwrap();
+        let tree = parser.parse(source).unwrap();
+        rule.analyze(&[], source, &tree)
+    }
+
+    const SOURCE: &str = r#"
+beforeEach(() => { resetDatabase(); });
+it('creates a user', async () => {
+  resetDatabase();
+  expect(await createUser()).toEqual({ id: 1 });
+});
+it.each([1, 2])('reads %d', (n) => { resetDatabase(); });
+"#;
+    const QUERY: &str =
+        r#"(call_expression function: (identifier) @fn (#eq? @fn "resetDatabase")) @match"#;
+
+    #[test]
+    fn test_matches_are_scoped_to_tests_or_hooks() {
+        let in_tests = CustomRulesRule::new(
+            &[custom("custom-test-no-reset", QUERY, CustomRuleScope::Test)],
+            TestFramework::Jest,
+        );
+        let issues = analyze(&in_tests, SOURCE);
+        let lines: Vec<usize> = issues.iter().map(|i| i.location.line).collect();
+        assert_eq!(lines, vec![4, 7]);
+        assert_eq!(issues[0].rule.to_string(), "custom-test-no-reset");
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert_eq!(issues[0].location.column, 3);
+
+        let in_hooks = CustomRulesRule::new(
+            &[custom("custom-test-no-reset", QUERY, CustomRuleScope::Hook)],
+            TestFramework::Jest,
+        );
+        let lines: Vec<usize> = analyze(&in_hooks, SOURCE)
+            .iter()
+            .map(|i| i.location.line)
+            .collect();
+        assert_eq!(lines, vec![2]);
+
+        let anywhere = CustomRulesRule::new(
+            &[custom(
+                "custom-test-no-reset",
+                QUERY,
+                CustomRuleScope::Anywhere,
+            )],
+            TestFramework::Jest,
+        );
+        assert_eq!(analyze(&anywhere, SOURCE).len(), 3);
+    }
+
+    #[test]
+    fn test_framework_filter() {
+        let mut rule = custom("custom-test-vitest-only", QUERY, CustomRuleScope::Anywhere);
+        rule.frameworks = vec![TestFramework::Vitest];
+        let jest = CustomRulesRule::new(std::slice::from_ref(&rule), TestFramework::Jest);
+        assert!(analyze(&jest, SOURCE).is_empty());
+        let vitest = CustomRulesRule::new(&[rule], TestFramework::Vitest);
+        assert_eq!(analyze(&vitest, SOURCE).len(), 3);
+    }
+}
@@ -1,6 +1,7 @@
 //! Error coverage analysis rule
 
 use super::AnalysisRule;
+use crate::coverage::{FileCoverage, UncoveredCode};
 use crate::parser::SourceFileParser;
 use crate::{AssertionKind, Issue, Location, Rule, Severity, TestCase, TestType};
 use tree_sitter::Tree;
@@ -11,6 +12,8 @@ pub struct ErrorCoverageRule {
     source_tree: Option<Tree>,
     /// When E2e, skip per-test "appears to test error handling" heuristic (e2e tests check error display, not exceptions).
     test_type: TestType,
+    /// Code the suite never executes (from a coverage report)
+    uncovered: UncoveredCode,
 }
 
 impl ErrorCoverageRule {
@@ -19,6 +22,7 @@ impl ErrorCoverageRule {
             source_content: None,
             source_tree: None,
             test_type: TestType::Unit,
+            uncovered: UncoveredCode::default(),
         }
     }
 
@@ -35,6 +39,18 @@ impl ErrorCoverageRule {
         self
     }
 
+    /// Set runtime coverage for the source file; throws it never executes become errors
+    pub fn with_coverage(mut self, coverage: &FileCoverage) -> Self {
+        self.uncovered = UncoveredCode::new(coverage);
+        self
+    }
+
+    /// True if coverage data shows the throw is never executed
+    fn throw_never_executed(&self, throwable: &crate::parser::ThrowableFunction) -> bool {
+        self.uncovered
+            .contains(throwable.location.line, &throwable.name)
+    }
+
     fn has_error_test(tests: &[TestCase], fn_name: &str) -> bool {
         let fn_name_lower = fn_name.to_lowercase();
 
@@ -105,12 +121,24 @@ impl AnalysisRule for ErrorCoverageRule {
                         throwable.error_types.join(", ")
                     };
 
+                    let (severity, coverage_note) = if self.throw_never_executed(&throwable) {
+                        (
+                            Severity::Error,
+                            format!(
+                                " (coverage: throw on source line {} is never executed)",
+                                throwable.location.line
+                            ),
+                        )
+                    } else {
+                        (Severity::Warning, String::new())
+                    };
+
                     issues.push(Issue {
                         rule: Rule::MissingErrorTest,
-                        severity: Severity::Warning,
+                        severity,
                         message: format!(
-                            "Function '{}' can throw {} but has no error test",
-                            throwable.name, error_types
+                            "Function '{}' can throw {} but has no error test{}",
+                            throwable.name, error_types, coverage_note
                         ),
                         location: Location::new(1, 1), // Test file location
                         suggestion: Some(format!(
@@ -168,10 +196,16 @@ impl AnalysisRule for ErrorCoverageRule {
         let total_tests = tests.len().max(1);
         let mut score: i32 = 25;
 
+        // Throws confirmed unexecuted by coverage count double
         let missing = issues
             .iter()
-            .filter(|i| i.rule == Rule::MissingErrorTest && i.severity == Severity::Warning)
-            .count();
+            .filter(|i| i.rule == Rule::MissingErrorTest)
+            .map(|i| match i.severity {
+                Severity::Error => 2,
+                Severity::Warning => 1,
+                Severity::Info => 0,
+            })
+            .sum::<usize>();
         let weak_error = issues
             .iter()
             .filter(|i| i.rule == Rule::MissingErrorTest && i.severity == Severity::Info)
@@ -333,6 +367,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uncovered_throw_reported_as_error() {
+        let source_content = r#"
+function parseAge(input: string): number {
+    const n = Number(input);
+    if (Number.isNaN(n)) {
+        throw new RangeError('not a number');
+    }
+    return n;
+}
+
+function parseName(input: string): string {
+    if (!input) {
+        throw new TypeError('empty');
+    }
+    return input;
+}
+"#;
+        let mut parser = crate::parser::TypeScriptParser::new().unwrap();
+        let source_tree = parser.parse(source_content).unwrap();
+        let mut coverage = FileCoverage::default();
+        coverage.lines.insert(5, 0);
+        coverage.lines.insert(12, 3);
+
+        let rule = ErrorCoverageRule::new()
+            .with_source(source_content.to_string(), source_tree)
+            .with_coverage(&coverage);
+        let tests = vec![make_test("parses numbers", vec![])];
+        let tree = parser.parse("test").unwrap();
+        let issues = rule.analyze(&tests, "", &tree);
+
+        let age = issues
+            .iter()
+            .find(|i| i.message.contains("parseAge"))
+            .unwrap();
+        assert_eq!(age.severity, Severity::Error);
+        assert!(age.message.contains("never executed"));
+
+        let name = issues
+            .iter()
+            .find(|i| i.message.contains("parseName"))
+            .unwrap();
+        assert_eq!(
+            name.severity,
+            Severity::Warning,
+            "a throw the suite executes stays a static warning"
+        );
+    }
+
     #[test]
     fn return_path_issues_reduce_error_coverage_score() {
         use crate::{Issue, Location, Rule, Severity};
@@ -3,16 +3,42 @@
 use super::AnalysisRule;
 use crate::parser::{global_query_cache, QueryId, TypeScriptParser};
 use crate::{Issue, Location, Rule, Severity, TestCase};
+use serde::Deserialize;
 use tree_sitter::{Node, Tree};
 
 const SETUP_STATEMENT_THRESHOLD: usize = 15;
 
+/// Options for `excessive-setup`, e.g. `["info", { "maxStatements": 25 }]`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct ExcessiveSetupOptions {
+    /// Statements a `beforeEach`/`beforeAll` callback may have before it is flagged
+    pub max_statements: usize,
+}
+
+impl Default for ExcessiveSetupOptions {
+    fn default() -> Self {
+        Self {
+            max_statements: SETUP_STATEMENT_THRESHOLD,
+        }
+    }
+}
+
 /// Rule for detecting excessive test setup
-pub struct ExcessiveSetupRule;
+pub struct ExcessiveSetupRule {
+    options: ExcessiveSetupOptions,
+}
 
 impl ExcessiveSetupRule {
     pub fn new() -> Self {
-        Self
+        Self {
+            options: ExcessiveSetupOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: ExcessiveSetupOptions) -> Self {
+        self.options = options;
+        self
     }
 
     fn count_statements(node: Node) -> usize {
@@ -85,7 +111,7 @@ impl AnalysisRule for ExcessiveSetupRule {
                 }
                 let callback = children[0];
                 let count = Self::count_statements(callback);
-                if count > SETUP_STATEMENT_THRESHOLD {
+                if count > self.options.max_statements {
                     let (line, col) = call_cap.start_point;
                     issues.push(Issue {
                         rule: Rule::ExcessiveSetup,
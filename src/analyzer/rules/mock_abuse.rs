@@ -4,10 +4,27 @@
 use super::AnalysisRule;
 use crate::parser::{global_query_cache, QueryId, TypeScriptParser};
 use crate::{Issue, Location, Rule, Severity, TestCase};
+use serde::Deserialize;
 use tree_sitter::Tree;
 
 const MOCK_COUNT_WARNING_THRESHOLD: usize = 5;
 
+/// Options for `mock-abuse`, e.g. `["warning", { "maxMocks": 10 }]`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct MockAbuseOptions {
+    /// Module mocks a file may have before it is flagged
+    pub max_mocks: usize,
+}
+
+impl Default for MockAbuseOptions {
+    fn default() -> Self {
+        Self {
+            max_mocks: MOCK_COUNT_WARNING_THRESHOLD,
+        }
+    }
+}
+
 /// Standard library / built-in modules that are suspicious to mock
 const STD_MOCKS: &[&str] = &[
     "Array",
@@ -24,11 +41,20 @@ const STD_MOCKS: &[&str] = &[
 ];
 
 /// Rule for detecting mock abuse
-pub struct MockAbuseRule;
+pub struct MockAbuseRule {
+    options: MockAbuseOptions,
+}
 
 impl MockAbuseRule {
     pub fn new() -> Self {
-        Self
+        Self {
+            options: MockAbuseOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: MockAbuseOptions) -> Self {
+        self.options = options;
+        self
     }
 
     /// Extract first string literal argument from a call node (e.g. jest.mock('foo') -> "foo").
@@ -101,7 +127,7 @@ impl AnalysisRule for MockAbuseRule {
         }
 
         let count = mock_calls.len();
-        if count > MOCK_COUNT_WARNING_THRESHOLD {
+        if count > self.options.max_mocks {
             issues.push(Issue {
                 rule: Rule::MockAbuse,
                 severity: Severity::Warning,
@@ -184,6 +210,20 @@ mod tests {
         assert!(issues.iter().any(|i| i.rule == Rule::MockAbuse));
     }
 
+    #[test]
+    fn negative_max_mocks_option_raises_the_limit() {
+        let rule = MockAbuseRule::new().with_options(MockAbuseOptions { max_mocks: 10 });
+        let source = (0..6)
+            .map(|_| "jest.mock('foo');")
+            .collect::<Vec<_>>()
+            .join("\n");
+        let tree = crate::parser::TypeScriptParser::new()
+            .unwrap()
+            .parse(&source)
+            .unwrap();
+        assert!(rule.analyze(&make_empty_tests(), &source, &tree).is_empty());
+    }
+
     #[test]
     fn positive_detects_std_lib_mock() {
         let rule = MockAbuseRule::new();
@@ -9,6 +9,7 @@ pub mod behavioral_completeness;
 pub mod boundary_conditions;
 pub mod boundary_specificity;
 pub mod coupling;
+pub mod custom_rules;
 pub mod debug_code;
 pub mod error_coverage;
 pub mod excessive_setup;
@@ -25,12 +26,14 @@ pub mod redundant_test;
 pub mod return_path_coverage;
 pub mod side_effect_verification;
 pub mod state_verification;
+pub mod surviving_mutant;
 pub mod test_complexity;
 pub mod test_isolation;
 pub mod trivial_assertion;
 pub mod type_assertion_abuse;
 pub mod unreachable_test_code;
 pub mod vacuous_test;
+pub mod weightless_assertion;
 
 pub use ai_smells::AiSmellsRule;
 pub use assertion_intent::AssertionIntentRule;
@@ -41,28 +44,31 @@ pub use behavioral_completeness::BehavioralCompletenessRule;
 pub use boundary_conditions::BoundaryConditionsRule;
 pub use boundary_specificity::BoundarySpecificityRule;
 pub use coupling::CouplingAnalysisRule;
+pub use custom_rules::CustomRulesRule;
 pub use debug_code::DebugCodeRule;
 pub use error_coverage::ErrorCoverageRule;
-pub use excessive_setup::ExcessiveSetupRule;
+pub use excessive_setup::{ExcessiveSetupOptions, ExcessiveSetupRule};
 pub use flaky_patterns::FlakyPatternsRule;
 pub use implementation_coupling::ImplementationCouplingRule;
 pub use incomplete_mock_verification::IncompleteMockVerificationRule;
 pub use input_variety::InputVarietyRule;
 pub use missing_cleanup::MissingCleanupRule;
-pub use mock_abuse::MockAbuseRule;
+pub use mock_abuse::{MockAbuseOptions, MockAbuseRule};
 pub use mutation_resistant::MutationResistantRule;
 pub use naming_quality::NamingQualityRule;
 pub use react_testing_library::ReactTestingLibraryRule;
-pub use redundant_test::RedundantTestRule;
+pub use redundant_test::{RedundantTestOptions, RedundantTestRule};
 pub use return_path_coverage::ReturnPathCoverageRule;
 pub use side_effect_verification::SideEffectVerificationRule;
 pub use state_verification::StateVerificationRule;
-pub use test_complexity::TestComplexityRule;
+pub use surviving_mutant::SurvivingMutantRule;
+pub use test_complexity::{TestComplexityOptions, TestComplexityRule};
 pub use test_isolation::TestIsolationRule;
 pub use trivial_assertion::TrivialAssertionRule;
 pub use type_assertion_abuse::TypeAssertionAbuseRule;
 pub use unreachable_test_code::UnreachableTestCodeRule;
 pub use vacuous_test::VacuousTestRule;
+pub use weightless_assertion::WeightlessAssertionRule;
 
 use crate::{Issue, TestCase};
 use tree_sitter::Tree;
@@ -10,11 +10,12 @@
 //! `expect(parsePrice('')).toThrow(ParseError)` got the same signature even
 //! though they test completely different error paths.
 //!
-//! A group must have 3+ tests to trigger a flag, since pairs of tests with the
-//! same pattern are common in boundary and error-variant testing.
+//! A group must have 3+ tests (`minGroupSize`) to trigger a flag, since pairs of
+//! tests with the same pattern are common in boundary and error-variant testing.
 
 use super::AnalysisRule;
 use crate::{Issue, Rule, Severity, TestCase};
+use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use tree_sitter::Tree;
 
@@ -22,12 +23,46 @@ use tree_sitter::Tree;
 /// Pairs (2) are common in boundary/error testing; require 3+ to reduce noise.
 const MIN_GROUP_SIZE: usize = 3;
 
+/// Options for `redundant-test`, e.g. `["info", { "minGroupSize": 4 }]`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct RedundantTestOptions {
+    /// Tests sharing an assertion signature before all but the first are flagged (at least 2)
+    #[serde(deserialize_with = "group_size")]
+    pub min_group_size: usize,
+}
+
+impl Default for RedundantTestOptions {
+    fn default() -> Self {
+        Self {
+            min_group_size: MIN_GROUP_SIZE,
+        }
+    }
+}
+
+fn group_size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+    let size = usize::deserialize(deserializer)?;
+    if size < 2 {
+        return Err(serde::de::Error::custom("minGroupSize must be at least 2"));
+    }
+    Ok(size)
+}
+
 /// Rule for detecting redundant tests
-pub struct RedundantTestRule;
+pub struct RedundantTestRule {
+    options: RedundantTestOptions,
+}
 
 impl RedundantTestRule {
     pub fn new() -> Self {
-        Self
+        Self {
+            options: RedundantTestOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: RedundantTestOptions) -> Self {
+        self.options = options;
+        self
     }
 
     /// Build a signature from the full normalized assertion text.
@@ -82,7 +117,7 @@ impl AnalysisRule for RedundantTestRule {
         }
 
         for (_sig, group) in sig_to_tests {
-            if group.len() < MIN_GROUP_SIZE {
+            if group.len() < self.options.min_group_size {
                 continue;
             }
             // Flag all but the first in the group
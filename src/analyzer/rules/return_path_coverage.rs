@@ -4,6 +4,7 @@
 //! only cover a subset (e.g. only positive inputs when function has negative/zero/positive paths).
 
 use super::AnalysisRule;
+use crate::coverage::{FileCoverage, UncoveredCode};
 use crate::parser::SourceFileParser;
 use crate::{Issue, Location, Rule, Severity, TestCase};
 use std::collections::HashSet;
@@ -32,6 +33,8 @@ fn test_location_for_function(tests: &[TestCase], fn_name: &str) -> Location {
 pub struct ReturnPathCoverageRule {
     source_content: Option<String>,
     source_tree: Option<Tree>,
+    /// Code the suite never executes (from a coverage report)
+    uncovered: UncoveredCode,
 }
 
 impl ReturnPathCoverageRule {
@@ -39,6 +42,7 @@ impl ReturnPathCoverageRule {
         Self {
             source_content: None,
             source_tree: None,
+            uncovered: UncoveredCode::default(),
         }
     }
 
@@ -49,6 +53,24 @@ impl ReturnPathCoverageRule {
         self
     }
 
+    /// Set runtime coverage for the source file; return paths it never executes become errors
+    pub fn with_coverage(mut self, coverage: &FileCoverage) -> Self {
+        self.uncovered = UncoveredCode::new(coverage);
+        self
+    }
+
+    /// Source lines of return statements that coverage shows are never executed
+    fn unexecuted_return_lines(&self, func: &crate::parser::FunctionDetails) -> Vec<usize> {
+        let mut lines: Vec<usize> = func
+            .return_statements
+            .iter()
+            .map(|r| r.location.line)
+            .filter(|&line| self.uncovered.contains(line, &func.name))
+            .collect();
+        lines.dedup();
+        lines
+    }
+
     /// Estimate how many return paths are likely covered by tests based on test names and assertion content
     fn estimate_covered_paths(
         &self,
@@ -184,12 +206,25 @@ impl AnalysisRule for ReturnPathCoverageRule {
                 if coverage_ratio < 0.66 {
                     let percent = (coverage_ratio * 100.0) as u32;
                     let location = test_location_for_function(tests, &func.name);
+                    let unexecuted = self.unexecuted_return_lines(&func);
+                    let (severity, coverage_note) = if unexecuted.is_empty() {
+                        (Severity::Warning, String::new())
+                    } else {
+                        let lines: Vec<String> = unexecuted.iter().map(|l| l.to_string()).collect();
+                        (
+                            Severity::Error,
+                            format!(
+                                " (coverage: return on source line(s) {} never executed)",
+                                lines.join(", ")
+                            ),
+                        )
+                    };
                     issues.push(Issue {
                         rule: Rule::ReturnPathCoverage,
-                        severity: Severity::Warning,
+                        severity,
                         message: format!(
-                            "Function '{}' has {} return path(s) but tests likely cover only ~{}% ({} of {} paths)",
-                            func.name, total_paths, percent, estimated_covered, total_paths
+                            "Function '{}' has {} return path(s) but tests likely cover only ~{}% ({} of {} paths){}",
+                            func.name, total_paths, percent, estimated_covered, total_paths, coverage_note
                         ),
                         location,
                         suggestion: Some(Self::path_suggestion(&func)),
@@ -260,6 +295,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unexecuted_return_path_reported_as_error() {
+        let source_content = r#"
+function classify(n: number): number {
+    if (n < 0) return -1;
+    if (n === 0) return 0;
+    return 1;
+}
+"#;
+        let mut parser = crate::parser::TypeScriptParser::new().unwrap();
+        let source_tree = parser.parse(source_content).unwrap();
+        let mut coverage = crate::coverage::FileCoverage::default();
+        coverage.lines.insert(3, 2);
+        coverage.lines.insert(4, 0);
+        coverage.lines.insert(5, 2);
+
+        let rule = ReturnPathCoverageRule::new()
+            .with_source(source_content.to_string(), source_tree)
+            .with_coverage(&coverage);
+        let tests = vec![TestCase {
+            name: "positive case".to_string(),
+            location: Location::new(2, 1),
+            is_async: false,
+            is_skipped: false,
+            assertions: vec![],
+            describe_block: None,
+        }];
+        let test_source = "expect(classify(5)).toBe(1);";
+        let tree = parser.parse(test_source).unwrap();
+        let issues = rule.analyze(&tests, test_source, &tree);
+
+        let issue = issues
+            .iter()
+            .find(|i| i.rule == Rule::ReturnPathCoverage)
+            .unwrap();
+        assert_eq!(issue.severity, Severity::Error);
+        assert!(issue.message.contains("line(s) 4 never executed"));
+    }
+
     #[test]
     fn score_decreases_with_issues() {
         let rule = ReturnPathCoverageRule::new();
@@ -0,0 +1,237 @@
+//! Surviving mutants: mutation results for the mapped source file show behavior the
+//! tests don't pin down.
+//!
+//! With the source available, each mutant is attributed to the first test that calls the
+//! function it sits in, so it counts against that test's score; otherwise the issue is
+//! reported on the file.
+
+use super::AnalysisRule;
+use crate::mutation::{function_name_at, hint_for, MutantStatus, MutationResult};
+use crate::{Issue, Location, Rule, Severity, TestCase};
+use tree_sitter::{Node, Tree};
+
+/// Rule turning undetected mutants of the mapped source into issues
+pub struct SurvivingMutantRule<'a> {
+    result: Option<&'a MutationResult>,
+    /// How the source file is named in messages
+    source_label: String,
+    source_content: Option<String>,
+    source_tree: Option<Tree>,
+}
+
+impl<'a> SurvivingMutantRule<'a> {
+    pub fn new() -> Self {
+        Self {
+            result: None,
+            source_label: String::new(),
+            source_content: None,
+            source_tree: None,
+        }
+    }
+
+    /// Mutation results for the test file's source, and the path to show for it
+    pub fn with_result(mut self, result: &'a MutationResult, source_label: String) -> Self {
+        self.result = Some(result);
+        self.source_label = source_label;
+        self
+    }
+
+    /// The mapped source, used to find the function each mutant sits in
+    pub fn with_source(mut self, content: String, tree: Tree) -> Self {
+        self.source_content = Some(content);
+        self.source_tree = Some(tree);
+        self
+    }
+
+    /// Function containing `offset` in the source, if it has a name
+    fn function_at(&self, offset: usize) -> Option<&str> {
+        let (content, tree) = (self.source_content.as_deref()?, self.source_tree.as_ref()?);
+        function_name_at(tree.root_node(), content, offset)
+    }
+}
+
+/// First call to `name` (`name(...)` or `x.name(...)`) inside a test that runs
+fn first_call_in_tests(
+    tests: &[TestCase],
+    name: &str,
+    source: &str,
+    tree: &Tree,
+) -> Option<Location> {
+    let in_test = |line: usize| {
+        tests.iter().any(|t| {
+            !t.is_skipped
+                && line >= t.location.line
+                && line <= t.location.end_line.unwrap_or(t.location.line)
+        })
+    };
+    find_call(tree.root_node(), name, source, &in_test)
+}
+
+fn find_call(
+    node: Node,
+    name: &str,
+    source: &str,
+    in_test: &dyn Fn(usize) -> bool,
+) -> Option<Location> {
+    if node.kind() == "call_expression" {
+        let callee = node
+            .child_by_field_name("function")
+            .map(|f| f.child_by_field_name("property").unwrap_or(f));
+        let pos = node.start_position();
+        if callee.is_some_and(|c| &source[c.byte_range()] == name) && in_test(pos.row + 1) {
+            return Some(Location::new(pos.row + 1, pos.column + 1));
+        }
+    }
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .find_map(|child| find_call(child, name, source, in_test));
+    found
+}
+
+impl Default for SurvivingMutantRule<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnalysisRule for SurvivingMutantRule<'_> {
+    fn name(&self) -> &'static str {
+        "surviving-mutant"
+    }
+
+    fn analyze(&self, tests: &[TestCase], source: &str, tree: &Tree) -> Vec<Issue> {
+        let Some(result) = self.result else {
+            return Vec::new();
+        };
+
+        result
+            .details
+            .iter()
+            .filter(|run| run.status.is_undetected())
+            .map(|run| {
+                let outcome = if run.status == MutantStatus::NoCoverage {
+                    "is not covered by any test"
+                } else {
+                    "survived"
+                };
+                let function = self.function_at(run.mutation.start);
+                let in_function = function.map(|f| format!(" in {}()", f)).unwrap_or_default();
+                // The mutant lives in the source file: anchor it on the test exercising its
+                // function, else on the file
+                let location = function
+                    .and_then(|f| first_call_in_tests(tests, f, source, tree))
+                    .unwrap_or_else(|| Location::new(1, 1));
+                Issue {
+                    rule: Rule::SurvivingMutant,
+                    severity: Severity::Warning,
+                    message: format!(
+                        "Mutant {}{} at {}:{}:{} {}",
+                        run.mutation.change_summary(),
+                        in_function,
+                        self.source_label,
+                        run.mutation.line,
+                        run.mutation.column,
+                        outcome
+                    ),
+                    location,
+                    suggestion: Some(hint_for(run).to_string()),
+                    fix: None,
+                }
+            })
+            .collect()
+    }
+
+    fn calculate_score(&self, _tests: &[TestCase], issues: &[Issue]) -> u8 {
+        let n = issues
+            .iter()
+            .filter(|i| i.rule == Rule::SurvivingMutant)
+            .count();
+        (25i32 - (n as i32 * 2).min(10)).max(0) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::{Mutation, MutationOperator, MutationRun};
+    use crate::parser::TypeScriptParser;
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn run(status: MutantStatus, line: usize) -> MutationRun {
+        MutationRun {
+            mutation: Mutation {
+                start: 0,
+                end: 2,
+                line,
+                column: 10,
+                original: ">=".to_string(),
+                replacement: ">".to_string(),
+                description: ">= to >".to_string(),
+                operator: MutationOperator::Boundary,
+                scope: 0..0,
+            },
+            status,
+            stdout: String::new(),
+            stderr: String::new(),
+            duration: Duration::ZERO,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn test_undetected_mutants_become_issues() {
+        let result = MutationResult::from_runs(
+            Path::new("/project/src/age.ts"),
+            vec![
+                run(MutantStatus::Killed, 3),
+                run(MutantStatus::Survived, 12),
+                run(MutantStatus::NoCoverage, 20),
+            ],
+        );
+        let source = "it('works', () => { expect(isAdult(20)).toBe(true); });";
+        let tree = TypeScriptParser::new().unwrap().parse(source).unwrap();
+        let rule = SurvivingMutantRule::new().with_result(&result, "src/age.ts".to_string());
+
+        let issues = rule.analyze(&[], source, &tree);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0].message,
+            "Mutant `>=`→`>` at src/age.ts:12:10 survived"
+        );
+        assert!(issues[0]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("boundary"));
+        assert!(issues[1].message.ends_with("is not covered by any test"));
+    }
+
+    #[test]
+    fn test_mutant_is_attributed_to_the_calling_test() {
+        let source = "export function isAdult(age) {\n  return age >= 18;\n}\n";
+        let source_tree = TypeScriptParser::new().unwrap().parse(source).unwrap();
+        let mut survivor = run(MutantStatus::Survived, 2);
+        survivor.mutation.start = source.find(">=").unwrap();
+        let result = MutationResult::from_runs(Path::new("/project/src/age.ts"), vec![survivor]);
+
+        let test_source = "it('formats', () => { expect(format(1)).toBe('1'); });\n\
+                           it('checks age', () => {\n  expect(isAdult(20)).toBe(true);\n});\n";
+        let tree = TypeScriptParser::new().unwrap().parse(test_source).unwrap();
+        let tests = crate::parser::TestFileParser::new(test_source).extract_tests(&tree);
+        let rule = SurvivingMutantRule::new()
+            .with_result(&result, "src/age.ts".to_string())
+            .with_source(source.to_string(), source_tree);
+
+        let issues = rule.analyze(&tests, test_source, &tree);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .message
+            .contains(" in isAdult() at src/age.ts:2:10"));
+        assert_eq!(
+            (issues[0].location.line, issues[0].location.column),
+            (3, 10)
+        );
+    }
+}
@@ -3,18 +3,50 @@
 use super::AnalysisRule;
 use crate::parser::{count_branches_with_source, find_assertions_in_body, node_line_count};
 use crate::{Issue, Location, Rule, Severity, TestCase};
+use serde::Deserialize;
 use tree_sitter::{Node, Tree};
 
 const MAX_ASSERTIONS: usize = 15;
 const MAX_LINES: usize = 50;
 const MAX_COMPLEXITY: usize = 10;
 
+/// Options for `test-complexity`, e.g. `["warning", { "maxLines": 80 }]`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct TestComplexityOptions {
+    /// Assertions per test
+    pub max_assertions: usize,
+    /// Lines in a test body
+    pub max_lines: usize,
+    /// Cyclomatic complexity of a test body
+    pub max_complexity: usize,
+}
+
+impl Default for TestComplexityOptions {
+    fn default() -> Self {
+        Self {
+            max_assertions: MAX_ASSERTIONS,
+            max_lines: MAX_LINES,
+            max_complexity: MAX_COMPLEXITY,
+        }
+    }
+}
+
 /// Rule for detecting overly complex tests
-pub struct TestComplexityRule;
+pub struct TestComplexityRule {
+    options: TestComplexityOptions,
+}
 
 impl TestComplexityRule {
     pub fn new() -> Self {
-        Self
+        Self {
+            options: TestComplexityOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: TestComplexityOptions) -> Self {
+        self.options = options;
+        self
     }
 
     fn node_text(node: Node, source: &[u8]) -> String {
@@ -49,7 +81,12 @@ impl TestComplexityRule {
         false
     }
 
-    fn visit_tests(node: Node, source: &str, _tree: &Tree, issues: &mut Vec<Issue>) {
+    fn visit_tests(
+        node: Node,
+        source: &str,
+        options: &TestComplexityOptions,
+        issues: &mut Vec<Issue>,
+    ) {
         if Self::is_test_call(node, source.as_bytes()) {
             if let Some(args) = node.child_by_field_name("arguments") {
                 let mut cursor = args.walk();
@@ -65,18 +102,25 @@ impl TestComplexityRule {
                     let complexity = count_branches_with_source(body, source);
 
                     let (over_assertions, over_lines, over_complexity) = (
-                        assertion_count > MAX_ASSERTIONS,
-                        lines > MAX_LINES,
-                        complexity > MAX_COMPLEXITY,
+                        assertion_count > options.max_assertions,
+                        lines > options.max_lines,
+                        complexity > options.max_complexity,
                     );
                     if over_assertions || over_lines || over_complexity {
                         let reasons: Vec<String> = [
                             over_assertions.then(|| {
-                                format!("{} assertions (max {})", assertion_count, MAX_ASSERTIONS)
+                                format!(
+                                    "{} assertions (max {})",
+                                    assertion_count, options.max_assertions
+                                )
                             }),
-                            over_lines.then(|| format!("{} lines (max {})", lines, MAX_LINES)),
+                            over_lines
+                                .then(|| format!("{} lines (max {})", lines, options.max_lines)),
                             over_complexity.then(|| {
-                                format!("complexity {} (max {})", complexity, MAX_COMPLEXITY)
+                                format!(
+                                    "complexity {} (max {})",
+                                    complexity, options.max_complexity
+                                )
                             }),
                         ]
                         .into_iter()
@@ -105,7 +149,7 @@ impl TestComplexityRule {
 
         let mut cursor = node.walk();
         for child in node.named_children(&mut cursor) {
-            Self::visit_tests(child, source, _tree, issues);
+            Self::visit_tests(child, source, options, issues);
         }
     }
 }
@@ -124,7 +168,7 @@ impl AnalysisRule for TestComplexityRule {
     fn analyze(&self, _tests: &[TestCase], source: &str, tree: &Tree) -> Vec<Issue> {
         let mut issues = Vec::new();
         let root = tree.root_node();
-        Self::visit_tests(root, source, tree, &mut issues);
+        Self::visit_tests(root, source, &self.options, &mut issues);
         issues
     }
 
@@ -158,6 +202,22 @@ mod tests {
         assert!(issues.iter().any(|i| i.rule == Rule::TestComplexity));
     }
 
+    #[test]
+    fn options_lower_the_limits() {
+        let rule = TestComplexityRule::new().with_options(TestComplexityOptions {
+            max_assertions: 2,
+            ..TestComplexityOptions::default()
+        });
+        let source = "it('adds', () => {\n  expect(add(1, 1)).toBe(2);\n  expect(add(1, 2)).toBe(3);\n  expect(add(2, 2)).toBe(4);\n});";
+        let tree = crate::parser::TypeScriptParser::new()
+            .unwrap()
+            .parse(source)
+            .unwrap();
+        let issues = rule.analyze(&[], source, &tree);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("3 assertions (max 2)"));
+    }
+
     #[test]
     fn negative_simple_test_no_issue() {
         let rule = TestComplexityRule::new();
@@ -0,0 +1,109 @@
+//! Weightless assertions: `--mutate-tests` inverted an assertion of the test file and its
+//! tests still passed, so the assertion never runs or checks nothing.
+
+use super::AnalysisRule;
+use crate::mutation::MutationResult;
+use crate::{Issue, Location, Rule, Severity, TestCase};
+use tree_sitter::Tree;
+
+/// Rule turning assertions that survived inversion into issues
+pub struct WeightlessAssertionRule<'a> {
+    result: Option<&'a MutationResult>,
+}
+
+impl<'a> WeightlessAssertionRule<'a> {
+    pub fn new() -> Self {
+        Self { result: None }
+    }
+
+    /// Assertion mutation results for the test file itself
+    pub fn with_result(mut self, result: &'a MutationResult) -> Self {
+        self.result = Some(result);
+        self
+    }
+}
+
+impl Default for WeightlessAssertionRule<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnalysisRule for WeightlessAssertionRule<'_> {
+    fn name(&self) -> &'static str {
+        "weightless-assertion"
+    }
+
+    fn analyze(&self, _tests: &[TestCase], _source: &str, _tree: &Tree) -> Vec<Issue> {
+        let Some(result) = self.result else {
+            return Vec::new();
+        };
+
+        result
+            .details
+            .iter()
+            .filter(|run| run.status.is_undetected())
+            .map(|run| Issue {
+                rule: Rule::WeightlessAssertion,
+                severity: Severity::Warning,
+                message: "Assertion carries no weight: the tests still pass with it inverted"
+                    .to_string(),
+                location: Location::new(run.mutation.line, run.mutation.column),
+                suggestion: Some(
+                    "Make sure the assertion runs (await promises, check callbacks are called) and compares against a value the code under test produces."
+                        .to_string(),
+                ),
+                fix: None,
+            })
+            .collect()
+    }
+
+    fn calculate_score(&self, _tests: &[TestCase], issues: &[Issue]) -> u8 {
+        let n = issues
+            .iter()
+            .filter(|i| i.rule == Rule::WeightlessAssertion)
+            .count();
+        (25i32 - (n as i32 * 3).min(15)).max(0) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::{generate_assertion_mutations, MutantStatus, MutationRun};
+    use crate::parser::TypeScriptParser;
+    use std::path::Path;
+    use std::time::Duration;
+
+    #[test]
+    fn test_surviving_inversions_become_issues() {
+        let source =
+            "it('totals', () => {\n  expect(total).toBe(3);\n  expect(count).toBe(1);\n});\n";
+        let path = Path::new("/project/cart.test.ts");
+        let runs = generate_assertion_mutations(path, source)
+            .into_iter()
+            .zip([MutantStatus::Killed, MutantStatus::Survived])
+            .map(|(mutation, status)| MutationRun {
+                mutation,
+                status,
+                stdout: String::new(),
+                stderr: String::new(),
+                duration: Duration::ZERO,
+                cached: false,
+            })
+            .collect();
+        let result = MutationResult::from_runs(path, runs);
+        let tree = TypeScriptParser::new().unwrap().parse(source).unwrap();
+
+        let issues =
+            WeightlessAssertionRule::new()
+                .with_result(&result)
+                .analyze(&[], source, &tree);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::WeightlessAssertion);
+        assert_eq!((issues[0].location.line, issues[0].location.column), (3, 3));
+        assert!(WeightlessAssertionRule::new()
+            .analyze(&[], source, &tree)
+            .is_empty());
+    }
+}
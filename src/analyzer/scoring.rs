@@ -10,11 +10,24 @@
 //!   MockAbuse, SnapshotOveruse, VagueTestName, MissingAwait, RtlPreferScreen, RtlPreferSemantic,
 //!   RtlPreferUserEvent, and all Phase 2.2 rules (TestComplexity, VacuousTest, etc.).
 //!   See `crate::rule_scoring_category` for the mapping.
+//! - **Custom rules** (`customRules` in config) with a `category` take
+//!   [`ASSIGNED_CATEGORY_DEDUCTION`] points per issue off that category; without one they
+//!   are penalty-only. The category comes from the file's own config
+//!   ([`ScoringModel::for_config`]), since nested configs may define the same id differently.
+//!
+//! ## Configurable model
+//! [`ScoringModel`] holds the weights, penalty sizes and caps, and rule categories. The
+//! `scoring` config section adjusts it: a rule moved into another category no longer counts
+//! toward its own and deducts [`ASSIGNED_CATEGORY_DEDUCTION`] from the new one, like a
+//! custom rule; a rule made penalty-only counts as a penalty.
 
+use crate::config::{Config, ScoringConfig};
 use crate::{
-    rule_scoring_category, CategoryBreakdownEntry, Grade, Issue, Rule, Score, ScoreBreakdown,
+    registry, CategoryBreakdownEntry, Grade, Issue, PenaltyModel, Rule, Score, ScoreBreakdown,
     ScoringWeights, Severity, TestCase, TestType, TransparentBreakdown,
 };
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 
 use super::rules::{
     AiSmellsRule, AssertionQualityRule, BoundaryConditionsRule, ErrorCoverageRule,
@@ -51,6 +64,207 @@ const MAX_PENALTY_FROM_ERRORS: i32 = 50;
 const MAX_PENALTY_FROM_WARNINGS: i32 = 40;
 const MAX_PENALTY_FROM_INFO: i32 = 15;
 
+/// Category points an issue deducts from a category config assigned its rule to (custom rule
+/// `category`, `scoring.categories`), by severity (error, warning, info)
+const ASSIGNED_CATEGORY_DEDUCTION: (u8, u8, u8) = (5, 3, 1);
+
+/// `scoring.categories` value that makes a rule penalty-only
+pub const PENALTY_ONLY: &str = "penalty";
+
+impl Default for PenaltyModel {
+    fn default() -> Self {
+        Self {
+            per_error: PENALTY_PER_ERROR,
+            per_warning: PENALTY_PER_WARNING,
+            per_info: PENALTY_PER_INFO,
+            max_from_errors: MAX_PENALTY_FROM_ERRORS,
+            max_from_warnings: MAX_PENALTY_FROM_WARNINGS,
+            max_from_info: MAX_PENALTY_FROM_INFO,
+        }
+    }
+}
+
+impl PenaltyModel {
+    /// Penalty from error, warning and info issue counts, each capped
+    fn penalties(&self, errors: i32, warnings: i32, infos: i32) -> (i32, i32, i32) {
+        (
+            (errors * self.per_error).min(self.max_from_errors),
+            (warnings * self.per_warning).min(self.max_from_warnings),
+            (infos * self.per_info).min(self.max_from_info),
+        )
+    }
+}
+
+/// Weights, penalties and rule categories used to score a file
+#[derive(Debug, Clone, Default)]
+pub struct ScoringModel {
+    /// Weights for test types whose defaults were changed
+    weights: HashMap<TestType, ScoringWeights>,
+    pub penalties: PenaltyModel,
+    /// Rule id to category; `None` is penalty-only
+    categories: HashMap<String, Option<&'static str>>,
+    /// Custom rule id to the category its config declares; `None` is penalty-only
+    custom_categories: HashMap<String, Option<&'static str>>,
+}
+
+impl ScoringModel {
+    /// Built-in model adjusted by `scoring` in config. Fails if a test type's weights don't
+    /// total 100 or a category override names an unknown rule or category.
+    pub fn from_config(config: &ScoringConfig) -> anyhow::Result<Self> {
+        let mut model = Self::default();
+        for (&test_type, overrides) in &config.weights {
+            let mut weights = ScoringWeights::for_test_type(test_type);
+            let fields = [
+                (&mut weights.assertion_quality, overrides.assertion_quality),
+                (&mut weights.error_coverage, overrides.error_coverage),
+                (
+                    &mut weights.boundary_conditions,
+                    overrides.boundary_conditions,
+                ),
+                (&mut weights.test_isolation, overrides.test_isolation),
+                (&mut weights.input_variety, overrides.input_variety),
+                (&mut weights.ai_smells, overrides.ai_smells),
+            ];
+            let mut total = 0u32;
+            for (weight, value) in fields {
+                if let Some(value) = value {
+                    *weight = value;
+                }
+                total += *weight as u32;
+            }
+            if total != 100 {
+                anyhow::bail!(
+                    "scoring.weights.{}: weights total {}, expected 100",
+                    serde_json::to_value(test_type)?
+                        .as_str()
+                        .unwrap_or_default(),
+                    total
+                );
+            }
+            model.weights.insert(test_type, weights);
+        }
+
+        let penalties = &mut model.penalties;
+        let sizes = [
+            (&mut penalties.per_error, config.penalties.error),
+            (&mut penalties.per_warning, config.penalties.warning),
+            (&mut penalties.per_info, config.penalties.info),
+            (&mut penalties.max_from_errors, config.penalty_caps.error),
+            (
+                &mut penalties.max_from_warnings,
+                config.penalty_caps.warning,
+            ),
+            (&mut penalties.max_from_info, config.penalty_caps.info),
+        ];
+        for (size, value) in sizes {
+            if let Some(value) = value {
+                *size = value as i32;
+            }
+        }
+
+        for (id, category) in &config.categories {
+            // Plugin rule ids (`plugin/rule`) are only known once the plugin runs
+            if registry::rule_for_id(id).is_none() && !id.contains('/') {
+                anyhow::bail!("scoring.categories: unknown rule '{}'", id);
+            }
+            let category = if category == PENALTY_ONLY {
+                None
+            } else {
+                let known = registry::CATEGORIES
+                    .iter()
+                    .find(|c| **c == category.as_str());
+                Some(*known.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "scoring.categories: unknown category '{}' for '{}' (expected \"{}\" or one of: {})",
+                        category,
+                        id,
+                        PENALTY_ONLY,
+                        registry::CATEGORIES.join(", ")
+                    )
+                })?)
+            };
+            model.categories.insert(id.clone(), category);
+        }
+        Ok(model)
+    }
+
+    /// [`from_config`](Self::from_config) plus the categories of `config`'s custom rules
+    pub fn for_config(config: &Config) -> anyhow::Result<Self> {
+        let mut model = Self::from_config(&config.scoring)?;
+        model.custom_categories = config
+            .custom_rules
+            .iter()
+            .map(|rule| {
+                let category = rule.category.as_deref().and_then(|c| {
+                    registry::CATEGORIES
+                        .iter()
+                        .copied()
+                        .find(|known| *known == c)
+                });
+                (rule.id.clone(), category)
+            })
+            .collect();
+        Ok(model)
+    }
+
+    /// Category weights for a test type
+    pub fn weights(&self, test_type: TestType) -> ScoringWeights {
+        self.weights
+            .get(&test_type)
+            .copied()
+            .unwrap_or_else(|| ScoringWeights::for_test_type(test_type))
+    }
+
+    /// Category an issue of `rule` counts toward, or `None` if it is penalty-only
+    pub fn category(&self, rule: &Rule) -> Option<&'static str> {
+        let id = rule.id();
+        match self
+            .categories
+            .get(id)
+            .or_else(|| self.custom_categories.get(id))
+        {
+            Some(category) => *category,
+            None => rule.category(),
+        }
+    }
+
+    /// True if config moved `rule` out of the category its analyzer scores it in
+    fn is_reassigned(&self, rule: &Rule) -> bool {
+        self.categories.contains_key(rule.id())
+    }
+
+    /// Rule moves for the transparent breakdown
+    fn category_overrides(&self) -> BTreeMap<String, String> {
+        self.categories
+            .iter()
+            .map(|(id, category)| (id.clone(), category.unwrap_or(PENALTY_ONLY).to_string()))
+            .collect()
+    }
+
+    /// Custom rule categories for the transparent breakdown
+    fn custom_categories(&self) -> BTreeMap<String, String> {
+        self.custom_categories
+            .iter()
+            .map(|(id, category)| (id.clone(), category.unwrap_or(PENALTY_ONLY).to_string()))
+            .collect()
+    }
+
+    /// Error, warning and info counts of the issues that only affect the penalty
+    fn penalty_counts(&self, issues: &[Issue]) -> (i32, i32, i32) {
+        issues.iter().fold((0, 0, 0), |acc, i| {
+            if self.category(&i.rule).is_some() {
+                acc
+            } else {
+                match i.severity {
+                    Severity::Error => (acc.0 + 1, acc.1, acc.2),
+                    Severity::Warning => (acc.0, acc.1 + 1, acc.2),
+                    Severity::Info => (acc.0, acc.1, acc.2 + 1),
+                }
+            }
+        })
+    }
+}
+
 /// Calculator for test quality scores
 pub struct ScoreCalculator;
 
@@ -62,9 +276,12 @@ impl ScoreCalculator {
     }
 
     /// Calculate the overall score with test-type-specific weights
-    pub fn calculate_weighted(breakdown: &ScoreBreakdown, test_type: TestType) -> Score {
-        let weights = ScoringWeights::for_test_type(test_type);
-        let total = weights.calculate_total(breakdown);
+    pub fn calculate_weighted(
+        breakdown: &ScoreBreakdown,
+        test_type: TestType,
+        model: &ScoringModel,
+    ) -> Score {
+        let total = model.weights(test_type).calculate_total(breakdown);
         Score::new(total)
     }
 
@@ -72,22 +289,11 @@ impl ScoreCalculator {
     /// Only issues that do NOT affect a category score (e.g. DebugCode, FocusedTest)
     /// are counted here. Issues that reduce a category (e.g. WeakAssertion, MissingErrorTest)
     /// have already been reflected in the category score and are not penalized again.
-    pub fn apply_issue_penalty(score: Score, issues: &[Issue]) -> Score {
-        let (errors, warnings, infos) = issues.iter().fold((0i32, 0i32, 0i32), |acc, i| {
-            if rule_scoring_category(&i.rule).is_some() {
-                acc
-            } else {
-                match i.severity {
-                    Severity::Error => (acc.0 + 1, acc.1, acc.2),
-                    Severity::Warning => (acc.0, acc.1 + 1, acc.2),
-                    Severity::Info => (acc.0, acc.1, acc.2 + 1),
-                }
-            }
-        });
-
-        let penalty = (errors * PENALTY_PER_ERROR).min(MAX_PENALTY_FROM_ERRORS)
-            + (warnings * PENALTY_PER_WARNING).min(MAX_PENALTY_FROM_WARNINGS)
-            + (infos * PENALTY_PER_INFO).min(MAX_PENALTY_FROM_INFO);
+    pub fn apply_issue_penalty(score: Score, issues: &[Issue], model: &ScoringModel) -> Score {
+        let (errors, warnings, infos) = model.penalty_counts(issues);
+        let (from_errors, from_warnings, from_info) =
+            model.penalties.penalties(errors, warnings, infos);
+        let penalty = from_errors + from_warnings + from_info;
 
         let value = (score.value as i32 - penalty).clamp(0, 100) as u8;
         Score::new(value)
@@ -104,16 +310,60 @@ impl ScoreCalculator {
         isolation_rule: &TestIsolationRule,
         variety_rule: &InputVarietyRule,
         ai_smells_rule: &AiSmellsRule,
+        model: &ScoringModel,
     ) -> ScoreBreakdown {
         use super::rules::AnalysisRule;
 
-        ScoreBreakdown {
-            assertion_quality: assertion_rule.calculate_score(tests, issues),
-            error_coverage: error_rule.calculate_score(tests, issues),
-            boundary_conditions: boundary_rule.calculate_score(tests, issues),
-            test_isolation: isolation_rule.calculate_score(tests, issues),
-            input_variety: variety_rule.calculate_score(tests, issues),
-            ai_smells: ai_smells_rule.calculate_score(tests, issues),
+        // Category rules only see the issues config left in their category
+        let scored: Cow<[Issue]> = if model.categories.is_empty() {
+            Cow::Borrowed(issues)
+        } else {
+            Cow::Owned(
+                issues
+                    .iter()
+                    .filter(|i| !model.is_reassigned(&i.rule))
+                    .cloned()
+                    .collect(),
+            )
+        };
+        let mut breakdown = ScoreBreakdown {
+            assertion_quality: assertion_rule.calculate_score(tests, &scored),
+            error_coverage: error_rule.calculate_score(tests, &scored),
+            boundary_conditions: boundary_rule.calculate_score(tests, &scored),
+            test_isolation: isolation_rule.calculate_score(tests, &scored),
+            input_variety: variety_rule.calculate_score(tests, &scored),
+            ai_smells: ai_smells_rule.calculate_score(tests, &scored),
+        };
+        Self::deduct_assigned_categories(&mut breakdown, issues, model);
+        breakdown
+    }
+
+    /// Take issues of custom rules and reassigned rules off the category config gives them
+    fn deduct_assigned_categories(
+        breakdown: &mut ScoreBreakdown,
+        issues: &[Issue],
+        model: &ScoringModel,
+    ) {
+        let assigned = issues
+            .iter()
+            .filter(|i| matches!(i.rule, Rule::Custom(_)) || model.is_reassigned(&i.rule));
+        for issue in assigned {
+            let category = match model.category(&issue.rule) {
+                Some("Assertion Quality") => &mut breakdown.assertion_quality,
+                Some("Error Coverage") => &mut breakdown.error_coverage,
+                Some("Boundary Conditions") => &mut breakdown.boundary_conditions,
+                Some("Test Isolation") => &mut breakdown.test_isolation,
+                Some("Input Variety") => &mut breakdown.input_variety,
+                Some("AI Smells") => &mut breakdown.ai_smells,
+                _ => continue,
+            };
+            let (error, warning, info) = ASSIGNED_CATEGORY_DEDUCTION;
+            let points = match issue.severity {
+                Severity::Error => error,
+                Severity::Warning => warning,
+                Severity::Info => info,
+            };
+            *category = category.saturating_sub(points);
         }
     }
 
@@ -134,8 +384,9 @@ impl ScoreCalculator {
         breakdown: &ScoreBreakdown,
         issues: &[Issue],
         test_type: TestType,
+        model: &ScoringModel,
     ) -> TransparentBreakdown {
-        let weights = ScoringWeights::for_test_type(test_type);
+        let weights = model.weights(test_type);
         let categories = [
             (
                 "Assertion Quality",
@@ -183,21 +434,9 @@ impl ScoreCalculator {
         let total_before_penalties = weights.calculate_total(breakdown);
 
         // Only count penalty-only issues (not category issues) to avoid double-counting
-        let (errors, warnings, infos) = issues.iter().fold((0i32, 0i32, 0i32), |acc, i| {
-            if rule_scoring_category(&i.rule).is_some() {
-                acc
-            } else {
-                match i.severity {
-                    Severity::Error => (acc.0 + 1, acc.1, acc.2),
-                    Severity::Warning => (acc.0, acc.1 + 1, acc.2),
-                    Severity::Info => (acc.0, acc.1, acc.2 + 1),
-                }
-            }
-        });
-
-        let penalty_from_errors = (errors * PENALTY_PER_ERROR).min(MAX_PENALTY_FROM_ERRORS);
-        let penalty_from_warnings = (warnings * PENALTY_PER_WARNING).min(MAX_PENALTY_FROM_WARNINGS);
-        let penalty_from_info = (infos * PENALTY_PER_INFO).min(MAX_PENALTY_FROM_INFO);
+        let (errors, warnings, infos) = model.penalty_counts(issues);
+        let (penalty_from_errors, penalty_from_warnings, penalty_from_info) =
+            model.penalties.penalties(errors, warnings, infos);
         let penalty_total = penalty_from_errors + penalty_from_warnings + penalty_from_info;
 
         let final_score = (total_before_penalties as i32 - penalty_total).clamp(0, 100) as u8;
@@ -209,6 +448,9 @@ impl ScoreCalculator {
             penalty_from_errors,
             penalty_from_warnings,
             penalty_from_info,
+            penalty_model: model.penalties,
+            category_overrides: model.category_overrides(),
+            custom_categories: model.custom_categories(),
             final_score,
             per_test_aggregated: None, // Set by engine when per-test aggregation changes the score
         }
@@ -306,6 +548,7 @@ impl ScoreCalculator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::{TestFileParser, TypeScriptParser};
     use crate::{Issue, Location, Rule, Severity};
 
     #[test]
@@ -402,7 +645,7 @@ mod tests {
     #[test]
     fn test_apply_issue_penalty_no_issues() {
         let score = Score::new(90);
-        let result = ScoreCalculator::apply_issue_penalty(score, &[]);
+        let result = ScoreCalculator::apply_issue_penalty(score, &[], &ScoringModel::default());
         assert_eq!(result.value, 90);
         assert_eq!(result.grade, Grade::A);
     }
@@ -418,7 +661,11 @@ mod tests {
             suggestion: None,
             fix: None,
         }];
-        let result = ScoreCalculator::apply_issue_penalty(Score::new(90), &category_issues);
+        let result = ScoreCalculator::apply_issue_penalty(
+            Score::new(90),
+            &category_issues,
+            &ScoringModel::default(),
+        );
         assert_eq!(result.value, 90, "category issues should not add penalty");
 
         // DebugCode is penalty-only — should be penalized
@@ -430,7 +677,11 @@ mod tests {
             suggestion: None,
             fix: None,
         }];
-        let result = ScoreCalculator::apply_issue_penalty(Score::new(90), &penalty_issues);
+        let result = ScoreCalculator::apply_issue_penalty(
+            Score::new(90),
+            &penalty_issues,
+            &ScoringModel::default(),
+        );
         // 1 error * 7 = 7 penalty, 90 - 7 = 83
         assert_eq!(result.value, 83, "penalty-only issues should reduce score");
     }
@@ -449,7 +700,7 @@ mod tests {
                 fix: None,
             })
             .collect();
-        let result = ScoreCalculator::apply_issue_penalty(score, &issues);
+        let result = ScoreCalculator::apply_issue_penalty(score, &issues, &ScoringModel::default());
         assert_eq!(result.value, 0);
         assert_eq!(result.grade, Grade::F);
     }
@@ -484,7 +735,7 @@ mod tests {
                 fix: None,
             },
         ];
-        let result = ScoreCalculator::apply_issue_penalty(score, &issues);
+        let result = ScoreCalculator::apply_issue_penalty(score, &issues, &ScoringModel::default());
         // 1*7 + 1*3 + 1*1 = 11 penalty, 95 - 11 = 84
         assert_eq!(result.value, 84);
         assert_eq!(result.grade, Grade::B);
@@ -576,7 +827,12 @@ mod tests {
                 fix: None,
             },
         ];
-        let tb = ScoreCalculator::build_transparent_breakdown(&breakdown, &issues, TestType::Unit);
+        let tb = ScoreCalculator::build_transparent_breakdown(
+            &breakdown,
+            &issues,
+            TestType::Unit,
+            &ScoringModel::default(),
+        );
         assert_eq!(tb.categories.len(), 6);
         assert_eq!(
             tb.final_score as i32,
@@ -611,10 +867,20 @@ mod tests {
             suggestion: None,
             fix: None,
         }];
-        let score_before = ScoreCalculator::calculate_weighted(&breakdown, TestType::Unit);
-        let score_after = ScoreCalculator::apply_issue_penalty(score_before, &issues);
+        let score_before = ScoreCalculator::calculate_weighted(
+            &breakdown,
+            TestType::Unit,
+            &ScoringModel::default(),
+        );
+        let score_after =
+            ScoreCalculator::apply_issue_penalty(score_before, &issues, &ScoringModel::default());
         // Category-only issue should not add any penalty
-        let tb = ScoreCalculator::build_transparent_breakdown(&breakdown, &issues, TestType::Unit);
+        let tb = ScoreCalculator::build_transparent_breakdown(
+            &breakdown,
+            &issues,
+            TestType::Unit,
+            &ScoringModel::default(),
+        );
         assert_eq!(tb.penalty_from_warnings, 0);
         assert_eq!(tb.penalty_total, 0);
         assert_eq!(score_after.value as i32, tb.final_score as i32);
@@ -648,10 +914,149 @@ mod tests {
                 fix: None,
             },
         ];
-        let score_before = ScoreCalculator::calculate_weighted(&breakdown, TestType::Unit);
-        let score_after = ScoreCalculator::apply_issue_penalty(score_before, &issues);
-        let tb = ScoreCalculator::build_transparent_breakdown(&breakdown, &issues, TestType::Unit);
+        let score_before = ScoreCalculator::calculate_weighted(
+            &breakdown,
+            TestType::Unit,
+            &ScoringModel::default(),
+        );
+        let score_after =
+            ScoreCalculator::apply_issue_penalty(score_before, &issues, &ScoringModel::default());
+        let tb = ScoreCalculator::build_transparent_breakdown(
+            &breakdown,
+            &issues,
+            TestType::Unit,
+            &ScoringModel::default(),
+        );
         assert!(tb.penalty_total > 0);
         assert_eq!(score_after.value as i32, tb.final_score as i32);
     }
+
+    #[test]
+    fn test_custom_rule_issues_deduct_from_their_category() {
+        let isolation = crate::registry::register_custom(
+            "scoring-test-no-reset-db",
+            Some("Test Isolation"),
+            "m",
+        );
+        let penalty = crate::registry::register_custom("scoring-test-house-style", None, "m");
+        let issue = |rule, severity| Issue {
+            rule,
+            severity,
+            message: String::new(),
+            location: Location::new(1, 1),
+            suggestion: None,
+            fix: None,
+        };
+        let mut breakdown = ScoreBreakdown {
+            assertion_quality: 25,
+            error_coverage: 25,
+            boundary_conditions: 25,
+            test_isolation: 25,
+            input_variety: 25,
+            ai_smells: 25,
+        };
+        let issues = vec![
+            issue(isolation, Severity::Error),
+            issue(isolation, Severity::Warning),
+            issue(penalty, Severity::Error),
+        ];
+        ScoreCalculator::deduct_assigned_categories(
+            &mut breakdown,
+            &issues,
+            &ScoringModel::default(),
+        );
+        assert_eq!(breakdown.test_isolation, 17);
+        assert_eq!(breakdown.assertion_quality, 25);
+
+        // Only the uncategorized custom issue is a penalty: 100 - 7
+        let score = ScoreCalculator::apply_issue_penalty(
+            Score::new(100),
+            &issues,
+            &ScoringModel::default(),
+        );
+        assert_eq!(score.value, 93);
+    }
+
+    fn config(json: &str) -> ScoringConfig {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_scoring_model_moves_rules_and_resizes_penalties() {
+        let model = ScoringModel::from_config(&config(
+            r#"{
+                "weights": { "unit": { "assertionQuality": 30, "aiSmells": 5 } },
+                "penalties": { "warning": 5 },
+                "penaltyCaps": { "warning": 8 },
+                "categories": { "weak-assertion": "penalty", "debug-code": "Test Isolation" }
+            }"#,
+        ))
+        .unwrap();
+        assert_eq!(model.weights(TestType::Unit).assertion_quality, 30);
+        assert_eq!(model.weights(TestType::Integration).assertion_quality, 22);
+
+        let source =
+            "it('is set', () => {\n  expect(a).toBeTruthy();\n  expect(b).toBeTruthy();\n});\n";
+        let tree = TypeScriptParser::new().unwrap().parse(source).unwrap();
+        let tests = TestFileParser::new(source).extract_tests(&tree);
+        let issue = |rule, severity, line| Issue {
+            rule,
+            severity,
+            message: String::new(),
+            location: Location::new(line, 3),
+            suggestion: None,
+            fix: None,
+        };
+        let issues = vec![
+            issue(Rule::WeakAssertion, Severity::Warning, 2),
+            issue(Rule::WeakAssertion, Severity::Warning, 3),
+            issue(Rule::DebugCode, Severity::Error, 3),
+        ];
+        let breakdown = |model: &ScoringModel| {
+            ScoreCalculator::calculate_breakdown(
+                &tests,
+                &issues,
+                &AssertionQualityRule::new(),
+                &ErrorCoverageRule::new(),
+                &BoundaryConditionsRule::new(),
+                &TestIsolationRule::new(),
+                &InputVarietyRule::new(),
+                &AiSmellsRule::new(),
+                model,
+            )
+        };
+        let default = breakdown(&ScoringModel::default());
+        let custom = breakdown(&model);
+        assert!(custom.assertion_quality > default.assertion_quality);
+        assert_eq!(custom.test_isolation, default.test_isolation - 5);
+
+        // The weak assertions are now penalties (2 x 5, capped at 8); debug-code is not
+        let tb =
+            ScoreCalculator::build_transparent_breakdown(&custom, &issues, TestType::Unit, &model);
+        assert_eq!(tb.penalty_from_warnings, 8);
+        assert_eq!(tb.penalty_from_errors, 0);
+        assert_eq!(tb.penalty_model.per_warning, 5);
+        assert_eq!(tb.penalty_model.per_error, PENALTY_PER_ERROR);
+        assert_eq!(tb.categories[0].weight_pct, 30);
+        assert_eq!(tb.category_overrides["weak-assertion"], "penalty");
+        assert_eq!(tb.category_overrides["debug-code"], "Test Isolation");
+    }
+
+    #[test]
+    fn test_scoring_model_rejects_invalid_config() {
+        let err =
+            ScoringModel::from_config(&config(r#"{ "weights": { "e2e": { "aiSmells": 15 } } }"#))
+                .unwrap_err()
+                .to_string();
+        assert_eq!(err, "scoring.weights.e2e: weights total 110, expected 100");
+
+        assert!(ScoringModel::from_config(&config(
+            r#"{ "categories": { "no-such-rule": "penalty" } }"#
+        ))
+        .is_err());
+        assert!(ScoringModel::from_config(&config(
+            r#"{ "categories": { "debug-code": "Hygiene" } }"#
+        ))
+        .is_err());
+    }
 }
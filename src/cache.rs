@@ -1,7 +1,10 @@
 //! Analysis result caching for performance
 //!
 //! Caches analysis results based on file content hash to avoid re-analyzing unchanged files.
+//! Entries are also keyed on the file's effective config and the rigor version, so editing a
+//! config (or upgrading) re-analyzes the files it applies to.
 
+use crate::config::Config;
 use crate::AnalysisResult;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -11,7 +14,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 2;
 const CACHE_FILENAME: &str = ".rigor-cache.json";
 
 /// Cache entry for a single file
@@ -21,6 +24,8 @@ struct CacheEntry {
     content_hash: String,
     /// SHA256 hash of the source file content (if any)
     source_hash: Option<String>,
+    /// [`AnalysisCache::config_hash`] of the config the result was computed with
+    config_hash: String,
     /// Cached analysis result
     result: AnalysisResult,
     /// Timestamp when cached
@@ -115,12 +120,29 @@ impl AnalysisCache {
         hex::encode(hasher.finalize())
     }
 
+    /// Hash of the rigor version and `config` (a file's effective config), including the
+    /// modification times of its plugins
+    pub fn config_hash(config: &Config) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        // Through a Value, whose maps are sorted, so HashMap fields hash the same every run
+        if let Ok(value) = serde_json::to_value(config) {
+            hasher.update(value.to_string().as_bytes());
+        }
+        for plugin in &config.plugins {
+            let modified = fs::metadata(&plugin.path).and_then(|m| m.modified()).ok();
+            hasher.update(format!("{:?}", modified).as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
     /// Get cached result if still valid
     pub fn get(
         &self,
         test_path: &Path,
         test_content: &str,
         source_content: Option<&str>,
+        config_hash: &str,
     ) -> Option<AnalysisResult> {
         if !self.enabled {
             return None;
@@ -128,6 +150,9 @@ impl AnalysisCache {
 
         let key = test_path.to_string_lossy().to_string();
         let entry = self.data.entries.get(&key)?;
+        if entry.config_hash != config_hash {
+            return None;
+        }
 
         // Check if test file hash matches
         let current_hash = Self::hash_content(test_content);
@@ -156,6 +181,7 @@ impl AnalysisCache {
         test_path: &Path,
         test_content: &str,
         source_content: Option<&str>,
+        config_hash: &str,
         result: AnalysisResult,
     ) {
         if !self.enabled {
@@ -166,6 +192,7 @@ impl AnalysisCache {
         let entry = CacheEntry {
             content_hash: Self::hash_content(test_content),
             source_hash: source_content.map(Self::hash_content),
+            config_hash: config_hash.to_string(),
             result,
             cached_at: SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -219,6 +246,8 @@ mod tests {
     use super::*;
     use crate::{Score, ScoreBreakdown, TestFramework, TestStats, TestType};
 
+    const CONFIG: &str = "config-hash";
+
     fn make_result(path: &str) -> AnalysisResult {
         AnalysisResult {
             file_path: PathBuf::from(path),
@@ -241,6 +270,7 @@ mod tests {
             framework: TestFramework::Jest,
             test_type: TestType::Unit,
             source_file: None,
+            package: None,
         }
     }
 
@@ -258,16 +288,20 @@ mod tests {
     fn test_cache_disabled() {
         let cache = AnalysisCache::disabled();
         assert!(!cache.enabled);
-        assert!(cache.get(Path::new("test.ts"), "content", None).is_none());
+        assert!(cache
+            .get(Path::new("test.ts"), "content", None, CONFIG)
+            .is_none());
     }
 
     #[test]
     fn test_cache_disabled_set_noop() {
         let mut cache = AnalysisCache::disabled();
         let result = make_result("test.ts");
-        cache.set(Path::new("test.ts"), "content", None, result);
+        cache.set(Path::new("test.ts"), "content", None, CONFIG, result);
         // get should still return None since cache is disabled
-        assert!(cache.get(Path::new("test.ts"), "content", None).is_none());
+        assert!(cache
+            .get(Path::new("test.ts"), "content", None, CONFIG)
+            .is_none());
         assert!(!cache.dirty, "disabled cache should not become dirty");
     }
 
@@ -289,11 +323,12 @@ mod tests {
             Path::new("auth.test.ts"),
             "const x = 1;",
             None,
+            CONFIG,
             result.clone(),
         );
         assert!(cache.dirty, "cache should be dirty after set");
 
-        let cached = cache.get(Path::new("auth.test.ts"), "const x = 1;", None);
+        let cached = cache.get(Path::new("auth.test.ts"), "const x = 1;", None, CONFIG);
         assert!(cached.is_some(), "cache should hit for same content");
         let cached = cached.unwrap();
         assert_eq!(cached.score.value, 85);
@@ -306,12 +341,47 @@ mod tests {
         let mut cache = AnalysisCache::new(dir.path());
         let result = make_result("auth.test.ts");
 
-        cache.set(Path::new("auth.test.ts"), "const x = 1;", None, result);
+        cache.set(
+            Path::new("auth.test.ts"),
+            "const x = 1;",
+            None,
+            CONFIG,
+            result,
+        );
 
-        let cached = cache.get(Path::new("auth.test.ts"), "const x = 2;", None);
+        let cached = cache.get(Path::new("auth.test.ts"), "const x = 2;", None, CONFIG);
         assert!(cached.is_none(), "cache should miss when content changes");
     }
 
+    #[test]
+    fn test_cache_miss_on_changed_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut cache = AnalysisCache::new(dir.path());
+        let config = Config::default();
+        let hash = AnalysisCache::config_hash(&config);
+        assert_eq!(hash, AnalysisCache::config_hash(&config.clone()));
+        cache.set(
+            Path::new("auth.test.ts"),
+            "content",
+            None,
+            &hash,
+            make_result("auth.test.ts"),
+        );
+        assert!(cache
+            .get(Path::new("auth.test.ts"), "content", None, &hash)
+            .is_some());
+
+        let stricter = Config {
+            threshold: Some(90),
+            ..Config::default()
+        };
+        let changed = AnalysisCache::config_hash(&stricter);
+        assert_ne!(hash, changed);
+        assert!(cache
+            .get(Path::new("auth.test.ts"), "content", None, &changed)
+            .is_none());
+    }
+
     #[test]
     fn test_cache_roundtrip_with_source() {
         let dir = tempfile::TempDir::new().unwrap();
@@ -322,6 +392,7 @@ mod tests {
             Path::new("auth.test.ts"),
             "test content",
             Some("source content"),
+            CONFIG,
             result,
         );
 
@@ -330,6 +401,7 @@ mod tests {
             Path::new("auth.test.ts"),
             "test content",
             Some("source content"),
+            CONFIG,
         );
         assert!(cached.is_some(), "should hit with same source content");
 
@@ -338,11 +410,12 @@ mod tests {
             Path::new("auth.test.ts"),
             "test content",
             Some("different source"),
+            CONFIG,
         );
         assert!(cached.is_none(), "should miss when source content changes");
 
         // Miss when source presence changes (cached with source, queried without)
-        let cached = cache.get(Path::new("auth.test.ts"), "test content", None);
+        let cached = cache.get(Path::new("auth.test.ts"), "test content", None, CONFIG);
         assert!(cached.is_none(), "should miss when source presence changes");
     }
 
@@ -354,14 +427,14 @@ mod tests {
         {
             let mut cache = AnalysisCache::new(dir.path());
             let result = make_result("auth.test.ts");
-            cache.set(Path::new("auth.test.ts"), "content", None, result);
+            cache.set(Path::new("auth.test.ts"), "content", None, CONFIG, result);
             cache.save().unwrap();
         }
 
         // Load and verify
         {
             let cache = AnalysisCache::new(dir.path());
-            let cached = cache.get(Path::new("auth.test.ts"), "content", None);
+            let cached = cache.get(Path::new("auth.test.ts"), "content", None, CONFIG);
             assert!(cached.is_some(), "cache should persist across save/load");
             assert_eq!(cached.unwrap().score.value, 85);
         }
@@ -372,13 +445,13 @@ mod tests {
         let dir = tempfile::TempDir::new().unwrap();
         let mut cache = AnalysisCache::new(dir.path());
         let result = make_result("auth.test.ts");
-        cache.set(Path::new("auth.test.ts"), "content", None, result);
+        cache.set(Path::new("auth.test.ts"), "content", None, CONFIG, result);
 
         assert_eq!(cache.stats().entries, 1);
         cache.clear();
         assert_eq!(cache.stats().entries, 0);
         assert!(cache
-            .get(Path::new("auth.test.ts"), "content", None)
+            .get(Path::new("auth.test.ts"), "content", None, CONFIG)
             .is_none());
     }
 
@@ -386,15 +459,31 @@ mod tests {
     fn test_cache_cleanup() {
         let dir = tempfile::TempDir::new().unwrap();
         let mut cache = AnalysisCache::new(dir.path());
-        cache.set(Path::new("a.test.ts"), "a", None, make_result("a.test.ts"));
-        cache.set(Path::new("b.test.ts"), "b", None, make_result("b.test.ts"));
+        cache.set(
+            Path::new("a.test.ts"),
+            "a",
+            None,
+            CONFIG,
+            make_result("a.test.ts"),
+        );
+        cache.set(
+            Path::new("b.test.ts"),
+            "b",
+            None,
+            CONFIG,
+            make_result("b.test.ts"),
+        );
         assert_eq!(cache.stats().entries, 2);
 
         // Only a.test.ts still exists
         cache.cleanup(&[PathBuf::from("a.test.ts")]);
         assert_eq!(cache.stats().entries, 1);
-        assert!(cache.get(Path::new("a.test.ts"), "a", None).is_some());
-        assert!(cache.get(Path::new("b.test.ts"), "b", None).is_none());
+        assert!(cache
+            .get(Path::new("a.test.ts"), "a", None, CONFIG)
+            .is_some());
+        assert!(cache
+            .get(Path::new("b.test.ts"), "b", None, CONFIG)
+            .is_none());
     }
 
     #[test]
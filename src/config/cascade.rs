@@ -0,0 +1,238 @@
+//! Per-file config resolution for runs over many directories. Each file gets every config
+//! file between it and the repository root merged, as [`load_config`](super::load_config)
+//! does for one directory; results are cached per directory, so each config file is read
+//! once per run.
+
+use super::{
+    config_dirs, declares_root, find_config_in_dir, finish_config, is_repository_root,
+    load_config_file, Config,
+};
+use anyhow::Result;
+use globset::GlobSet;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Config for a directory, with its ignore patterns compiled
+struct Resolved {
+    config: Arc<Config>,
+    ignore: Option<GlobSet>,
+}
+
+/// Resolves the config that applies to each analyzed file
+pub struct ConfigResolver {
+    /// Config given with `--config`, used for every file instead of the cascade
+    fixed: Option<Arc<Resolved>>,
+    cli_threshold: Option<u8>,
+    by_dir: Mutex<HashMap<PathBuf, Arc<Resolved>>>,
+}
+
+impl ConfigResolver {
+    /// Use `config` for every file (an explicit `--config`)
+    pub fn fixed(config: Config) -> Result<Self> {
+        Ok(Self {
+            fixed: Some(Arc::new(Resolved::new(config)?)),
+            cli_threshold: None,
+            by_dir: Mutex::default(),
+        })
+    }
+
+    /// Look up config files from each file's directory; `cli_threshold` (`--threshold`)
+    /// overrides every config's threshold
+    pub fn cascading(cli_threshold: Option<u8>) -> Self {
+        Self {
+            fixed: None,
+            cli_threshold,
+            by_dir: Mutex::default(),
+        }
+    }
+
+    /// Merged config for `file`
+    pub fn for_file(&self, file: &Path) -> Result<Arc<Config>> {
+        Ok(self.resolve_file(file)?.config.clone())
+    }
+
+    /// Merged config for files directly in `dir`
+    pub fn for_dir(&self, dir: &Path) -> Result<Arc<Config>> {
+        match &self.fixed {
+            Some(fixed) => Ok(fixed.config.clone()),
+            None => Ok(self.resolve_dir(&config_dirs(dir))?.config.clone()),
+        }
+    }
+
+    /// True if `file` matches the `ignore` patterns of its config
+    pub fn is_ignored(&self, file: &Path) -> Result<bool> {
+        Ok(self
+            .resolve_file(file)?
+            .ignore
+            .as_ref()
+            .is_some_and(|set| super::is_ignored(file, set)))
+    }
+
+    fn resolve_file(&self, file: &Path) -> Result<Arc<Resolved>> {
+        if let Some(fixed) = &self.fixed {
+            return Ok(fixed.clone());
+        }
+        let dir = match file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        self.resolve_dir(&config_dirs(dir))
+    }
+
+    /// Config for `dirs[0]`, where `dirs` is it and its parents
+    fn resolve_dir(&self, dirs: &[PathBuf]) -> Result<Arc<Resolved>> {
+        let dir = &dirs[0];
+        if let Some(resolved) = self.by_dir.lock().unwrap().get(dir) {
+            return Ok(resolved.clone());
+        }
+
+        let own = find_config_in_dir(dir);
+        let stop = own.as_deref().is_some_and(declares_root) || is_repository_root(dir);
+        let parent = match dirs.get(1..) {
+            Some(parents) if !stop && !parents.is_empty() => Some(self.resolve_dir(parents)?),
+            _ => None,
+        };
+        let resolved = match (own, parent) {
+            (None, Some(parent)) => parent,
+            (own, parent) => {
+                let parent = parent.map_or_else(Config::default, |p| (*p.config).clone());
+                let config = match own {
+                    Some(path) => load_config_file(&path, parent, true)?.0,
+                    None => parent,
+                };
+                finish_config(&config)?;
+                crate::plugin::check(&config.plugins)?;
+                Arc::new(Resolved::new(
+                    config.merge_with_cli(self.cli_threshold, None),
+                )?)
+            }
+        };
+
+        self.by_dir
+            .lock()
+            .unwrap()
+            .insert(dir.clone(), resolved.clone());
+        Ok(resolved)
+    }
+}
+
+impl Resolved {
+    fn new(config: Config) -> Result<Self> {
+        let ignore = match config.ignore.is_empty() {
+            true => None,
+            false => Some(super::build_ignore_set(&config.ignore)?),
+        };
+        Ok(Self {
+            config: Arc::new(config),
+            ignore,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_nested_configs_layer_per_directory() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            ".rigorrc.json",
+            r#"{ "threshold": 80, "rules": { "weak-assertion": "error" },
+                 "customRules": [{ "id": "no-reset-db", "query": "(call_expression) @c", "message": "m" }] }"#,
+        );
+        write(
+            "packages/legacy-app/.rigorrc.yaml",
+            "threshold: 50\nignore: ['**/fixtures/**']\nrules:\n  no-reset-db: off\n",
+        );
+        write(
+            "packages/isolated/.rigorrc.json",
+            r#"{ "root": true, "rules": { "mock-abuse": "off" } }"#,
+        );
+
+        let resolver = ConfigResolver::cascading(None);
+        let top = resolver.for_file(&root.join("src/a.test.ts")).unwrap();
+        assert_eq!(top.threshold, Some(80));
+
+        let legacy_file = root.join("packages/legacy-app/src/old/a.test.ts");
+        let legacy = resolver.for_file(&legacy_file).unwrap();
+        assert_eq!(legacy.threshold, Some(50));
+        assert_eq!(
+            legacy.rule_severity("weak-assertion"),
+            top.rule_severity("weak-assertion")
+        );
+        assert_eq!(
+            legacy.rule_severity("no-reset-db"),
+            Some(super::super::RuleSeverity::Off)
+        );
+        assert!(resolver
+            .is_ignored(&root.join("packages/legacy-app/fixtures/a.test.ts"))
+            .unwrap());
+        assert!(!resolver.is_ignored(&legacy_file).unwrap());
+
+        // Same directory comes from the cache
+        let again = resolver.for_file(&legacy_file).unwrap();
+        assert!(Arc::ptr_eq(&legacy, &again));
+
+        let isolated = resolver
+            .for_file(&root.join("packages/isolated/a.test.ts"))
+            .unwrap();
+        assert_eq!(isolated.threshold, None);
+        assert!(isolated.rule_severity("weak-assertion").is_none());
+
+        // load_config agrees with the resolver, and --threshold wins everywhere
+        let loaded =
+            super::super::load_config(&root.join("packages/legacy-app/src"), None).unwrap();
+        assert_eq!(loaded.threshold, Some(50));
+        let resolver = ConfigResolver::cascading(Some(90));
+        assert_eq!(resolver.for_file(&legacy_file).unwrap().threshold, Some(90));
+    }
+
+    #[test]
+    fn test_nested_patterns_are_relative_to_their_config() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(".rigorrc.json", r#"{ "ignore": ["./generated/**"] }"#);
+        write(
+            "packages/legacy/.rigorrc.json",
+            r#"{ "ignore": ["fixtures/**"],
+                 "overrides": [{ "files": ["src/**"], "threshold": 99 }] }"#,
+        );
+
+        let resolver = ConfigResolver::cascading(None);
+        let ignored = |path: &str| resolver.is_ignored(&root.join(path)).unwrap();
+        assert!(ignored("packages/legacy/fixtures/a.test.ts"));
+        assert!(!ignored("fixtures/a.test.ts"));
+        assert!(!ignored("packages/legacy/src/fixtures.test.ts"));
+        // The root config's patterns are relative to the root, also in nested directories
+        assert!(ignored("generated/a.test.ts"));
+        assert!(!ignored("packages/legacy/generated/a.test.ts"));
+
+        let threshold = |path: &str| {
+            let file = root.join(path);
+            resolver
+                .for_file(&file)
+                .unwrap()
+                .effective_for_file(&file)
+                .threshold
+        };
+        assert_eq!(threshold("packages/legacy/src/a.test.ts"), Some(99));
+        assert_eq!(threshold("packages/legacy/test/src.test.ts"), None);
+    }
+}
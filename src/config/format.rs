@@ -0,0 +1,88 @@
+//! Config file formats: `.rigorrc.json`, `.rigorrc.yaml`/`.rigorrc.yml`, `rigor.toml` and the
+//! `"rigor"` key of `package.json`
+
+use super::Config;
+use anyhow::Result;
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Config file names looked for in each directory, highest precedence first. Only the first
+/// one present is used; `package.json` counts only if it has a `"rigor"` key.
+pub const CONFIG_FILES: &[&str] = &[
+    ".rigorrc.json",
+    ".rigorrc.yaml",
+    ".rigorrc.yml",
+    "rigor.toml",
+    "package.json",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+    /// The `"rigor"` key of a `package.json`
+    PackageJson,
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConfigFormat::Json => "JSON",
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::PackageJson => "package.json \"rigor\" key",
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct PackageJson<T> {
+    rigor: Option<T>,
+}
+
+impl ConfigFormat {
+    /// Format of a config file, from its name. Anything unrecognized is read as JSON.
+    pub(super) fn of(path: &Path) -> Self {
+        if path.file_name().is_some_and(|name| name == "package.json") {
+            return ConfigFormat::PackageJson;
+        }
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => ConfigFormat::Yaml,
+            Some("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    pub(super) fn parse(self, content: &str) -> Result<Config> {
+        Ok(match self {
+            ConfigFormat::Json => serde_json::from_str(content)?,
+            ConfigFormat::Yaml => serde_yaml_ng::from_str(content)?,
+            ConfigFormat::Toml => toml::from_str(content)?,
+            ConfigFormat::PackageJson => serde_json::from_str::<PackageJson<Config>>(content)?
+                .rigor
+                .ok_or_else(|| anyhow::anyhow!("no \"rigor\" key"))?,
+        })
+    }
+}
+
+/// Config file in `dir`, by [`CONFIG_FILES`] precedence
+pub fn find_config_in_dir(dir: &Path) -> Option<PathBuf> {
+    CONFIG_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| match ConfigFormat::of(path) {
+            ConfigFormat::PackageJson => has_rigor_key(path),
+            _ => path.is_file(),
+        })
+}
+
+fn has_rigor_key(package_json: &Path) -> bool {
+    std::fs::read_to_string(package_json)
+        .ok()
+        .and_then(|content| {
+            serde_json::from_str::<PackageJson<serde::de::IgnoredAny>>(&content).ok()
+        })
+        .is_some_and(|package| package.rigor.is_some())
+}
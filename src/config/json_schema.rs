@@ -0,0 +1,352 @@
+//! JSON Schema for `.rigorrc.json`, printed by `rigor config schema` for editor completion
+//! and validation. Rule ids, categories, presets and operator groups come from the same
+//! tables rigor itself uses, so the schema cannot fall behind them.
+
+use crate::registry::{CATEGORIES, RULES};
+use crate::Rule;
+use serde_json::{json, Map, Value};
+
+/// Draft-07 schema for `.rigorrc.json`
+pub fn config_schema() -> Value {
+    let presets: Vec<String> = super::PRESETS.iter().map(|p| p.id()).collect();
+    let mut rules = Map::new();
+    for info in RULES {
+        let mut setting = match rule_options_schema(info.rule) {
+            Some(options) => json!({
+                "oneOf": [
+                    { "$ref": "#/definitions/severity" },
+                    {
+                        "type": "array",
+                        "items": [{ "$ref": "#/definitions/severity" }, options],
+                        "minItems": 1,
+                        "maxItems": 2
+                    }
+                ]
+            }),
+            None => json!({ "$ref": "#/definitions/severity" }),
+        };
+        setting["description"] = Value::String(info.description.to_string());
+        rules.insert(info.id.to_string(), setting);
+    }
+    let categories_or_penalty: Vec<&str> = CATEGORIES
+        .iter()
+        .copied()
+        .chain(std::iter::once(crate::analyzer::scoring::PENALTY_ONLY))
+        .collect();
+    let weight = json!({ "type": "integer", "minimum": 0, "maximum": 100 });
+    let points = json!({ "type": "integer", "minimum": 0, "maximum": 255 });
+    let glob_list = json!({ "type": "array", "items": { "type": "string" } });
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "rigor config (.rigorrc.json)",
+        "type": "object",
+        "additionalProperties": false,
+        "definitions": {
+            "severity": {
+                "enum": ["error", "warning", "info", "off"]
+            },
+            "rules": {
+                "description": "Severity per rule id, or [severity, options] for rules with options. Custom rule ids and plugin rules (name/rule) are also accepted.",
+                "type": "object",
+                "properties": rules,
+                "additionalProperties": {
+                    "oneOf": [
+                        { "$ref": "#/definitions/severity" },
+                        { "type": "array", "items": [{ "$ref": "#/definitions/severity" }], "maxItems": 1 }
+                    ]
+                }
+            },
+            "severityPoints": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": { "error": points, "warning": points, "info": points }
+            },
+            "categoryWeights": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "assertionQuality": weight,
+                    "errorCoverage": weight,
+                    "boundaryConditions": weight,
+                    "testIsolation": weight,
+                    "inputVariety": weight,
+                    "aiSmells": weight
+                }
+            }
+        },
+        "properties": {
+            "$schema": {
+                "description": "Schema reference for editors; ignored by rigor",
+                "type": "string"
+            },
+            "root": {
+                "description": "Stop looking for config files in parent directories",
+                "type": "boolean"
+            },
+            "extends": {
+                "description": "Configs to extend, in order: relative paths, npm packages or built-in presets",
+                "oneOf": [
+                    { "type": "string", "examples": presets },
+                    { "type": "array", "items": { "type": "string", "examples": presets } }
+                ]
+            },
+            "threshold": {
+                "description": "Minimum score (0-100); exit 1 if below",
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 100
+            },
+            "rules": { "$ref": "#/definitions/rules" },
+            "ignore": {
+                "description": "Glob patterns for files and directories to exclude",
+                "type": "array",
+                "items": { "type": "string" }
+            },
+            "framework": {
+                "description": "Force a test framework (default: auto-detect)",
+                "enum": ["auto", "jest", "vitest", "playwright", "cypress", "mocha"]
+            },
+            "sourceMapping": {
+                "description": "How test files are mapped to source files",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "mode": { "enum": ["auto", "tsconfig", "manual", "off"] },
+                    "mappings": {
+                        "description": "Test glob to source glob",
+                        "type": "object",
+                        "additionalProperties": { "type": "string" }
+                    },
+                    "sourceRoot": { "type": "string" },
+                    "testRoot": { "type": "string" }
+                }
+            },
+            "testPatterns": {
+                "description": "Test file suffixes (default: .test.ts, .spec.ts, ...)",
+                "type": "array",
+                "items": { "type": "string" }
+            },
+            "testRoot": {
+                "description": "Directory to search for test files, relative to the project root",
+                "type": "string"
+            },
+            "overrides": {
+                "description": "Per-path settings; later overrides win",
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "required": ["files"],
+                    "properties": {
+                        "files": glob_list,
+                        "threshold": { "type": "integer", "minimum": 0, "maximum": 100 },
+                        "rules": { "$ref": "#/definitions/rules" },
+                        "skipSourceAnalysis": { "type": "boolean" }
+                    }
+                }
+            },
+            "mutation": {
+                "description": "Mutation testing settings",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "testCommand": {
+                        "description": "Test command template; placeholders {testFile}, {sourceFile}, {testNamePattern}",
+                        "type": "string"
+                    },
+                    "operators": {
+                        "description": "Operator groups to switch on or off",
+                        "type": "object",
+                        "propertyNames": { "enum": crate::mutation::OPERATOR_GROUPS },
+                        "additionalProperties": { "type": "boolean" }
+                    },
+                    "customOperators": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "additionalProperties": false,
+                            "required": ["name", "from", "to"],
+                            "properties": {
+                                "name": { "type": "string" },
+                                "from": { "type": "string" },
+                                "to": { "type": "string" }
+                            }
+                        }
+                    },
+                    "exclude": glob_list,
+                    "excludeFunctions": { "type": "array", "items": { "type": "string" } },
+                    "seed": { "type": "integer", "minimum": 0 }
+                }
+            },
+            "customRules": {
+                "description": "Project rules defined by tree-sitter queries",
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "required": ["id", "query", "message"],
+                    "properties": {
+                        "id": { "type": "string" },
+                        "query": { "type": "string" },
+                        "inside": { "enum": ["anywhere", "test", "hook"] },
+                        "frameworks": {
+                            "type": "array",
+                            "items": { "enum": ["jest", "vitest", "playwright", "cypress", "mocha"] }
+                        },
+                        "message": { "type": "string" },
+                        "suggestion": { "type": "string" },
+                        "severity": { "$ref": "#/definitions/severity" },
+                        "category": { "enum": CATEGORIES }
+                    }
+                }
+            },
+            "plugins": {
+                "description": "WASM plugins providing third-party rules",
+                "type": "array",
+                "items": {
+                    "oneOf": [
+                        { "type": "string" },
+                        {
+                            "type": "object",
+                            "additionalProperties": false,
+                            "required": ["path"],
+                            "properties": {
+                                "path": { "type": "string" },
+                                "name": { "type": "string" },
+                                "memoryLimitMb": { "type": "integer", "minimum": 1 },
+                                "timeoutMs": { "type": "integer", "minimum": 1 }
+                            }
+                        }
+                    ]
+                }
+            },
+            "scoring": {
+                "description": "Category weights, penalties and rule categories",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "weights": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "unit": { "$ref": "#/definitions/categoryWeights" },
+                            "e2e": { "$ref": "#/definitions/categoryWeights" },
+                            "component": { "$ref": "#/definitions/categoryWeights" },
+                            "integration": { "$ref": "#/definitions/categoryWeights" }
+                        }
+                    },
+                    "penalties": { "$ref": "#/definitions/severityPoints" },
+                    "penaltyCaps": { "$ref": "#/definitions/severityPoints" },
+                    "categories": {
+                        "type": "object",
+                        "additionalProperties": { "enum": categories_or_penalty }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Schema of a rule's options object; `None` for rules without options. Kept in step with
+/// `check_rule_options`.
+fn rule_options_schema(rule: Rule) -> Option<Value> {
+    let count = |minimum: u32| json!({ "type": "integer", "minimum": minimum });
+    let properties = match rule {
+        Rule::MockAbuse => json!({ "maxMocks": count(0) }),
+        Rule::TestComplexity => json!({
+            "maxAssertions": count(0),
+            "maxLines": count(0),
+            "maxComplexity": count(0)
+        }),
+        Rule::ExcessiveSetup => json!({ "maxStatements": count(0) }),
+        Rule::RedundantTest => json!({ "minGroupSize": count(2) }),
+        _ => return None,
+    };
+    Some(json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": properties
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Field names serde expects for `T`, read from its unknown-field error
+    fn serde_fields<T: serde::de::DeserializeOwned + std::fmt::Debug>(json: &str) -> Vec<String> {
+        let err = serde_json::from_str::<T>(json).unwrap_err().to_string();
+        let expected = err
+            .split("expected one of ")
+            .nth(1)
+            .unwrap_or_else(|| panic!("not an unknown-field error: {}", err));
+        let mut fields: Vec<String> = expected
+            .split(" at line")
+            .next()
+            .unwrap()
+            .split(", ")
+            .map(|f| f.trim_matches('`').to_string())
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    fn schema_fields(schema: &Value) -> Vec<String> {
+        let mut fields: Vec<String> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    #[test]
+    fn test_schema_matches_config_types() {
+        use super::super::{Config, MutationConfig, ScoringConfig, SourceMappingConfig};
+
+        let schema = config_schema();
+        let top = &schema["properties"];
+        assert_eq!(
+            schema_fields(&schema),
+            serde_fields::<Config>(r#"{ "unknownField": 1 }"#)
+        );
+        assert_eq!(
+            schema_fields(&top["sourceMapping"]),
+            serde_fields::<SourceMappingConfig>(r#"{ "unknownField": 1 }"#)
+        );
+        assert_eq!(
+            schema_fields(&top["mutation"]),
+            serde_fields::<MutationConfig>(r#"{ "unknownField": 1 }"#)
+        );
+        assert_eq!(
+            schema_fields(&top["scoring"]),
+            serde_fields::<ScoringConfig>(r#"{ "unknownField": 1 }"#)
+        );
+        assert_eq!(
+            schema_fields(&top["overrides"]["items"]),
+            serde_fields::<Config>(r#"{ "overrides": [{ "files": [], "unknownField": 1 }] }"#)
+        );
+        assert_eq!(
+            schema_fields(&top["customRules"]["items"]),
+            serde_fields::<Config>(
+                r#"{ "customRules": [{ "id": "x", "query": "q", "message": "m", "unknownField": 1 }] }"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_rule_options_schema_matches_validation() {
+        for info in RULES {
+            let has_options = super::super::check_rule_options(info.id, &Map::new()).is_ok();
+            assert_eq!(
+                rule_options_schema(info.rule).is_some(),
+                has_options,
+                "{}",
+                info.id
+            );
+        }
+    }
+}
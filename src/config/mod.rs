@@ -1,13 +1,26 @@
 //! Configuration loading for Rigor
 
+mod cascade;
+mod format;
+mod json_schema;
+mod presets;
+mod provenance;
 mod schema;
 
+pub use cascade::ConfigResolver;
+pub use format::{find_config_in_dir, CONFIG_FILES};
+pub use json_schema::config_schema;
+pub use presets::{find_preset, Preset, PRESETS, PRESET_PREFIX};
+pub use provenance::{ConfigEntry, ConfigLayer, ConfigReport, Source};
+
 pub use schema::{
-    Config, ConfigOverride, EffectiveConfig, FrameworkOverride, RuleSeverity, SourceMappingConfig,
-    SourceMappingMode,
+    CategoryWeights, Config, ConfigOverride, CustomOperator, CustomRule, CustomRuleScope,
+    EffectiveConfig, FrameworkOverride, MutationConfig, PluginConfig, RuleSetting, RuleSeverity,
+    ScoringConfig, SeverityPoints, SourceMappingConfig, SourceMappingMode,
 };
 
 use anyhow::{Context, Result};
+use format::ConfigFormat;
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use std::collections::HashSet;
 use std::fs;
@@ -15,63 +28,401 @@ use std::path::{Path, PathBuf};
 
 pub const CONFIG_FILENAME: &str = ".rigorrc.json";
 
-/// Find and load config file with extends resolution. Searches current directory then parents.
+/// Find and load config with extends resolution. Every config file (any of
+/// [`CONFIG_FILES`]) from `work_dir` up to the repository root is merged, nearer ones taking
+/// precedence; a config with `"root": true` stops the search. `custom_path` is used alone.
 pub fn load_config(work_dir: &Path, custom_path: Option<&Path>) -> Result<Config> {
-    let path = if let Some(p) = custom_path {
+    load_config_layers(work_dir, custom_path).map(|(config, _)| config)
+}
+
+/// [`load_config`], also returning each config file and preset that went into the result as
+/// written, lowest precedence first (for `rigor config print`)
+pub fn load_config_layers(
+    work_dir: &Path,
+    custom_path: Option<&Path>,
+) -> Result<(Config, Vec<ConfigLayer>)> {
+    let paths = if let Some(p) = custom_path {
         let path = if p.is_absolute() {
             p.to_path_buf()
         } else {
             work_dir.join(p)
         };
-        if path.exists() {
-            Some(path)
-        } else {
+        if !path.exists() {
             anyhow::bail!("Config file not found: {}", path.display());
         }
+        vec![path]
     } else {
-        find_config_in_parents(work_dir)?
+        let mut paths = Vec::new();
+        for dir in config_dirs(work_dir) {
+            if let Some(path) = find_config_in_dir(&dir) {
+                let root = declares_root(&path);
+                paths.push(path);
+                if root {
+                    break;
+                }
+            }
+            if is_repository_root(&dir) {
+                break;
+            }
+        }
+        paths.reverse();
+        paths
     };
 
-    match path {
-        Some(path) => load_config_with_extends(&path, &mut HashSet::new()),
-        None => Ok(Config::default()),
+    // Patterns of an explicit --config stay relative to the working directory
+    let anchored = custom_path.is_none();
+    let mut config = Config::default();
+    let mut layers = Vec::new();
+    for path in paths {
+        let (own, mut own_layers) = load_config_file(&path, config, anchored)?;
+        config = own;
+        layers.append(&mut own_layers);
+    }
+    finish_config(&config)?;
+    Ok((config, layers))
+}
+
+/// Load one config file and its extends chain on top of `parent` (the merged config of the
+/// directories above it), with the layers that went into it. With `anchored`, its glob
+/// patterns are made relative to the file's directory.
+fn load_config_file(
+    path: &Path,
+    parent: Config,
+    anchored: bool,
+) -> Result<(Config, Vec<ConfigLayer>)> {
+    let mut loader = Loader {
+        inherited: parent.custom_rules.clone(),
+        ..Loader::default()
+    };
+    let mut config = load_config_with_extends(path, &mut loader)?;
+    if anchored {
+        config.anchor_patterns(path.parent().unwrap_or(Path::new(".")));
+    }
+    config.merge_from(parent);
+    Ok((config, loader.layers))
+}
+
+/// Whether a config file sets `"root": true`. Parse errors are left for loading to report.
+fn declares_root(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| ConfigFormat::of(path).parse(&content).ok())
+        .is_some_and(|config| config.root)
+}
+
+/// Checks that need the fully merged config, and registration of its custom rules
+fn finish_config(config: &Config) -> Result<()> {
+    validate_custom_rules(config)?;
+    for rule in &config.custom_rules {
+        crate::registry::register_custom(&rule.id, rule.category.as_deref(), &rule.message);
     }
+    validate_rule_options(config)?;
+    crate::analyzer::ScoringModel::from_config(&config.scoring)?;
+    Ok(())
+}
+
+/// `dir` and its parents, nearest first. A path relative to the working directory keeps its
+/// relative form (so config paths print as written) and continues above it.
+fn config_dirs(dir: &Path) -> Vec<PathBuf> {
+    let relative = dir.is_relative()
+        && !dir
+            .components()
+            .any(|c| c == std::path::Component::ParentDir);
+    if !relative {
+        let dir = if dir.is_relative() {
+            dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf())
+        } else {
+            dir.to_path_buf()
+        };
+        return dir.ancestors().map(Path::to_path_buf).collect();
+    }
+    let mut dirs: Vec<PathBuf> = dir
+        .ancestors()
+        .map(|d| match d.as_os_str().is_empty() {
+            true => PathBuf::from("."),
+            false => d.to_path_buf(),
+        })
+        .collect();
+    dirs.dedup();
+    if let Ok(cwd) = std::env::current_dir() {
+        dirs.extend(cwd.ancestors().skip(1).map(Path::to_path_buf));
+    }
+    dirs
+}
+
+/// Config files are not looked for above a git repository's root
+fn is_repository_root(dir: &Path) -> bool {
+    dir.join(".git").exists()
+}
+
+/// Check `["severity", { ... }]` options in `rules` and overrides against each rule's
+/// options type
+fn validate_rule_options(config: &Config) -> Result<()> {
+    let overrides = config.overrides.iter().flat_map(|o| &o.rules);
+    for (id, setting) in config.rules.iter().chain(overrides) {
+        if let Some(options) = &setting.options {
+            check_rule_options(id, options)
+                .map_err(|e| anyhow::anyhow!("rules: invalid options for '{}': {}", id, e))?;
+        }
+    }
+    Ok(())
+}
+
+fn check_rule_options(
+    id: &str,
+    options: &serde_json::Map<String, serde_json::Value>,
+) -> std::result::Result<(), String> {
+    use crate::analyzer::rules::{
+        ExcessiveSetupOptions, MockAbuseOptions, RedundantTestOptions, TestComplexityOptions,
+    };
+    use crate::Rule;
+
+    fn parse<T: serde::de::DeserializeOwned>(
+        options: &serde_json::Map<String, serde_json::Value>,
+    ) -> std::result::Result<(), String> {
+        serde_json::from_value::<T>(serde_json::Value::Object(options.clone()))
+            .map(drop)
+            .map_err(|e| e.to_string())
+    }
+
+    match crate::registry::rule_for_id(id) {
+        Some(Rule::MockAbuse) => parse::<MockAbuseOptions>(options),
+        Some(Rule::TestComplexity) => parse::<TestComplexityOptions>(options),
+        Some(Rule::ExcessiveSetup) => parse::<ExcessiveSetupOptions>(options),
+        Some(Rule::RedundantTest) => parse::<RedundantTestOptions>(options),
+        _ => Err("the rule takes no options".to_string()),
+    }
+}
+
+/// Reject custom rules whose id clashes with a built-in rule or another custom rule, whose
+/// query does not compile, or whose category is unknown
+fn validate_custom_rules(config: &Config) -> Result<()> {
+    let lang = crate::parser::TypeScriptParser::language();
+    let mut seen = HashSet::new();
+    for rule in &config.custom_rules {
+        if rule.id.is_empty() || rule.id.contains(char::is_whitespace) {
+            anyhow::bail!("customRules: invalid id {:?}", rule.id);
+        }
+        if crate::registry::find(&rule.id).is_some() {
+            anyhow::bail!("customRules: '{}' is a built-in rule id", rule.id);
+        }
+        if !seen.insert(rule.id.as_str()) {
+            anyhow::bail!("customRules: '{}' is declared twice", rule.id);
+        }
+        tree_sitter::Query::new(&lang, &rule.query)
+            .map_err(|e| anyhow::anyhow!("customRules: invalid query for '{}': {}", rule.id, e))?;
+        if let Some(category) = &rule.category {
+            if !crate::registry::CATEGORIES.contains(&category.as_str()) {
+                anyhow::bail!(
+                    "customRules: unknown category '{}' for '{}' (expected one of: {})",
+                    category,
+                    rule.id,
+                    crate::registry::CATEGORIES.join(", ")
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// State while resolving an extends chain
+#[derive(Default)]
+struct Loader {
+    /// Configs being loaded, to detect circular extends
+    visited: HashSet<PathBuf>,
+    /// Every config loaded so far as written, lowest precedence first
+    layers: Vec<ConfigLayer>,
+    /// Custom rules from config files in parent directories, which rule ids may refer to
+    inherited: Vec<CustomRule>,
 }
 
 /// Load a config file and resolve extends chain
-fn load_config_with_extends(config_path: &Path, visited: &mut HashSet<PathBuf>) -> Result<Config> {
-    // Prevent circular extends
+fn load_config_with_extends(config_path: &Path, loader: &mut Loader) -> Result<Config> {
+    let format = ConfigFormat::of(config_path);
+    let content = fs::read_to_string(config_path)
+        .with_context(|| format!("Failed to read config: {}", config_path.display()))?;
+    let mut config = format
+        .parse(&content)
+        .with_context(|| format!("Invalid {} in config: {}", format, config_path.display()))?;
+
+    // Plugin paths are relative to the config that lists them; resolved to one absolute
+    // spelling so configs reaching the same plugin by different routes dedupe on merge
+    let config_dir = config_path.parent().unwrap_or(Path::new("."));
+    for plugin in &mut config.plugins {
+        plugin.path = absolute_path(&config_dir.join(&plugin.path))
+            .to_string_lossy()
+            .to_string();
+    }
+
     let canonical = config_path
         .canonicalize()
         .unwrap_or_else(|_| config_path.to_path_buf());
-    if visited.contains(&canonical) {
+    let keys = rule_keys(&config);
+    let source = Source::File {
+        path: config_path.display().to_string(),
+    };
+    let config = apply_extends(config, config_path, canonical, source, loader)?;
+    let custom: Vec<CustomRule> = config
+        .custom_rules
+        .iter()
+        .chain(&loader.inherited)
+        .cloned()
+        .collect();
+    validate_rule_ids(&content, &keys, &custom)
+        .with_context(|| format!("Invalid config: {}", config_path.display()))?;
+    Ok(config)
+}
+
+/// Load a built-in preset (`rigor:<name>`) and resolve its extends chain
+fn load_preset(reference: &str, loader: &mut Loader) -> Result<Config> {
+    let preset = find_preset(reference).with_context(|| {
+        format!(
+            "Unknown preset '{}' (available: {})",
+            reference,
+            presets::preset_ids()
+        )
+    })?;
+    let config: Config = serde_json::from_str(preset.json)
+        .with_context(|| format!("Invalid built-in preset: {}", reference))?;
+    let keys = rule_keys(&config);
+    let source = Source::Preset { name: preset.id() };
+    let config = apply_extends(
+        config,
+        Path::new(reference),
+        PathBuf::from(preset.id()),
+        source,
+        loader,
+    )?;
+    validate_rule_ids(preset.json, &keys, &config.custom_rules)
+        .with_context(|| format!("Invalid built-in preset: {}", reference))?;
+    Ok(config)
+}
+
+/// Rule ids set in a config's own `rules` and overrides, with the section each is in
+fn rule_keys(config: &Config) -> Vec<(String, String)> {
+    let own = config
+        .rules
+        .keys()
+        .map(|id| ("rules".to_string(), id.clone()));
+    let overrides = config.overrides.iter().enumerate().flat_map(|(i, o)| {
+        o.rules
+            .keys()
+            .map(move |id| (format!("overrides[{}].rules", i), id.clone()))
+    });
+    own.chain(overrides).collect()
+}
+
+/// Reject rule ids that match no built-in rule, custom rule (including those from extended
+/// configs) or plugin (`name/rule`). Errors point at the key in `content`, like serde's.
+fn validate_rule_ids(
+    content: &str,
+    keys: &[(String, String)],
+    custom: &[CustomRule],
+) -> Result<()> {
+    // Report the first unknown id in the file
+    let Some((position, (section, id))) = keys
+        .iter()
+        .filter(|(_, id)| {
+            !id.contains('/')
+                && crate::registry::find(id).is_none()
+                && !custom.iter().any(|rule| &rule.id == id)
+        })
+        .map(|key| (key_position(content, &key.1), key))
+        .min()
+    else {
+        return Ok(());
+    };
+
+    let known = crate::registry::RULES
+        .iter()
+        .map(|info| info.id)
+        .chain(custom.iter().map(|rule| rule.id.as_str()));
+    let mut message = format!("{}: unknown rule '{}'", section, id);
+    if let Some(suggestion) = did_you_mean(id, known) {
+        message.push_str(&format!(" (did you mean '{}'?)", suggestion));
+    }
+    if let Some((line, column)) = position {
+        message.push_str(&format!(" at line {} column {}", line, column));
+    }
+    anyhow::bail!(message)
+}
+
+/// Closest of `candidates` to `input`, if any is close enough to be a likely typo
+fn did_you_mean<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (strsim::jaro_winkler(input, candidate), candidate))
+        .filter(|(similarity, _)| *similarity > 0.8)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, candidate)| candidate)
+}
+
+/// 1-based line and column of the first `key` in `content` followed by `:` or `=`. The key
+/// may be double-quoted, single-quoted or bare, so this covers JSON, YAML and TOML.
+fn key_position(content: &str, key: &str) -> Option<(usize, usize)> {
+    let offset = content.match_indices(key).find_map(|(start, _)| {
+        let end = start + key.len();
+        let (start, end) = match (
+            content[..start].chars().last(),
+            content[end..].chars().next(),
+        ) {
+            (Some(open @ ('"' | '\'')), Some(close)) if open == close => (start - 1, end + 1),
+            _ => (start, end),
+        };
+        let bounded = content[..start]
+            .chars()
+            .last()
+            .is_none_or(|c| c.is_whitespace() || matches!(c, '{' | ',' | '[' | '.'));
+        let assigned = content[end..]
+            .trim_start_matches([' ', '\t'])
+            .starts_with([':', '=']);
+        (bounded && assigned).then_some(start)
+    })?;
+    let before = &content[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = offset - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    Some((line, column))
+}
+
+/// Merge the configs listed in `config.extends` into it. Entries are applied in order, so
+/// later ones override earlier ones and `config` itself overrides them all. `key`
+/// identifies `config` for circular extends detection.
+fn apply_extends(
+    mut config: Config,
+    config_path: &Path,
+    key: PathBuf,
+    source: Source,
+    loader: &mut Loader,
+) -> Result<Config> {
+    // Prevent circular extends (a config may still be extended twice along different paths)
+    if !loader.visited.insert(key.clone()) {
         anyhow::bail!(
             "Circular extends detected in config: {}",
             config_path.display()
         );
     }
-    visited.insert(canonical.clone());
-
-    let content = fs::read_to_string(config_path)
-        .with_context(|| format!("Failed to read config: {}", config_path.display()))?;
-    let mut config: Config = serde_json::from_str(&content)
-        .with_context(|| format!("Invalid JSON in config: {}", config_path.display()))?;
 
-    // Resolve extends
-    if let Some(extends) = config.extends.take() {
-        let base_config = resolve_extends(config_path, &extends, visited)?;
-        config.merge_from(base_config);
+    let mut base = Config::default();
+    for reference in std::mem::take(&mut config.extends) {
+        let mut next = resolve_extends(config_path, &reference, loader)?;
+        next.merge_from(base);
+        base = next;
     }
+    loader.layers.push(ConfigLayer {
+        source,
+        config: config.clone(),
+    });
+    config.merge_from(base);
 
+    loader.visited.remove(&key);
     Ok(config)
 }
 
 /// Resolve an extends reference to a config
-fn resolve_extends(
-    config_path: &Path,
-    extends: &str,
-    visited: &mut HashSet<PathBuf>,
-) -> Result<Config> {
+fn resolve_extends(config_path: &Path, extends: &str, loader: &mut Loader) -> Result<Config> {
+    if extends.starts_with(PRESET_PREFIX) {
+        return load_preset(extends, loader);
+    }
+
     let config_dir = config_path.parent().unwrap_or(Path::new("."));
 
     // Try as relative path first
@@ -106,7 +457,7 @@ fn resolve_extends(
         );
     }
 
-    load_config_with_extends(&extends_path, visited)
+    load_config_with_extends(&extends_path, loader)
 }
 
 /// Try to find a config in node_modules
@@ -116,11 +467,14 @@ fn find_node_modules_config(start_dir: &Path, package: &str) -> Option<PathBuf>
         let node_modules = dir.join("node_modules").join(package);
 
         // Try common config file locations in the package
-        for filename in &[".rigorrc.json", "rigor.config.json", "index.json"] {
-            let candidate = node_modules.join(filename);
-            if candidate.exists() {
-                return Some(candidate);
-            }
+        let found = find_config_in_dir(&node_modules).or_else(|| {
+            ["rigor.config.json", "index.json"]
+                .iter()
+                .map(|filename| node_modules.join(filename))
+                .find(|candidate| candidate.exists())
+        });
+        if found.is_some() {
+            return found;
         }
 
         // Also check if it's directly a .json file reference
@@ -133,20 +487,6 @@ fn find_node_modules_config(start_dir: &Path, package: &str) -> Option<PathBuf>
     }
 }
 
-/// Search for .rigorrc.json in directory and its parents
-fn find_config_in_parents(mut dir: &Path) -> Result<Option<PathBuf>> {
-    loop {
-        let candidate = dir.join(CONFIG_FILENAME);
-        if candidate.exists() {
-            return Ok(Some(candidate));
-        }
-        dir = match dir.parent() {
-            Some(p) => p,
-            None => return Ok(None),
-        };
-    }
-}
-
 /// Build a GlobSet from ignore patterns for path matching
 pub fn build_ignore_set(patterns: &[String]) -> Result<GlobSet> {
     let mut builder = GlobSetBuilder::new();
@@ -160,7 +500,29 @@ pub fn build_ignore_set(patterns: &[String]) -> Result<GlobSet> {
 
 /// Check if a path should be ignored based on config glob patterns
 pub fn is_ignored(path: &Path, ignore_set: &GlobSet) -> bool {
-    ignore_set.is_match(path)
+    ignore_set.is_match(path) || ignore_set.is_match(absolute_path(path))
+}
+
+/// `path` made absolute against the working directory, with `.` and `..` resolved
+/// lexically; patterns anchored at a config's directory are matched against this
+fn absolute_path(path: &Path) -> PathBuf {
+    let joined = match path.is_absolute() {
+        true => path.to_path_buf(),
+        false => std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf()),
+    };
+    let mut normalized = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
 }
 
 /// Find the project root directory (containing package.json, .git, or config file)
@@ -170,7 +532,7 @@ pub fn find_project_root(start_dir: &Path) -> Option<PathBuf> {
         // Check for project markers
         if dir.join("package.json").exists()
             || dir.join(".git").exists()
-            || dir.join(CONFIG_FILENAME).exists()
+            || find_config_in_dir(dir).is_some()
         {
             return Some(dir.to_path_buf());
         }
@@ -187,7 +549,9 @@ mod tests {
     #[test]
     fn test_find_config_none() {
         let dir = std::env::temp_dir();
-        let result = find_config_in_parents(&dir).unwrap();
+        let result = config_dirs(&dir)
+            .iter()
+            .find_map(|dir| find_config_in_dir(dir));
         let _ = result;
     }
 
@@ -246,6 +610,50 @@ mod tests {
         assert!(config.ignore.contains(&"**/legacy/**".to_string()));
     }
 
+    #[test]
+    fn test_plugins_listed_twice_run_once() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("configs")).unwrap();
+        fs::write(
+            dir.path().join("shared.json"),
+            r#"{ "plugins": ["./plugins/no-sleep.wasm"] }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("configs").join("team.json"),
+            r#"{ "extends": "../shared.json", "plugins": ["../plugins/house.wasm"] }"#,
+        )
+        .unwrap();
+        // Reaches shared.json twice, and lists no-sleep again by another route
+        fs::write(
+            dir.path().join(CONFIG_FILENAME),
+            r#"{
+                "extends": ["./shared.json", "./configs/team.json"],
+                "plugins": [{ "path": "./configs/../plugins/no-sleep.wasm", "timeoutMs": 5000 }]
+            }"#,
+        )
+        .unwrap();
+
+        let config = load_config(dir.path(), None).unwrap();
+        let plugins: Vec<(String, Option<u64>)> = config
+            .plugins
+            .iter()
+            .map(|p| {
+                let path = Path::new(&p.path);
+                assert!(path.is_absolute(), "{}", p.path);
+                let name = path.file_name().unwrap().to_string_lossy().to_string();
+                (name, p.timeout_ms)
+            })
+            .collect();
+        assert_eq!(
+            plugins,
+            vec![
+                ("house.wasm".to_string(), None),
+                ("no-sleep.wasm".to_string(), Some(5000)),
+            ]
+        );
+    }
+
     #[test]
     fn test_config_overrides() {
         let config: Config = serde_json::from_str(
@@ -279,4 +687,313 @@ mod tests {
         let effective = config.effective_for_file(Path::new("src/auth.e2e.test.ts"));
         assert!(effective.skip_source_analysis);
     }
+
+    #[test]
+    fn test_custom_rules_are_validated_on_load() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_FILENAME);
+        let write = |custom_rules: &str| {
+            fs::write(&path, format!(r#"{{ "customRules": {} }}"#, custom_rules)).unwrap();
+        };
+
+        write(
+            r#"[{ "id": "no-reset-db", "query": "(call_expression) @match", "inside": "test",
+                 "message": "Do not reset the database in a test", "category": "Test Isolation" }]"#,
+        );
+        let config = load_config(dir.path(), None).unwrap();
+        assert_eq!(config.custom_rules[0].inside, CustomRuleScope::Test);
+        assert_eq!(config.custom_rules[0].severity, RuleSeverity::Warning);
+
+        write(r#"[{ "id": "no-reset-db", "query": "(call_expression", "message": "m" }]"#);
+        let err = load_config(dir.path(), None).unwrap_err().to_string();
+        assert!(err.contains("invalid query for 'no-reset-db'"), "{}", err);
+
+        write(r#"[{ "id": "weak-assertion", "query": "(call_expression) @c", "message": "m" }]"#);
+        assert!(load_config(dir.path(), None).is_err());
+
+        write(
+            r#"[{ "id": "x", "query": "(call_expression) @c", "message": "m", "category": "Speed" }]"#,
+        );
+        assert!(load_config(dir.path(), None).is_err());
+    }
+
+    #[test]
+    fn test_rule_options_are_validated_on_load() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_FILENAME);
+        let load = |rules: &str| {
+            fs::write(
+                &path,
+                format!(
+                    r#"{{ "rules": {}, "overrides": [{{ "files": ["**/it/**"], "rules": {{ "mock-abuse": "error" }} }}] }}"#,
+                    rules
+                ),
+            )
+            .unwrap();
+            load_config(dir.path(), None)
+        };
+
+        let config = load(r#"{ "mock-abuse": ["warning", { "maxMocks": 10 }] }"#).unwrap();
+        assert_eq!(
+            config.rule_severity("mock-abuse"),
+            Some(RuleSeverity::Warning)
+        );
+        // A severity-only override keeps the configured options
+        let effective = config.effective_for_file(Path::new("tests/it/orders.test.ts"));
+        assert_eq!(
+            effective.rule_severity("mock-abuse"),
+            Some(RuleSeverity::Error)
+        );
+        let options: crate::analyzer::rules::MockAbuseOptions =
+            effective.rule_options("mock-abuse");
+        assert_eq!(options.max_mocks, 10);
+
+        let err = load(r#"{ "mock-abuse": ["warning", { "maxMock": 10 }] }"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid options for 'mock-abuse'"), "{}", err);
+        assert!(load(r#"{ "mock-abuse": ["warning", { "maxMocks": "ten" }] }"#).is_err());
+        assert!(load(r#"{ "redundant-test": ["info", { "minGroupSize": 1 }] }"#).is_err());
+        assert!(load(r#"{ "weak-assertion": ["error", { "strict": true }] }"#).is_err());
+        assert!(load(r#"{ "mock-abuse": ["warning", {}, {}] }"#).is_err());
+    }
+
+    #[test]
+    fn test_scoring_is_merged_and_validated_on_load() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("base.json"),
+            r#"{ "scoring": {
+                "weights": { "integration": { "errorCoverage": 23, "aiSmells": 0 } },
+                "penalties": { "error": 10 },
+                "categories": { "mock-abuse": "Test Isolation" }
+            } }"#,
+        )
+        .unwrap();
+        let path = dir.path().join(CONFIG_FILENAME);
+        fs::write(
+            &path,
+            r#"{ "extends": "./base.json", "scoring": {
+                "penalties": { "info": 0 },
+                "categories": { "mock-abuse": "penalty" }
+            } }"#,
+        )
+        .unwrap();
+
+        let scoring = load_config(dir.path(), None).unwrap().scoring;
+        assert_eq!(
+            scoring.weights[&crate::TestType::Integration].error_coverage,
+            Some(23)
+        );
+        assert_eq!(scoring.penalties.error, Some(10));
+        assert_eq!(scoring.penalties.info, Some(0));
+        assert_eq!(scoring.categories["mock-abuse"], "penalty");
+
+        fs::write(
+            &path,
+            r#"{ "scoring": { "weights": { "unit": { "assertionQuality": 50 } } } }"#,
+        )
+        .unwrap();
+        let err = load_config(dir.path(), None).unwrap_err().to_string();
+        assert!(err.contains("weights total 130"), "{}", err);
+    }
+
+    #[test]
+    fn test_extends_presets_in_order() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("team.json"),
+            r#"{ "extends": "rigor:recommended", "threshold": 60, "rules": { "mock-abuse": "off" } }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join(CONFIG_FILENAME),
+            r#"{ "extends": ["rigor:strict", "./team.json"], "rules": { "debug-code": "warning" } }"#,
+        )
+        .unwrap();
+
+        let config = load_config(dir.path(), None).unwrap();
+        // team.json comes after rigor:strict, so its threshold and rules win
+        assert_eq!(config.threshold, Some(60));
+        assert_eq!(config.rule_severity("mock-abuse"), Some(RuleSeverity::Off));
+        assert_eq!(
+            config.rule_severity("weak-assertion"),
+            Some(RuleSeverity::Error)
+        );
+        assert_eq!(
+            config.rule_severity("debug-code"),
+            Some(RuleSeverity::Warning)
+        );
+        assert!(config.ignore.contains(&"**/node_modules/**".to_string()));
+
+        fs::write(
+            dir.path().join(CONFIG_FILENAME),
+            r#"{ "extends": "rigor:nonexistent" }"#,
+        )
+        .unwrap();
+        let err = format!("{:#}", load_config(dir.path(), None).unwrap_err());
+        assert!(
+            err.contains("Unknown preset 'rigor:nonexistent'"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_every_preset_loads() {
+        let dir = TempDir::new().unwrap();
+        for preset in PRESETS {
+            fs::write(
+                dir.path().join(CONFIG_FILENAME),
+                format!(r#"{{ "extends": "{}" }}"#, preset.id()),
+            )
+            .unwrap();
+            let config = load_config(dir.path(), None)
+                .unwrap_or_else(|e| panic!("{}: {:#}", preset.id(), e));
+            assert!(config.threshold.is_some(), "{}", preset.id());
+            for id in config.rules.keys() {
+                assert!(
+                    crate::registry::find(id).is_some(),
+                    "{}: {}",
+                    preset.id(),
+                    id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_rules_and_fields_are_rejected_with_location() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_FILENAME);
+        let load = |json: &str| {
+            fs::write(&path, json).unwrap();
+            load_config(dir.path(), None).map_err(|e| format!("{:#}", e))
+        };
+
+        let err = load("{\n  \"rules\": {\n    \"weak-assertions\": \"off\"\n  }\n}").unwrap_err();
+        assert!(
+            err.contains("rules: unknown rule 'weak-assertions' (did you mean 'weak-assertion'?) at line 3 column 5"),
+            "{}",
+            err
+        );
+        let err = load(r#"{ "overrides": [{ "files": ["**"], "rules": { "zzz": "off" } }] }"#)
+            .unwrap_err();
+        assert!(
+            err.contains("overrides[0].rules: unknown rule 'zzz' at line 1"),
+            "{}",
+            err
+        );
+        let err = load("{\n  \"treshold\": 80\n}").unwrap_err();
+        assert!(err.contains("unknown field `treshold`"), "{}", err);
+        assert!(err.contains("at line 2 column"), "{}", err);
+        assert!(load(r#"{ "scoring": { "penalty": { "error": 1 } } }"#).is_err());
+
+        // Plugin rules, custom rules from an extended config and $schema are accepted
+        fs::write(
+            dir.path().join("base.json"),
+            r#"{ "customRules": [{ "id": "no-reset-db", "query": "(call_expression) @c", "message": "m" }] }"#,
+        )
+        .unwrap();
+        load(
+            r#"{ "$schema": "./rigorrc.schema.json", "extends": "./base.json",
+                 "rules": { "no-reset-db": "error", "no-sleep/sleep-call": "off" } }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_yaml_toml_and_package_json_configs() {
+        let dir = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| fs::write(dir.path().join(name), content).unwrap();
+
+        write(
+            "package.json",
+            r#"{ "name": "app", "rigor": { "threshold": 60, "ignore": ["**/legacy/**"] } }"#,
+        );
+        let config = load_config(dir.path(), None).unwrap();
+        assert_eq!(config.threshold, Some(60));
+        assert_eq!(config.ignore, vec!["**/legacy/**"]);
+
+        write(
+            "rigor.toml",
+            "threshold = 70\n\n[rules]\nweak-assertion = \"error\"\nmock-abuse = [\"warning\", { maxMocks = 3 }]\n",
+        );
+        let config = load_config(dir.path(), None).unwrap();
+        assert_eq!(config.threshold, Some(70));
+        assert_eq!(config.rules["weak-assertion"].severity, RuleSeverity::Error);
+        assert_eq!(
+            config.rules["mock-abuse"].options.as_ref().unwrap()["maxMocks"],
+            3
+        );
+
+        // `off` stays a string in YAML
+        write(
+            ".rigorrc.yaml",
+            "threshold: 80\nrules:\n  snapshot-overuse: off\n  'weak-assertion': warning\n",
+        );
+        let config = load_config(dir.path(), None).unwrap();
+        assert_eq!(config.threshold, Some(80));
+        assert_eq!(config.rules["snapshot-overuse"].severity, RuleSeverity::Off);
+
+        write(".rigorrc.json", r#"{ "threshold": 90 }"#);
+        assert_eq!(load_config(dir.path(), None).unwrap().threshold, Some(90));
+
+        // package.json without a "rigor" key is not a config
+        let other = TempDir::new().unwrap();
+        fs::write(other.path().join("package.json"), r#"{ "name": "app" }"#).unwrap();
+        assert_eq!(find_config_in_dir(other.path()), None);
+    }
+
+    #[test]
+    fn test_extends_across_formats_and_format_errors() {
+        let dir = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| fs::write(dir.path().join(name), content).unwrap();
+        let load = || load_config(dir.path(), None).map_err(|e| format!("{:#}", e));
+
+        write(
+            "base.toml",
+            "threshold = 65\nignore = [\"**/fixtures/**\"]\n",
+        );
+        write(
+            "shared.yml",
+            "extends: ./base.toml\nrules:\n  weak-assertion: error\n",
+        );
+        write(
+            ".rigorrc.yaml",
+            "extends:\n  - ./shared.yml\n  - rigor:strict\nthreshold: 75\n",
+        );
+        let config = load().unwrap();
+        assert_eq!(config.threshold, Some(75));
+        assert!(config.ignore.contains(&"**/fixtures/**".to_string()));
+        assert_eq!(config.rules["weak-assertion"].severity, RuleSeverity::Error);
+
+        write("base.toml", "threshold = \"high\"\n");
+        let err = load().unwrap_err();
+        assert!(err.contains("Invalid TOML in config:"), "{}", err);
+        assert!(err.contains("base.toml"), "{}", err);
+
+        write(
+            ".rigorrc.yaml",
+            "threshold: 75\nrules:\n  weak-asertion: off\n",
+        );
+        let err = load().unwrap_err();
+        assert!(err.contains(".rigorrc.yaml"), "{}", err);
+        assert!(err.contains("unknown rule 'weak-asertion'"), "{}", err);
+        assert!(err.contains("at line 3 column 3"), "{}", err);
+
+        write(".rigorrc.yaml", "threshold: [\n");
+        let err = load().unwrap_err();
+        assert!(err.contains("Invalid YAML in config:"), "{}", err);
+
+        fs::remove_file(dir.path().join(".rigorrc.yaml")).unwrap();
+        write("package.json", r#"{ "rigor": { "treshold": 70 } }"#);
+        let err = load().unwrap_err();
+        assert!(
+            err.contains("Invalid package.json \"rigor\" key in config:"),
+            "{}",
+            err
+        );
+        assert!(err.contains("unknown field `treshold`"), "{}", err);
+    }
 }
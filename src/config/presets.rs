@@ -0,0 +1,71 @@
+//! Built-in config presets, embedded in the binary and referenced as `"extends": "rigor:<name>"`
+
+/// Prefix that marks a preset reference in `extends`
+pub const PRESET_PREFIX: &str = "rigor:";
+
+/// A named config shipped with rigor
+#[derive(Debug, Clone, Copy)]
+pub struct Preset {
+    /// Name without the `rigor:` prefix, e.g. `recommended`
+    pub name: &'static str,
+    /// One-line summary for `rigor init --help` and docs
+    pub description: &'static str,
+    /// The preset's `.rigorrc.json` content
+    pub json: &'static str,
+}
+
+impl Preset {
+    /// Reference to use in `extends`, e.g. `rigor:recommended`
+    pub fn id(&self) -> String {
+        format!("{}{}", PRESET_PREFIX, self.name)
+    }
+}
+
+pub const PRESETS: &[Preset] = &[
+    Preset {
+        name: "recommended",
+        description: "Balanced defaults: every rule at its documented severity, threshold 70",
+        json: include_str!("presets/recommended.json"),
+    },
+    Preset {
+        name: "strict",
+        description: "Every rule at error severity, threshold 85",
+        json: include_str!("presets/strict.json"),
+    },
+    Preset {
+        name: "react",
+        description: "React Testing Library rules elevated, component isolation emphasized",
+        json: include_str!("presets/react.json"),
+    },
+    Preset {
+        name: "api",
+        description: "Error coverage and boundary conditions emphasized, RTL rules off",
+        json: include_str!("presets/api.json"),
+    },
+    Preset {
+        name: "ai-review",
+        description: "Quality gate for AI-generated tests: AI smell rules at error, threshold 75",
+        json: include_str!("presets/ai-review.json"),
+    },
+    Preset {
+        name: "legacy",
+        description:
+            "Gradual adoption: threshold 40, most rules at info, info issues cost no points",
+        json: include_str!("presets/legacy.json"),
+    },
+];
+
+/// Look up a preset by `rigor:<name>` or bare `<name>`
+pub fn find_preset(reference: &str) -> Option<&'static Preset> {
+    let name = reference.strip_prefix(PRESET_PREFIX).unwrap_or(reference);
+    PRESETS.iter().find(|p| p.name == name)
+}
+
+/// Comma-separated preset ids, for error messages
+pub fn preset_ids() -> String {
+    PRESETS
+        .iter()
+        .map(Preset::id)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,429 @@
+//! Where each config value comes from, for `rigor config print`.
+//!
+//! Values are read from the merged config. Each is attributed to the highest-precedence
+//! layer (config file, preset or CLI flag) that sets it, or to the `overrides` entry that
+//! changed it for the file. `ignore`, `overrides` and `plugins` are concatenated across
+//! layers, so their entries are attributed one by one.
+
+use super::Config;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::path::Path;
+
+/// Lists that `Config::merge_from` concatenates (base entries first) instead of replacing
+const CONCATENATED: &[&str] = &["ignore", "overrides", "plugins"];
+
+/// Where a config value was set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Source {
+    /// Not set anywhere; rigor's default
+    Default,
+    /// A config file
+    File { path: String },
+    /// A built-in preset, e.g. `rigor:strict`
+    Preset { name: String },
+    /// A command-line flag, e.g. `--threshold`
+    Cli { flag: String },
+    /// An `overrides` entry matching the file. `index` is its position in the merged list.
+    Override {
+        index: usize,
+        files: Vec<String>,
+        from: Box<Source>,
+    },
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File { path } => write!(f, "{}", path),
+            Source::Preset { name } => write!(f, "{}", name),
+            Source::Cli { flag } => write!(f, "{}", flag),
+            Source::Override { index, files, from } => {
+                write!(
+                    f,
+                    "overrides[{}] ({}) from {}",
+                    index,
+                    files.join(", "),
+                    from
+                )
+            }
+        }
+    }
+}
+
+/// One config file, preset or CLI flag as written, before merging
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    pub source: Source,
+    pub config: Config,
+}
+
+impl ConfigLayer {
+    /// `--threshold` given on the command line
+    pub fn cli_threshold(threshold: u8) -> Self {
+        Self {
+            source: Source::Cli {
+                flag: "--threshold".to_string(),
+            },
+            config: Config {
+                threshold: Some(threshold),
+                ..Config::default()
+            },
+        }
+    }
+}
+
+/// A config value and where it came from. `key` is a dotted path, e.g. `rules.mock-abuse`
+/// or `ignore[2]`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigEntry {
+    pub key: String,
+    pub value: Value,
+    pub source: Source,
+}
+
+/// The config that applies to one file, with provenance
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigReport {
+    pub file: String,
+    /// Config files, presets and flags that were merged, lowest precedence first
+    pub sources: Vec<Source>,
+    /// What `Config::effective_for_file` gives for the file: threshold, whether source
+    /// analysis is skipped, and the severity (and options) of every rule
+    pub effective: Vec<ConfigEntry>,
+    /// The rest of the merged config
+    pub config: Vec<ConfigEntry>,
+}
+
+impl ConfigReport {
+    /// Explain `config` (merged from `layers`, lowest precedence first) for `file`
+    pub fn new(config: &Config, layers: &[ConfigLayer], file: &Path) -> Self {
+        let tracer = Tracer::new(layers);
+        let mut report = Self {
+            file: file.display().to_string(),
+            sources: layers.iter().map(|l| l.source.clone()).collect(),
+            effective: tracer.effective(config, file),
+            config: Vec::new(),
+        };
+        let mut merged = serde_json::to_value(config).unwrap_or_default();
+        if let Value::Object(map) = &mut merged {
+            // Reported per file in `effective`
+            map.remove("threshold");
+            map.remove("rules");
+        }
+        tracer.flatten(&merged, &mut Vec::new(), &mut report.config);
+        report
+    }
+}
+
+struct Tracer<'a> {
+    layers: &'a [ConfigLayer],
+    /// Each layer's config as JSON, so values can be looked up by key path
+    values: Vec<Value>,
+}
+
+impl<'a> Tracer<'a> {
+    fn new(layers: &'a [ConfigLayer]) -> Self {
+        let values = layers
+            .iter()
+            .map(|l| serde_json::to_value(&l.config).unwrap_or_default())
+            .collect();
+        Self { layers, values }
+    }
+
+    /// Highest layer that sets `path`
+    fn source_of(&self, path: &[String]) -> Source {
+        self.last_layer(|value| lookup(value, path).is_some())
+    }
+
+    fn last_layer(&self, sets: impl Fn(&Value) -> bool) -> Source {
+        self.values
+            .iter()
+            .zip(self.layers)
+            .rev()
+            .find(|(value, _)| sets(value))
+            .map_or(Source::Default, |(_, layer)| layer.source.clone())
+    }
+
+    /// Source of each entry of a concatenated list, in merged order
+    fn concatenated(&self, key: &str) -> Vec<Source> {
+        self.values
+            .iter()
+            .zip(self.layers)
+            .flat_map(|(value, layer)| {
+                let len = value.get(key).and_then(Value::as_array).map_or(0, Vec::len);
+                std::iter::repeat_n(layer.source.clone(), len)
+            })
+            .collect()
+    }
+
+    fn effective(&self, config: &Config, file: &Path) -> Vec<ConfigEntry> {
+        let effective = config.effective_for_file(file);
+        let override_sources = self.concatenated("overrides");
+        let matching: Vec<(&super::ConfigOverride, Source)> = config
+            .overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| Config::matches_override(file, &o.files))
+            .map(|(index, o)| {
+                let from = override_sources
+                    .get(index)
+                    .cloned()
+                    .unwrap_or(Source::Default);
+                let source = Source::Override {
+                    index,
+                    files: o.files.clone(),
+                    from: Box::new(from),
+                };
+                (o, source)
+            })
+            .collect();
+        // Last matching override that sets a value, if any
+        let from_override = |sets: &dyn Fn(&super::ConfigOverride) -> bool| {
+            matching
+                .iter()
+                .rev()
+                .find(|(o, _)| sets(o))
+                .map(|(_, source)| source.clone())
+        };
+
+        let mut entries = vec![
+            ConfigEntry {
+                key: "threshold".to_string(),
+                value: serde_json::to_value(effective.threshold).unwrap_or_default(),
+                source: from_override(&|o| o.threshold.is_some())
+                    .unwrap_or_else(|| self.source_of(&["threshold".to_string()])),
+            },
+            ConfigEntry {
+                key: "skipSourceAnalysis".to_string(),
+                value: Value::Bool(effective.skip_source_analysis),
+                source: from_override(&|o| o.skip_source_analysis.is_some())
+                    .unwrap_or(Source::Default),
+            },
+        ];
+
+        // Every built-in and custom rule, then plugin rules set in config
+        let mut ids: Vec<String> = crate::registry::RULES
+            .iter()
+            .map(|info| info.id.to_string())
+            .chain(config.custom_rules.iter().map(|rule| rule.id.clone()))
+            .collect();
+        let mut configured: Vec<&String> = effective
+            .rules
+            .keys()
+            .filter(|id| !ids.contains(id))
+            .collect();
+        configured.sort();
+        ids.extend(configured.into_iter().cloned());
+
+        for id in ids {
+            let key = format!("rules.{}", id);
+            let Some(setting) = effective.rules.get(&id) else {
+                let severity = match crate::registry::find(&id) {
+                    Some(info) => serde_json::to_value(info.default_severity),
+                    None => serde_json::to_value(
+                        config
+                            .custom_rules
+                            .iter()
+                            .find(|rule| rule.id == id)
+                            .map(|rule| rule.severity),
+                    ),
+                };
+                entries.push(ConfigEntry {
+                    key,
+                    value: severity.unwrap_or_default(),
+                    source: Source::Default,
+                });
+                continue;
+            };
+            let path = ["rules".to_string(), id.clone()];
+            entries.push(ConfigEntry {
+                key: key.clone(),
+                value: serde_json::to_value(setting.severity).unwrap_or_default(),
+                source: from_override(&|o| o.rules.contains_key(&id))
+                    .unwrap_or_else(|| self.source_of(&path)),
+            });
+            if let Some(options) = &setting.options {
+                let has_options = |rules: &std::collections::HashMap<_, super::RuleSetting>| {
+                    rules.get(&id).is_some_and(|s| s.options.is_some())
+                };
+                entries.push(ConfigEntry {
+                    key: format!("{}.options", key),
+                    value: Value::Object(options.clone()),
+                    source: from_override(&|o| has_options(&o.rules)).unwrap_or_else(|| {
+                        self.last_layer(|value| {
+                            lookup(value, &path).is_some_and(|v| v.get(1).is_some())
+                        })
+                    }),
+                });
+            }
+        }
+        entries
+    }
+
+    /// One entry per leaf of `value`, with objects expanded and concatenated lists split
+    fn flatten(&self, value: &Value, path: &mut Vec<String>, out: &mut Vec<ConfigEntry>) {
+        match value {
+            Value::Object(map) => {
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort();
+                for key in keys {
+                    path.push(key.clone());
+                    self.flatten(&map[key], path, out);
+                    path.pop();
+                }
+            }
+            Value::Array(items) if path.len() == 1 && CONCATENATED.contains(&path[0].as_str()) => {
+                let sources = self.concatenated(&path[0]);
+                for (i, item) in items.iter().enumerate() {
+                    out.push(ConfigEntry {
+                        key: format!("{}[{}]", path[0], i),
+                        value: item.clone(),
+                        source: sources.get(i).cloned().unwrap_or(Source::Default),
+                    });
+                }
+            }
+            // Custom rules are merged by id
+            Value::Array(items) if path.len() == 1 && path[0] == "customRules" => {
+                for (i, item) in items.iter().enumerate() {
+                    let id = item.get("id");
+                    out.push(ConfigEntry {
+                        key: format!("customRules[{}]", i),
+                        value: item.clone(),
+                        source: self.last_layer(|value| {
+                            value
+                                .get("customRules")
+                                .and_then(Value::as_array)
+                                .is_some_and(|rules| rules.iter().any(|r| r.get("id") == id))
+                        }),
+                    });
+                }
+            }
+            _ => out.push(ConfigEntry {
+                key: dotted(path),
+                value: value.clone(),
+                source: self.source_of(path),
+            }),
+        }
+    }
+}
+
+fn lookup<'v>(value: &'v Value, path: &[String]) -> Option<&'v Value> {
+    path.iter().try_fold(value, |value, key| value.get(key))
+}
+
+/// `a.b.c`, quoting keys that are not plain identifiers (globs, category names)
+fn dotted(path: &[String]) -> String {
+    path.iter()
+        .map(|key| {
+            let plain = key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/' | '$'));
+            if plain {
+                key.clone()
+            } else {
+                Value::String(key.clone()).to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn entry<'r>(entries: &'r [ConfigEntry], key: &str) -> &'r ConfigEntry {
+        entries
+            .iter()
+            .find(|e| e.key == key)
+            .unwrap_or_else(|| panic!("no entry {}", key))
+    }
+
+    #[test]
+    fn test_report_attributes_values_to_layers_and_overrides() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("team.json"),
+            r#"{ "ignore": ["**/gen/**"], "rules": { "mock-abuse": ["warning", { "maxMocks": 8 }] } }"#,
+        )
+        .unwrap();
+        let path = dir.path().join(super::super::CONFIG_FILENAME);
+        fs::write(
+            &path,
+            r#"{
+                "extends": ["rigor:legacy", "./team.json"],
+                "rules": { "mock-abuse": "error" },
+                "overrides": [{ "files": ["**/legacy/**"], "rules": { "weak-assertion": "off" } }]
+            }"#,
+        )
+        .unwrap();
+
+        let (config, mut layers) = super::super::load_config_layers(dir.path(), None).unwrap();
+        let config = config.merge_with_cli(Some(55), None);
+        layers.push(ConfigLayer::cli_threshold(55));
+        let file = Path::new("src/legacy/orders.test.ts");
+        let report = ConfigReport::new(&config, &layers, file);
+
+        let team = Source::File {
+            path: dir.path().join("./team.json").display().to_string(),
+        };
+        let own = Source::File {
+            path: path.display().to_string(),
+        };
+        let legacy = Source::Preset {
+            name: "rigor:legacy".to_string(),
+        };
+        assert_eq!(
+            report.sources,
+            vec![
+                Source::Preset {
+                    name: "rigor:recommended".to_string()
+                },
+                legacy.clone(),
+                team.clone(),
+                own.clone(),
+                layers[4].source.clone(),
+            ]
+        );
+
+        let threshold = entry(&report.effective, "threshold");
+        assert_eq!(threshold.value, 55);
+        assert_eq!(threshold.source.to_string(), "--threshold");
+
+        let weak = entry(&report.effective, "rules.weak-assertion");
+        assert_eq!(weak.value, "off");
+        assert_eq!(
+            weak.source,
+            Source::Override {
+                index: 1,
+                files: vec!["**/legacy/**".to_string()],
+                from: Box::new(own.clone()),
+            }
+        );
+        // Severity from the project config, options from team.json
+        assert_eq!(entry(&report.effective, "rules.mock-abuse").source, own);
+        assert_eq!(
+            entry(&report.effective, "rules.mock-abuse.options").source,
+            team
+        );
+        assert_eq!(entry(&report.effective, "rules.debug-code").source, legacy);
+        assert_eq!(
+            entry(&report.effective, "rules.empty-test").source,
+            Source::Default
+        );
+
+        assert_eq!(entry(&report.config, "ignore[2]").value, "**/gen/**");
+        assert_eq!(entry(&report.config, "ignore[2]").source, team);
+        assert_eq!(
+            entry(&report.config, "scoring.penalties.info").source,
+            legacy
+        );
+    }
+}
@@ -1,11 +1,14 @@
 //! Config schema and deserialization
 
-use serde::Deserialize;
+use serde::de::{self, DeserializeOwned, Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::fmt;
 use std::path::Path;
 
 /// Rule severity override (error, warning, info, off)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RuleSeverity {
     Error,
@@ -27,8 +30,78 @@ impl RuleSeverity {
     }
 }
 
+/// A `rules` entry: `"warning"`, or `["warning", { "maxMocks": 10 }]` to also set the
+/// rule's options
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleSetting {
+    pub severity: RuleSeverity,
+    /// Rule-specific options, checked against the rule's options type when the config loads
+    pub options: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl From<RuleSeverity> for RuleSetting {
+    fn from(severity: RuleSeverity) -> Self {
+        Self {
+            severity,
+            options: None,
+        }
+    }
+}
+
+impl RuleSetting {
+    /// Apply `other` on top of this setting. A severity-only setting keeps the options
+    /// already configured, as in ESLint.
+    fn apply(&mut self, other: &RuleSetting) {
+        self.severity = other.severity;
+        if other.options.is_some() {
+            self.options = other.options.clone();
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RuleSetting {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SettingVisitor;
+
+        impl<'de> Visitor<'de> for SettingVisitor {
+            type Value = RuleSetting;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a severity or a [severity, options] array")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<RuleSetting, E> {
+                RuleSeverity::deserialize(de::value::StrDeserializer::new(value))
+                    .map(RuleSetting::from)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RuleSetting, A::Error> {
+                let severity: RuleSeverity = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let options = seq.next_element()?;
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(3, &self));
+                }
+                Ok(RuleSetting { severity, options })
+            }
+        }
+
+        deserializer.deserialize_any(SettingVisitor)
+    }
+}
+
+impl Serialize for RuleSetting {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.options {
+            Some(options) => (self.severity, options).serialize(serializer),
+            None => self.severity.serialize(serializer),
+        }
+    }
+}
+
 /// Framework override: auto-detect or force a specific framework
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum FrameworkOverride {
     #[default]
@@ -41,7 +114,7 @@ pub enum FrameworkOverride {
 }
 
 /// Source mapping mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum SourceMappingMode {
     /// Auto-detect using common patterns (default)
@@ -56,93 +129,396 @@ pub enum SourceMappingMode {
 }
 
 /// Source mapping configuration
-#[derive(Debug, Clone, Deserialize, Default)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct SourceMappingConfig {
     /// Source mapping mode
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     pub mode: SourceMappingMode,
 
     /// Explicit glob-based mappings: test pattern -> source pattern
     /// e.g., "tests/**/*.test.ts" -> "src/**/*.ts"
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub mappings: HashMap<String, String>,
 
     /// Root directory for source files (relative to project root)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_root: Option<String>,
 
     /// Root directory for test files (relative to project root)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub test_root: Option<String>,
 }
 
+/// Mutation testing configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MutationConfig {
+    /// Test command template run for each mutant. Placeholders: `{testFile}` (test files
+    /// mapped to the mutated source), `{sourceFile}`, `{testNamePattern}`.
+    /// Default: derived from the detected framework (e.g. `npx vitest run {testFile}`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_command: Option<String>,
+
+    /// Operator groups to switch on or off, e.g. `{ "string": false }`. Groups not listed
+    /// stay enabled. See `mutation::OPERATOR_GROUPS` for the names.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub operators: HashMap<String, bool>,
+
+    /// Project-specific operators, applied alongside the built-in ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_operators: Vec<CustomOperator>,
+
+    /// Glob patterns (relative to the project root) for source files never mutated
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// Names of functions whose bodies are never mutated (e.g. `debugLog`, `toString`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_functions: Vec<String>,
+
+    /// Seed for sampling mutants when a run is capped (`--mutate quick`/`medium`). The same
+    /// seed picks the same mutants; change it to sample a different subset. Default: 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+impl MutationConfig {
+    /// True if `relative` (a source path relative to the project root) matches `exclude`
+    pub fn excludes_file(&self, relative: &Path) -> bool {
+        self.exclude.iter().any(|pattern| {
+            globset::Glob::new(pattern).is_ok_and(|glob| glob.compile_matcher().is_match(relative))
+        })
+    }
+}
+
+/// A project-specific mutation: every expression or token whose source text is exactly
+/// `from` is replaced with `to`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CustomOperator {
+    /// Shown in reports, e.g. `"floor-to-ceil"`
+    pub name: String,
+    /// Source text to match, e.g. `"Math.floor"`
+    pub from: String,
+    /// Replacement text, e.g. `"Math.ceil"`
+    pub to: String,
+}
+
+/// A project rule defined by a tree-sitter query, e.g.
+/// `{ "id": "no-reset-db", "query": "(call_expression function: (identifier) @fn (#eq? @fn \"resetDatabase\"))", "inside": "test", "message": "..." }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CustomRule {
+    /// Rule id used in output, `rules`, overrides and ignore directives
+    pub id: String,
+    /// Tree-sitter S-expression query. Each match is one issue, reported at the `@match`
+    /// capture if the query has one, otherwise at the match's first capture.
+    pub query: String,
+    /// Only report matches inside a test body or a hook. Default: anywhere
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub inside: CustomRuleScope,
+    /// Only run for these frameworks (e.g. `["jest", "vitest"]`). Default: all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frameworks: Vec<crate::TestFramework>,
+    /// Issue message
+    pub message: String,
+    /// Optional suggestion shown with the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+    /// Default severity (`rules` and overrides can still change it). Default: warning
+    #[serde(default = "default_custom_severity")]
+    pub severity: RuleSeverity,
+    /// Scoring category the rule reduces (e.g. `"Test Isolation"`). Default: penalty-only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+fn default_custom_severity() -> RuleSeverity {
+    RuleSeverity::Warning
+}
+
+/// Where a custom rule's matches count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomRuleScope {
+    #[default]
+    Anywhere,
+    /// Inside an `it`/`test` callback
+    Test,
+    /// Inside a `beforeEach`/`afterEach`/`beforeAll`/`afterAll` callback
+    Hook,
+}
+
+/// Leave a field that holds its default out of serialized config
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// `"a"` or `["a", "b"]`
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(reference) => vec![reference],
+        OneOrMany::Many(references) => references,
+    })
+}
+
+/// A WASM plugin: `"./plugins/no-sleep.wasm"` or
+/// `{ "path": "./plugins/no-sleep.wasm", "name": "house", "timeoutMs": 5000 }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "PluginConfigRepr", rename_all = "camelCase")]
+pub struct PluginConfig {
+    /// `.wasm` (or `.wat`) module, relative to the config file that lists it
+    pub path: String,
+    /// Namespace for the plugin's rule ids. Default: the file stem of `path`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Memory limit per instance in MiB. Default: 64
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_limit_mb: Option<u32>,
+    /// Time limit per file in milliseconds. Default: 2000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PluginConfigRepr {
+    Path(String),
+    Full(PluginConfigFull),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct PluginConfigFull {
+    path: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    memory_limit_mb: Option<u32>,
+    #[serde(default)]
+    timeout_ms: Option<u64>,
+}
+
+impl From<PluginConfigRepr> for PluginConfig {
+    fn from(repr: PluginConfigRepr) -> Self {
+        match repr {
+            PluginConfigRepr::Path(path) => Self {
+                path,
+                name: None,
+                memory_limit_mb: None,
+                timeout_ms: None,
+            },
+            PluginConfigRepr::Full(PluginConfigFull {
+                path,
+                name,
+                memory_limit_mb,
+                timeout_ms,
+            }) => Self {
+                path,
+                name,
+                memory_limit_mb,
+                timeout_ms,
+            },
+        }
+    }
+}
+
+/// Adjustments to the scoring model (`scoring` in config). Anything not set keeps the
+/// built-in model.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ScoringConfig {
+    /// Category weights per test type, e.g. `{ "integration": { "errorCoverage": 25, ... } }`.
+    /// Categories left out keep their default weight; each test type must total 100.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub weights: HashMap<crate::TestType, CategoryWeights>,
+    /// Points per penalty-only issue by severity
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub penalties: SeverityPoints,
+    /// Maximum total penalty by severity
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub penalty_caps: SeverityPoints,
+    /// Move a rule into a category (`"Test Isolation"`) or make it penalty-only (`"penalty"`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub categories: HashMap<String, String>,
+}
+
+/// Weight (percent) per scoring category
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CategoryWeights {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assertion_quality: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_coverage: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boundary_conditions: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_isolation: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_variety: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_smells: Option<u8>,
+}
+
+/// A value per issue severity
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SeverityPoints {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<u8>,
+}
+
+impl CategoryWeights {
+    fn merge_from(&mut self, base: CategoryWeights) {
+        self.assertion_quality = self.assertion_quality.or(base.assertion_quality);
+        self.error_coverage = self.error_coverage.or(base.error_coverage);
+        self.boundary_conditions = self.boundary_conditions.or(base.boundary_conditions);
+        self.test_isolation = self.test_isolation.or(base.test_isolation);
+        self.input_variety = self.input_variety.or(base.input_variety);
+        self.ai_smells = self.ai_smells.or(base.ai_smells);
+    }
+}
+
+impl SeverityPoints {
+    fn merge_from(&mut self, base: SeverityPoints) {
+        self.error = self.error.or(base.error);
+        self.warning = self.warning.or(base.warning);
+        self.info = self.info.or(base.info);
+    }
+}
+
+impl ScoringConfig {
+    /// Fill in anything this config leaves unset from `base`
+    fn merge_from(&mut self, base: ScoringConfig) {
+        for (test_type, weights) in base.weights {
+            self.weights
+                .entry(test_type)
+                .or_default()
+                .merge_from(weights);
+        }
+        self.penalties.merge_from(base.penalties);
+        self.penalty_caps.merge_from(base.penalty_caps);
+        for (rule, category) in base.categories {
+            self.categories.entry(rule).or_insert(category);
+        }
+    }
+}
+
 /// Per-path override configuration
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ConfigOverride {
     /// Glob patterns this override applies to
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub files: Vec<String>,
 
     /// Optional threshold override for matched files
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub threshold: Option<u8>,
 
     /// Optional rule overrides for matched files
-    #[serde(default)]
-    pub rules: HashMap<String, RuleSeverity>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub rules: HashMap<String, RuleSetting>,
 
     /// Skip source analysis for matched files (e.g., E2E tests)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip_source_analysis: Option<bool>,
 }
 
 /// Root config structure for .rigorrc.json
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Config {
-    /// Extend another config file (path relative to this config, or package name)
-    #[serde(default)]
-    pub extends: Option<String>,
+    /// JSON Schema reference for editors (see `rigor config schema`); ignored by rigor
+    #[serde(default, rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// Stop looking for config files in parent directories. Not inherited through extends.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub root: bool,
+
+    /// Configs to extend, in order: paths relative to this config, package names or
+    /// built-in presets (`rigor:recommended`). A single string or an array; later entries
+    /// take precedence over earlier ones.
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub extends: Vec<String>,
 
     /// Minimum score threshold (exit 1 if below). Default: 0
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub threshold: Option<u8>,
 
-    /// Per-rule severity overrides. Key is rule name in kebab-case.
-    #[serde(default)]
-    pub rules: HashMap<String, RuleSeverity>,
+    /// Per-rule severity and options. Key is rule name in kebab-case.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub rules: HashMap<String, RuleSetting>,
 
     /// Glob patterns for files/directories to exclude from analysis
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub ignore: Vec<String>,
 
     /// Force a specific test framework (default: auto)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     pub framework: FrameworkOverride,
 
     /// Source file mapping configuration
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default")]
     pub source_mapping: SourceMappingConfig,
 
     /// Custom test file patterns (default: *.test.ts, *.spec.ts, etc.)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub test_patterns: Vec<String>,
 
     /// Root directory to search for test files recursively (relative to project root)
     /// If not set, searches from the path provided on the command line
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub test_root: Option<String>,
 
     /// Per-path configuration overrides (for monorepos, legacy code, etc.)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub overrides: Vec<ConfigOverride>,
+
+    /// Mutation testing settings
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub mutation: MutationConfig,
+
+    /// Project rules defined by tree-sitter queries
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_rules: Vec<CustomRule>,
+
+    /// Third-party rules as WASM plugins (needs the `plugins` build feature)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<PluginConfig>,
+
+    /// Scoring model adjustments: weights, penalties and rule categories
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub scoring: ScoringConfig,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
-            extends: None,
+            schema: None,
+            root: false,
+            extends: Vec::new(),
             threshold: None,
             rules: HashMap::new(),
             ignore: Vec::new(),
@@ -151,6 +527,10 @@ impl Default for Config {
             test_patterns: Vec::new(),
             test_root: None,
             overrides: Vec::new(),
+            mutation: MutationConfig::default(),
+            custom_rules: Vec::new(),
+            plugins: Vec::new(),
+            scoring: ScoringConfig::default(),
         }
     }
 }
@@ -172,7 +552,7 @@ impl Config {
 
     /// Get the effective severity for a rule, or None if rule is off / not in config
     pub fn rule_severity(&self, rule_id: &str) -> Option<RuleSeverity> {
-        self.rules.get(rule_id).copied()
+        self.rules.get(rule_id).map(|setting| setting.severity)
     }
 
     /// Get effective config for a specific file path, applying overrides
@@ -189,8 +569,13 @@ impl Config {
                 if let Some(threshold) = override_cfg.threshold {
                     effective.threshold = Some(threshold);
                 }
-                for (rule, severity) in &override_cfg.rules {
-                    effective.rules.insert(rule.clone(), *severity);
+                for (rule, setting) in &override_cfg.rules {
+                    match effective.rules.entry(rule.clone()) {
+                        Entry::Occupied(mut own) => own.get_mut().apply(setting),
+                        Entry::Vacant(slot) => {
+                            slot.insert(setting.clone());
+                        }
+                    }
                 }
                 if let Some(skip) = override_cfg.skip_source_analysis {
                     effective.skip_source_analysis = skip;
@@ -202,12 +587,14 @@ impl Config {
     }
 
     /// Check if a file path matches any of the override patterns
-    fn matches_override(file_path: &Path, patterns: &[String]) -> bool {
+    pub(super) fn matches_override(file_path: &Path, patterns: &[String]) -> bool {
         let path_str = file_path.to_string_lossy();
+        let absolute = super::absolute_path(file_path);
         for pattern in patterns {
             if let Ok(glob) = globset::Glob::new(pattern) {
                 let matcher = glob.compile_matcher();
                 if matcher.is_match(file_path)
+                    || matcher.is_match(&absolute)
                     || path_str.contains(pattern.trim_start_matches("**/"))
                 {
                     return true;
@@ -217,22 +604,55 @@ impl Config {
         false
     }
 
+    /// Anchor `ignore` and `overrides[].files` patterns at `dir`, the directory of the config
+    /// file that declares them, so `fixtures/**` means `<dir>/fixtures/**` whichever
+    /// directory rigor runs from. Patterns starting with `**` match anywhere and are kept.
+    pub(super) fn anchor_patterns(&mut self, dir: &Path) {
+        let dir = globset::escape(&super::absolute_path(dir).to_string_lossy());
+        let anchor = |pattern: &mut String| {
+            if !pattern.starts_with("**") && !Path::new(pattern.as_str()).is_absolute() {
+                *pattern = format!(
+                    "{}/{}",
+                    dir.trim_end_matches('/'),
+                    pattern.trim_start_matches("./")
+                );
+            }
+        };
+        self.ignore.iter_mut().for_each(anchor);
+        for override_cfg in &mut self.overrides {
+            override_cfg.files.iter_mut().for_each(anchor);
+        }
+    }
+
     /// Merge another config into this one (for extends)
     pub fn merge_from(&mut self, base: Config) {
         // Base values are overridden by this config's values
         if self.threshold.is_none() {
             self.threshold = base.threshold;
         }
-        if self.extends.is_none() {
-            self.extends = base.extends;
+        // Base extends first, so the list keeps resolution order
+        let mut extends = base.extends;
+        for reference in std::mem::take(&mut self.extends) {
+            if !extends.contains(&reference) {
+                extends.push(reference);
+            }
         }
+        self.extends = extends;
         if self.framework == FrameworkOverride::Auto {
             self.framework = base.framework;
         }
 
-        // Merge rules (this config takes precedence)
-        for (rule, severity) in base.rules {
-            self.rules.entry(rule).or_insert(severity);
+        // Merge rules (this config takes precedence, but a bare severity keeps base options)
+        for (rule, mut setting) in base.rules {
+            match self.rules.entry(rule) {
+                Entry::Occupied(mut own) => {
+                    setting.apply(own.get());
+                    own.insert(setting);
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(setting);
+                }
+            }
         }
 
         // Merge ignore patterns
@@ -267,6 +687,47 @@ impl Config {
                 .or_insert(target);
         }
 
+        let mutation = &mut self.mutation;
+        if mutation.test_command.is_none() {
+            mutation.test_command = base.mutation.test_command;
+        }
+        for (group, enabled) in base.mutation.operators {
+            mutation.operators.entry(group).or_insert(enabled);
+        }
+        if mutation.custom_operators.is_empty() {
+            mutation.custom_operators = base.mutation.custom_operators;
+        }
+        if mutation.exclude.is_empty() {
+            mutation.exclude = base.mutation.exclude;
+        }
+        if mutation.exclude_functions.is_empty() {
+            mutation.exclude_functions = base.mutation.exclude_functions;
+        }
+        if mutation.seed.is_none() {
+            mutation.seed = base.mutation.seed;
+        }
+
+        // Custom rules: base rules first, this config's replace any with the same id
+        let mut custom_rules: Vec<CustomRule> = base
+            .custom_rules
+            .into_iter()
+            .filter(|rule| !self.custom_rules.iter().any(|own| own.id == rule.id))
+            .collect();
+        custom_rules.append(&mut self.custom_rules);
+        self.custom_rules = custom_rules;
+
+        self.scoring.merge_from(base.scoring);
+
+        // Base plugins run first; a plugin listed again (diamond `extends`, a nested config
+        // extending the same shared config) runs once, with this config's entry
+        let mut plugins: Vec<PluginConfig> = base
+            .plugins
+            .into_iter()
+            .filter(|plugin| !self.plugins.iter().any(|own| own.path == plugin.path))
+            .collect();
+        plugins.append(&mut self.plugins);
+        self.plugins = plugins;
+
         // Prepend base overrides
         let mut all_overrides = base.overrides;
         all_overrides.append(&mut self.overrides);
@@ -301,10 +762,27 @@ impl Config {
 #[derive(Debug, Clone)]
 pub struct EffectiveConfig {
     pub threshold: Option<u8>,
-    pub rules: HashMap<String, RuleSeverity>,
+    pub rules: HashMap<String, RuleSetting>,
     pub skip_source_analysis: bool,
 }
 
+impl EffectiveConfig {
+    /// Configured severity for a rule, if any
+    pub fn rule_severity(&self, rule_id: &str) -> Option<RuleSeverity> {
+        self.rules.get(rule_id).map(|setting| setting.severity)
+    }
+
+    /// Options for a rule, or its defaults. Options are validated when the config loads,
+    /// so invalid ones only reach here from a hand-built config and fall back to defaults.
+    pub fn rule_options<T: DeserializeOwned + Default>(&self, rule_id: &str) -> T {
+        self.rules
+            .get(rule_id)
+            .and_then(|setting| setting.options.clone())
+            .and_then(|options| serde_json::from_value(serde_json::Value::Object(options)).ok())
+            .unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,8 +807,8 @@ mod tests {
     #[test]
     fn test_rule_severity_from_config() {
         let mut rules = HashMap::new();
-        rules.insert("weak-assertion".to_string(), RuleSeverity::Error);
-        rules.insert("debug-code".to_string(), RuleSeverity::Off);
+        rules.insert("weak-assertion".to_string(), RuleSeverity::Error.into());
+        rules.insert("debug-code".to_string(), RuleSeverity::Off.into());
 
         let config = Config {
             rules,
@@ -366,8 +844,8 @@ mod tests {
         assert!(effective.skip_source_analysis);
         assert_eq!(effective.threshold, Some(50));
         assert_eq!(
-            effective.rules.get("weak-assertion"),
-            Some(&RuleSeverity::Off)
+            effective.rule_severity("weak-assertion"),
+            Some(RuleSeverity::Off)
         );
     }
 
@@ -391,8 +869,8 @@ mod tests {
         assert_eq!(effective.threshold, Some(70));
         assert!(!effective.skip_source_analysis);
         assert_eq!(
-            effective.rules.get("debug-code"),
-            Some(&RuleSeverity::Error)
+            effective.rule_severity("debug-code"),
+            Some(RuleSeverity::Error)
         );
     }
 
@@ -455,8 +933,8 @@ mod tests {
         };
 
         let mut base_rules = HashMap::new();
-        base_rules.insert("weak-assertion".to_string(), RuleSeverity::Error);
-        base_rules.insert("debug-code".to_string(), RuleSeverity::Warning);
+        base_rules.insert("weak-assertion".to_string(), RuleSeverity::Error.into());
+        base_rules.insert("debug-code".to_string(), RuleSeverity::Warning.into());
 
         let base = Config {
             threshold: Some(60),
@@ -472,10 +950,13 @@ mod tests {
         assert_eq!(child.threshold, Some(80));
         // Base rules inherited
         assert_eq!(
-            child.rules.get("weak-assertion"),
-            Some(&RuleSeverity::Error)
+            child.rule_severity("weak-assertion"),
+            Some(RuleSeverity::Error)
+        );
+        assert_eq!(
+            child.rule_severity("debug-code"),
+            Some(RuleSeverity::Warning)
         );
-        assert_eq!(child.rules.get("debug-code"), Some(&RuleSeverity::Warning));
         // Base ignore inherited
         assert!(child.ignore.contains(&"**/legacy/**".to_string()));
         // Base framework inherited when child is Auto
@@ -517,6 +998,50 @@ mod tests {
         assert_eq!(config.test_root, Some("tests".to_string()));
     }
 
+    #[test]
+    fn test_mutation_test_command_inherited() {
+        let mut child: Config = serde_json::from_str(r#"{ "mutation": {} }"#).unwrap();
+        let base: Config =
+            serde_json::from_str(r#"{ "mutation": { "testCommand": "npx jest {testFile}" } }"#)
+                .unwrap();
+
+        child.merge_from(base);
+        assert_eq!(
+            child.mutation.test_command.as_deref(),
+            Some("npx jest {testFile}")
+        );
+    }
+
+    #[test]
+    fn test_mutation_block_merges_with_base() {
+        let mut child: Config = serde_json::from_str(
+            r#"{ "mutation": { "operators": { "string": true }, "seed": 7 } }"#,
+        )
+        .unwrap();
+        let base: Config = serde_json::from_str(
+            r#"{
+                "mutation": {
+                    "operators": { "string": false, "return": false },
+                    "customOperators": [{ "name": "floor-to-ceil", "from": "Math.floor", "to": "Math.ceil" }],
+                    "exclude": ["src/generated/**"],
+                    "excludeFunctions": ["debugLog"],
+                    "seed": 1
+                }
+            }"#,
+        )
+        .unwrap();
+
+        child.merge_from(base);
+        let mutation = &child.mutation;
+        assert_eq!(mutation.operators.get("string"), Some(&true));
+        assert_eq!(mutation.operators.get("return"), Some(&false));
+        assert_eq!(mutation.custom_operators[0].to, "Math.ceil");
+        assert_eq!(mutation.exclude_functions, vec!["debugLog"]);
+        assert_eq!(mutation.seed, Some(7));
+        assert!(mutation.excludes_file(Path::new("src/generated/api.ts")));
+        assert!(!mutation.excludes_file(Path::new("src/api.ts")));
+    }
+
     #[test]
     fn test_multiple_overrides_applied_in_order() {
         let config: Config = serde_json::from_str(
@@ -0,0 +1,197 @@
+//! Cobertura XML coverage parser
+//!
+//! Parses `cobertura-coverage.xml` files produced by Istanbul's cobertura reporter,
+//! Jest/Vitest (`coverageReporters: ["cobertura"]`) and JVM-oriented CI tooling.
+//! Format reference: https://github.com/cobertura/cobertura/blob/master/cobertura/src/site/htdocs/xml/coverage-04.dtd
+
+use super::istanbul::{
+    calculate_summary, summarize_file, BranchCoverage, CoverageReport, FileCoverage,
+};
+use std::path::{Path, PathBuf};
+
+/// Parse a Cobertura XML report
+pub fn parse_cobertura_xml(content: &str) -> anyhow::Result<CoverageReport> {
+    // Cobertura reports usually declare `<!DOCTYPE coverage SYSTEM ...>`
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let doc = roxmltree::Document::parse_with_options(content, options).map_err(|e| {
+        let pos = e.pos();
+        anyhow::anyhow!(
+            "Failed to parse Cobertura XML at line {}, column {}: {}",
+            pos.row,
+            pos.col,
+            e
+        )
+    })?;
+
+    let root = doc.root_element();
+    if root.tag_name().name() != "coverage" {
+        anyhow::bail!(
+            "Not a Cobertura report: root element is <{}>, expected <coverage>",
+            root.tag_name().name()
+        );
+    }
+
+    // Class filenames are relative to the first <source> directory
+    let source_root = root
+        .descendants()
+        .find(|n| n.has_tag_name("source"))
+        .and_then(|n| n.text())
+        .map(|t| PathBuf::from(t.trim()));
+
+    let mut report = CoverageReport::default();
+
+    for class in root.descendants().filter(|n| n.has_tag_name("class")) {
+        let Some(filename) = class.attribute("filename") else {
+            continue;
+        };
+        let path = resolve_path(source_root.as_deref(), filename);
+
+        // A source file may be split over several <class> elements; merge them
+        let fc = report
+            .files
+            .entry(path.clone())
+            .or_insert_with(|| FileCoverage {
+                path,
+                ..Default::default()
+            });
+
+        for method in class.descendants().filter(|n| n.has_tag_name("method")) {
+            let Some(name) = method.attribute("name") else {
+                continue;
+            };
+            // A method is entered if any of its lines was hit
+            let hits = method
+                .descendants()
+                .filter(|n| n.has_tag_name("line"))
+                .filter_map(|n| n.attribute("hits"))
+                .filter_map(|h| h.parse::<u32>().ok())
+                .max()
+                .unwrap_or(0);
+            let entry = fc.functions.entry(name.to_string()).or_insert(0);
+            *entry = (*entry).max(hits);
+        }
+
+        // Class-level <lines> (method lines are repeated there, so only read direct children)
+        let class_lines = class
+            .children()
+            .filter(|n| n.has_tag_name("lines"))
+            .flat_map(|lines| lines.children().filter(|n| n.has_tag_name("line")));
+
+        for line in class_lines {
+            let Some(number) = line.attribute("number").and_then(|n| n.parse::<u32>().ok()) else {
+                continue;
+            };
+            let hits = line
+                .attribute("hits")
+                .and_then(|h| h.parse::<u32>().ok())
+                .unwrap_or(0);
+            let entry = fc.lines.entry(number).or_insert(0);
+            *entry = (*entry).max(hits);
+            fc.statements.insert(number.to_string(), *entry);
+
+            if line.attribute("branch") == Some("true") {
+                if let Some((taken, total)) = line
+                    .attribute("condition-coverage")
+                    .and_then(parse_condition_coverage)
+                {
+                    let coverage = (0..total).map(|i| u32::from(i < taken)).collect();
+                    fc.branches.insert(
+                        number.to_string(),
+                        BranchCoverage {
+                            line: number,
+                            coverage,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    for fc in report.files.values_mut() {
+        fc.summary = summarize_file(fc);
+    }
+    report.summary = calculate_summary(&report.files);
+    Ok(report)
+}
+
+fn resolve_path(source_root: Option<&Path>, filename: &str) -> PathBuf {
+    let file = PathBuf::from(filename);
+    match source_root {
+        Some(root) if file.is_relative() && !root.as_os_str().is_empty() => root.join(file),
+        _ => file,
+    }
+}
+
+/// Parse `condition-coverage="50% (1/2)"` into (taken, total)
+fn parse_condition_coverage(value: &str) -> Option<(u32, u32)> {
+    let inner = value.split_once('(')?.1.trim_end_matches(')');
+    let (taken, total) = inner.split_once('/')?;
+    Some((taken.trim().parse().ok()?, total.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" ?>
+<!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">
+<coverage lines-valid="4" lines-covered="2" line-rate="0.5" branch-rate="0.5" timestamp="1" version="0.1">
+  <sources>
+    <source>/repo</source>
+  </sources>
+  <packages>
+    <package name="src" line-rate="0.5" branch-rate="0.5">
+      <classes>
+        <class name="age.ts" filename="src/age.ts" line-rate="0.5" branch-rate="0.5">
+          <methods>
+            <method name="isAdult" hits="3" signature="()V">
+              <lines><line number="1" hits="3"/></lines>
+            </method>
+            <method name="canRent" hits="0" signature="()V">
+              <lines><line number="5" hits="0"/></lines>
+            </method>
+          </methods>
+          <lines>
+            <line number="1" hits="3"/>
+            <line number="2" hits="3" branch="true" condition-coverage="50% (1/2)"/>
+            <line number="5" hits="0"/>
+            <line number="6" hits="0"/>
+          </lines>
+        </class>
+      </classes>
+    </package>
+  </packages>
+</coverage>"#;
+
+    #[test]
+    fn test_parse_cobertura_xml() {
+        let report = parse_cobertura_xml(SAMPLE).unwrap();
+        assert_eq!(report.files.len(), 1);
+
+        let fc = report
+            .files
+            .get(&PathBuf::from("/repo/src/age.ts"))
+            .unwrap();
+        assert!(fc.is_line_covered(2));
+        assert!(!fc.is_line_covered(6));
+        assert_eq!(fc.uncovered_functions(), vec!["canRent"]);
+        assert_eq!(fc.summary.lines_total, 4);
+        assert_eq!(fc.summary.lines_covered, 2);
+        assert_eq!(fc.summary.branches_total, 2);
+        assert_eq!(fc.summary.branches_covered, 1);
+    }
+
+    #[test]
+    fn test_parse_condition_coverage() {
+        assert_eq!(parse_condition_coverage("50% (1/2)"), Some((1, 2)));
+        assert_eq!(parse_condition_coverage("100%"), None);
+    }
+
+    #[test]
+    fn test_parse_cobertura_wrong_root_fails() {
+        assert!(parse_cobertura_xml("<report/>").is_err());
+    }
+}
@@ -14,6 +14,9 @@ pub struct CoverageReport {
     pub files: HashMap<PathBuf, FileCoverage>,
     /// Overall summary statistics
     pub summary: CoverageSummary,
+    /// Files of a coverage directory that were skipped, with the reason
+    #[serde(skip)]
+    pub skipped: Vec<(PathBuf, String)>,
 }
 
 /// Coverage data for a single file
@@ -254,8 +257,52 @@ fn calculate_file_summary(coverage: &FileCoverage, data: &CoverageData) -> Cover
     }
 }
 
+/// Calculate summary statistics from an already-normalized FileCoverage.
+/// Used by formats (LCOV, Cobertura, V8) that have no Istanbul statement/function ids.
+pub(super) fn summarize_file(coverage: &FileCoverage) -> CoverageSummary {
+    let pct = |covered: u32, total: u32| {
+        if total > 0 {
+            (covered as f32 / total as f32) * 100.0
+        } else {
+            100.0
+        }
+    };
+
+    let lines_total = coverage.lines.len() as u32;
+    let lines_covered = coverage.lines.values().filter(|&&c| c > 0).count() as u32;
+    let statements_total = coverage.statements.len() as u32;
+    let statements_covered = coverage.statements.values().filter(|&&c| c > 0).count() as u32;
+    let functions_total = coverage.functions.len() as u32;
+    let functions_covered = coverage.functions.values().filter(|&&c| c > 0).count() as u32;
+    let branches_total: u32 = coverage
+        .branches
+        .values()
+        .map(|b| b.coverage.len() as u32)
+        .sum();
+    let branches_covered: u32 = coverage
+        .branches
+        .values()
+        .map(|b| b.coverage.iter().filter(|&&c| c > 0).count() as u32)
+        .sum();
+
+    CoverageSummary {
+        lines_pct: pct(lines_covered, lines_total),
+        lines_covered,
+        lines_total,
+        branches_pct: pct(branches_covered, branches_total),
+        branches_covered,
+        branches_total,
+        functions_pct: pct(functions_covered, functions_total),
+        functions_covered,
+        functions_total,
+        statements_pct: pct(statements_covered, statements_total),
+        statements_covered,
+        statements_total,
+    }
+}
+
 /// Calculate overall summary from all file coverages
-fn calculate_summary(files: &HashMap<PathBuf, FileCoverage>) -> CoverageSummary {
+pub(super) fn calculate_summary(files: &HashMap<PathBuf, FileCoverage>) -> CoverageSummary {
     let mut total = CoverageSummary::default();
 
     for fc in files.values() {
@@ -319,6 +366,46 @@ impl FileCoverage {
 }
 
 impl CoverageReport {
+    /// Merge another report into this one (hit counts take the maximum per line,
+    /// function, statement and branch path), then recompute summaries
+    pub fn merge(&mut self, other: CoverageReport) {
+        for (path, incoming) in other.files {
+            let Some(existing) = self.files.get_mut(&path) else {
+                self.files.insert(path, incoming);
+                continue;
+            };
+            for (line, count) in incoming.lines {
+                let entry = existing.lines.entry(line).or_insert(0);
+                *entry = (*entry).max(count);
+            }
+            for (name, count) in incoming.functions {
+                let entry = existing.functions.entry(name).or_insert(0);
+                *entry = (*entry).max(count);
+            }
+            for (id, count) in incoming.statements {
+                let entry = existing.statements.entry(id).or_insert(0);
+                *entry = (*entry).max(count);
+            }
+            for (id, branch) in incoming.branches {
+                let entry = existing
+                    .branches
+                    .entry(id)
+                    .or_insert_with(|| BranchCoverage {
+                        line: branch.line,
+                        coverage: Vec::new(),
+                    });
+                if entry.coverage.len() < branch.coverage.len() {
+                    entry.coverage.resize(branch.coverage.len(), 0);
+                }
+                for (slot, count) in entry.coverage.iter_mut().zip(branch.coverage) {
+                    *slot = (*slot).max(count);
+                }
+            }
+            existing.summary = summarize_file(existing);
+        }
+        self.summary = calculate_summary(&self.files);
+    }
+
     /// Get coverage for a specific file path
     pub fn get_file_coverage(&self, path: &Path) -> Option<&FileCoverage> {
         // Try exact match first
@@ -326,6 +413,29 @@ impl CoverageReport {
             return Some(fc);
         }
 
+        // Coverage tools record absolute paths; the mapped source may be relative
+        if let Ok(canonical) = path.canonicalize() {
+            if let Some(fc) = self.files.get(&canonical) {
+                return Some(fc);
+            }
+        }
+
+        // Try matching by path suffix (e.g. "src/utils.ts" vs "/repo/src/utils.ts")
+        let relative: PathBuf = path
+            .components()
+            .filter(|c| matches!(c, std::path::Component::Normal(_)))
+            .collect();
+        if relative.components().count() > 1 {
+            if let Some(fc) = self
+                .files
+                .iter()
+                .find(|(p, _)| p.ends_with(&relative))
+                .map(|(_, fc)| fc)
+            {
+                return Some(fc);
+            }
+        }
+
         // Try matching by file name
         let file_name = path.file_name()?;
         for (p, fc) in &self.files {
@@ -383,4 +493,28 @@ mod tests {
         assert!(!uncovered.contains(&1));
         assert!(!uncovered.contains(&3));
     }
+
+    #[test]
+    fn test_get_file_coverage_matches_relative_suffix() {
+        let mut report = CoverageReport::default();
+        report.files.insert(
+            PathBuf::from("/repo/src/utils.ts"),
+            FileCoverage {
+                path: PathBuf::from("/repo/src/utils.ts"),
+                ..Default::default()
+            },
+        );
+        report.files.insert(
+            PathBuf::from("/repo/lib/utils.ts"),
+            FileCoverage {
+                path: PathBuf::from("/repo/lib/utils.ts"),
+                ..Default::default()
+            },
+        );
+
+        let fc = report
+            .get_file_coverage(Path::new("./lib/utils.ts"))
+            .unwrap();
+        assert_eq!(fc.path, PathBuf::from("/repo/lib/utils.ts"));
+    }
 }
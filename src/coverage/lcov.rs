@@ -0,0 +1,158 @@
+//! LCOV tracefile parser
+//!
+//! Parses `lcov.info` files produced by Vitest, c8 (`--reporter=lcov`), nyc, and genhtml tooling.
+//! Format reference: https://github.com/linux-test-project/lcov/blob/master/man/geninfo.1
+
+use super::istanbul::{
+    calculate_summary, summarize_file, BranchCoverage, CoverageReport, FileCoverage,
+};
+use std::path::PathBuf;
+
+/// Parse an LCOV tracefile
+pub fn parse_lcov(content: &str) -> anyhow::Result<CoverageReport> {
+    let mut report = CoverageReport::default();
+    let mut current: Option<FileCoverage> = None;
+
+    for (idx, raw_line) in content.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "end_of_record" {
+            if let Some(mut fc) = current.take() {
+                fc.summary = summarize_file(&fc);
+                report.files.insert(fc.path.clone(), fc);
+            }
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        if key == "SF" {
+            current = Some(FileCoverage {
+                path: PathBuf::from(value),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        // Records other than TN must belong to an open SF section
+        let Some(fc) = current.as_mut() else {
+            if key == "TN" {
+                continue;
+            }
+            anyhow::bail!(
+                "Invalid LCOV at line {}: '{}' record outside of an SF section",
+                idx + 1,
+                key
+            );
+        };
+
+        let fields: Vec<&str> = value.split(',').collect();
+        let parse_count = |s: &str| -> u32 {
+            // "-" marks a branch that was never evaluated
+            s.trim()
+                .parse::<u64>()
+                .map(|n| n.min(u32::MAX as u64) as u32)
+                .unwrap_or(0)
+        };
+
+        match key {
+            // DA:<line>,<hits>[,<checksum>]
+            "DA" if fields.len() >= 2 => {
+                if let Ok(line_no) = fields[0].trim().parse::<u32>() {
+                    let count = parse_count(fields[1]);
+                    let entry = fc.lines.entry(line_no).or_insert(0);
+                    *entry = (*entry).max(count);
+                    fc.statements.insert(line_no.to_string(), *entry);
+                }
+            }
+            // FN:<line>,<name> — declares the function; FNDA supplies the hit count
+            "FN" if fields.len() >= 2 => {
+                let name = fields[1..].join(",");
+                fc.functions.entry(name).or_insert(0);
+            }
+            // FNDA:<hits>,<name>
+            "FNDA" if fields.len() >= 2 => {
+                let name = fields[1..].join(",");
+                let count = parse_count(fields[0]);
+                let entry = fc.functions.entry(name).or_insert(0);
+                *entry = (*entry).max(count);
+            }
+            // BRDA:<line>,<block>,<branch>,<taken>
+            "BRDA" if fields.len() >= 4 => {
+                if let Ok(line_no) = fields[0].trim().parse::<u32>() {
+                    let id = format!("{}:{}", line_no, fields[1].trim());
+                    let branch = fc.branches.entry(id).or_insert_with(|| BranchCoverage {
+                        line: line_no,
+                        coverage: Vec::new(),
+                    });
+                    branch.coverage.push(parse_count(fields[3]));
+                }
+            }
+            // TN, FNF, FNH, LF, LH, BRF, BRH and unknown records carry no per-line data
+            _ => {}
+        }
+    }
+
+    // Tolerate a missing trailing end_of_record
+    if let Some(mut fc) = current.take() {
+        fc.summary = summarize_file(&fc);
+        report.files.insert(fc.path.clone(), fc);
+    }
+
+    report.summary = calculate_summary(&report.files);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "TN:
+SF:src/age.ts
+FN:1,isAdult
+FN:5,canRent
+FNDA:3,isAdult
+FNDA:0,canRent
+FNF:2
+FNH:1
+BRDA:2,0,0,2
+BRDA:2,0,1,-
+DA:1,3
+DA:2,3
+DA:3,0
+DA:5,0
+DA:6,0
+LF:5
+LH:2
+end_of_record
+SF:src/name.ts
+DA:1,1
+end_of_record
+";
+
+    #[test]
+    fn test_parse_lcov() {
+        let report = parse_lcov(SAMPLE).unwrap();
+        assert_eq!(report.files.len(), 2);
+
+        let fc = report.files.get(&PathBuf::from("src/age.ts")).unwrap();
+        assert!(fc.is_line_covered(2));
+        assert!(!fc.is_line_covered(3));
+        assert_eq!(fc.uncovered_functions(), vec!["canRent"]);
+        assert_eq!(fc.summary.lines_total, 5);
+        assert_eq!(fc.summary.lines_covered, 2);
+        assert_eq!(fc.summary.branches_total, 2);
+        assert_eq!(fc.summary.branches_covered, 1);
+        assert_eq!(report.summary.lines_total, 6);
+    }
+
+    #[test]
+    fn test_parse_lcov_record_outside_section_fails() {
+        let err = parse_lcov("DA:1,1\nend_of_record\n").unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+    }
+}
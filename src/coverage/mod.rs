@@ -1,23 +1,137 @@
-//! Coverage tool integration - parse and use coverage data from Istanbul/c8
+//! Coverage tool integration - parse and use coverage data from Istanbul, LCOV, Cobertura and V8
 
+mod cobertura;
 mod istanbul;
+mod lcov;
+mod v8;
 
+pub use cobertura::parse_cobertura_xml;
 pub use istanbul::{parse_istanbul_json, CoverageData, CoverageReport, FileCoverage};
+pub use lcov::parse_lcov;
+pub use v8::parse_v8_json;
 
+use std::collections::HashSet;
 use std::path::Path;
 
-/// Load coverage data from a file (auto-detects format)
+/// Coverage file formats accepted by `--coverage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageFormat {
+    /// Istanbul/nyc/c8/Jest `coverage-final.json`
+    Istanbul,
+    /// LCOV tracefile (`lcov.info`)
+    Lcov,
+    /// Cobertura XML
+    Cobertura,
+    /// Raw V8 JSON from `NODE_V8_COVERAGE`
+    V8,
+}
+
+impl std::fmt::Display for CoverageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoverageFormat::Istanbul => write!(f, "Istanbul JSON"),
+            CoverageFormat::Lcov => write!(f, "LCOV"),
+            CoverageFormat::Cobertura => write!(f, "Cobertura XML"),
+            CoverageFormat::V8 => write!(f, "V8 JSON"),
+        }
+    }
+}
+
+/// Detect the coverage format from file contents (extensions are unreliable:
+/// `lcov.info`, `coverage.xml`, `coverage-1234-1.json` etc.)
+pub fn detect_format(content: &str) -> Option<CoverageFormat> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+    if trimmed.starts_with('<') {
+        return Some(CoverageFormat::Cobertura);
+    }
+    if trimmed.starts_with('{') {
+        // V8 output is { "result": [ { "url": ..., "functions": [...] } ] }
+        let value: serde_json::Value = serde_json::from_str(trimmed).ok()?;
+        let is_v8 = value
+            .get("result")
+            .and_then(|r| r.as_array())
+            .is_some_and(|scripts| scripts.iter().all(|s| s.get("functions").is_some()));
+        return Some(if is_v8 {
+            CoverageFormat::V8
+        } else {
+            CoverageFormat::Istanbul
+        });
+    }
+    let is_lcov = trimmed
+        .lines()
+        .take(20)
+        .any(|l| l.starts_with("SF:") || l.starts_with("TN:"));
+    is_lcov.then_some(CoverageFormat::Lcov)
+}
+
+/// Parse coverage data in any supported format (auto-detected from contents)
+pub fn parse_coverage(content: &str) -> anyhow::Result<CoverageReport> {
+    match detect_format(content) {
+        Some(CoverageFormat::Istanbul) => parse_istanbul_json(content),
+        Some(CoverageFormat::Lcov) => parse_lcov(content),
+        Some(CoverageFormat::Cobertura) => parse_cobertura_xml(content),
+        Some(CoverageFormat::V8) => parse_v8_json(content),
+        None => anyhow::bail!(
+            "Unrecognized coverage format (expected Istanbul JSON, LCOV, Cobertura XML or V8 JSON)"
+        ),
+    }
+}
+
+/// Load coverage data from a file (auto-detects format).
+/// A directory (e.g. `NODE_V8_COVERAGE` output) is loaded file by file and merged; files
+/// that aren't coverage in a known format are listed in [`CoverageReport::skipped`].
 pub fn load_coverage(path: &Path) -> anyhow::Result<CoverageReport> {
+    if path.is_dir() {
+        let mut entries: Vec<_> = std::fs::read_dir(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read coverage directory: {}", e))?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.is_file())
+            .collect();
+        entries.sort();
+
+        let mut report = CoverageReport::default();
+        for entry in entries {
+            // Directories can hold other files too; skip anything that isn't coverage
+            let part = std::fs::read_to_string(&entry)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| parse_coverage(&content));
+            match part {
+                Ok(part) => report.merge(part),
+                Err(e) => report.skipped.push((entry, e.to_string())),
+            }
+        }
+        return Ok(report);
+    }
+
     let content = std::fs::read_to_string(path)
         .map_err(|e| anyhow::anyhow!("Failed to read coverage file: {}", e))?;
+    parse_coverage(&content)
+}
+
+/// Lines and functions of a source file that a coverage report shows are never executed.
+/// Coverage-aware rules escalate findings in this code from warnings to errors.
+#[derive(Debug, Clone, Default)]
+pub struct UncoveredCode {
+    lines: HashSet<u32>,
+    functions: HashSet<String>,
+}
 
-    // Try Istanbul/c8 JSON format
-    if path.extension().is_some_and(|ext| ext == "json") {
-        return parse_istanbul_json(&content);
+impl UncoveredCode {
+    pub fn new(coverage: &FileCoverage) -> Self {
+        Self {
+            lines: coverage.uncovered_lines().into_iter().collect(),
+            functions: coverage
+                .uncovered_functions()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
     }
 
-    // Default to Istanbul JSON
-    parse_istanbul_json(&content)
+    /// True if the 1-based source `line`, or the function named `function`, never runs
+    pub fn contains(&self, line: usize, function: &str) -> bool {
+        self.lines.contains(&(line as u32)) || self.functions.contains(function)
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +210,73 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            detect_format(r#"{"/a.ts": {"path": "/a.ts"}}"#),
+            Some(CoverageFormat::Istanbul)
+        );
+        assert_eq!(
+            detect_format(r#"{"result": [{"url": "file:///a.js", "functions": []}]}"#),
+            Some(CoverageFormat::V8)
+        );
+        assert_eq!(
+            detect_format("TN:\nSF:src/a.ts\nDA:1,1\nend_of_record\n"),
+            Some(CoverageFormat::Lcov)
+        );
+        assert_eq!(
+            detect_format("<?xml version=\"1.0\"?>\n<coverage></coverage>"),
+            Some(CoverageFormat::Cobertura)
+        );
+        assert_eq!(detect_format("not valid json"), None);
+    }
+
+    #[test]
+    fn test_load_coverage_lcov_by_content() {
+        let mut file = NamedTempFile::with_suffix(".info").unwrap();
+        write!(file, "SF:src/a.ts\nDA:1,1\nDA:2,0\nend_of_record\n").unwrap();
+        file.flush().unwrap();
+
+        let report = load_coverage(file.path()).unwrap();
+        let fc = report.get_file_coverage(Path::new("src/a.ts")).unwrap();
+        assert_eq!(fc.uncovered_lines(), vec![2]);
+    }
+
+    #[test]
+    fn test_load_coverage_directory_merges_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("a.info"),
+            "SF:/src/a.ts\nDA:1,0\nDA:2,0\nend_of_record\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("b.info"),
+            "SF:/src/a.ts\nDA:1,3\nend_of_record\nSF:/src/b.ts\nDA:1,1\nend_of_record\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.txt"), "ignored").unwrap();
+        std::fs::write(
+            dir.path().join("profile.json"),
+            r#"{"result": [{"functions": []}]}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("trace.bin"), [0xff, 0xfe, 0x00]).unwrap();
+
+        let report = load_coverage(dir.path()).unwrap();
+        assert_eq!(report.files.len(), 2);
+        let skipped: Vec<_> = report
+            .skipped
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(skipped, vec!["README.txt", "profile.json", "trace.bin"]);
+        let fc = report.get_file_coverage(Path::new("/src/a.ts")).unwrap();
+        assert!(fc.is_line_covered(1));
+        assert!(!fc.is_line_covered(2));
+        assert_eq!(report.summary.lines_total, 3);
+    }
+
     #[test]
     fn test_load_coverage_invalid_json() {
         let mut file = NamedTempFile::with_suffix(".json").unwrap();
@@ -0,0 +1,280 @@
+//! Raw V8 coverage parser
+//!
+//! Parses the JSON files Node writes to `NODE_V8_COVERAGE` (one file per process).
+//! V8 reports byte ranges per function (`startOffset`/`endOffset`/`count`) rather than
+//! lines, so each script's source is read from disk and every non-blank line is assigned
+//! the count of the innermost range containing its first non-whitespace character.
+//! Offsets are matched against the file on disk; for transpiled TypeScript prefer
+//! c8's Istanbul or LCOV output, which applies source maps.
+//! Format reference: https://v8.dev/blog/javascript-code-coverage
+
+use super::istanbul::{
+    calculate_summary, summarize_file, BranchCoverage, CoverageReport, FileCoverage,
+};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Deserialize)]
+struct V8Coverage {
+    result: Vec<V8Script>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct V8Script {
+    url: String,
+    #[serde(default)]
+    functions: Vec<V8Function>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct V8Function {
+    #[serde(default)]
+    function_name: String,
+    #[serde(default)]
+    ranges: Vec<V8Range>,
+    #[serde(default)]
+    is_block_coverage: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct V8Range {
+    start_offset: usize,
+    end_offset: usize,
+    count: u32,
+}
+
+/// Parse a raw V8 coverage JSON file, reading each script's source from disk
+pub fn parse_v8_json(content: &str) -> anyhow::Result<CoverageReport> {
+    parse_v8_json_with(content, |path| std::fs::read_to_string(path).ok())
+}
+
+/// Parse raw V8 coverage using `read_source` to load script sources (scripts whose
+/// source can't be loaded are skipped, since offsets can't be mapped to lines)
+pub(super) fn parse_v8_json_with(
+    content: &str,
+    read_source: impl Fn(&Path) -> Option<String>,
+) -> anyhow::Result<CoverageReport> {
+    let raw: V8Coverage = serde_json::from_str(content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse V8 coverage JSON: {}", e))?;
+
+    let mut report = CoverageReport::default();
+
+    for script in &raw.result {
+        let Some(path) = file_url_to_path(&script.url) else {
+            continue; // node: internals, eval'd code, etc.
+        };
+        if path.components().any(|c| c.as_os_str() == "node_modules") {
+            continue;
+        }
+        let Some(source) = read_source(&path) else {
+            continue;
+        };
+
+        let fc = script_coverage(path, &source, &script.functions);
+        report.files.insert(fc.path.clone(), fc);
+    }
+
+    report.summary = calculate_summary(&report.files);
+    Ok(report)
+}
+
+/// A source line: first non-whitespace offset in UTF-16 code units (V8's unit)
+struct LineInfo {
+    number: u32,
+    first_offset: usize,
+}
+
+fn line_infos(source: &str) -> Vec<LineInfo> {
+    let mut infos = Vec::new();
+    let mut offset = 0usize;
+    for (idx, line) in source.split('\n').enumerate() {
+        let mut first = None;
+        let mut col = 0usize;
+        for ch in line.chars() {
+            if first.is_none() && !ch.is_whitespace() {
+                first = Some(offset + col);
+            }
+            col += ch.len_utf16();
+        }
+        if let Some(first_offset) = first {
+            infos.push(LineInfo {
+                number: idx as u32 + 1,
+                first_offset,
+            });
+        }
+        offset += col + 1; // '\n'
+    }
+    infos
+}
+
+fn script_coverage(path: PathBuf, source: &str, functions: &[V8Function]) -> FileCoverage {
+    let lines = line_infos(source);
+    let mut fc = FileCoverage {
+        path,
+        ..Default::default()
+    };
+
+    // Innermost range wins, so check ranges from smallest to largest
+    let mut ranges: Vec<V8Range> = functions
+        .iter()
+        .flat_map(|f| f.ranges.iter().copied())
+        .collect();
+    ranges.sort_by_key(|r| r.end_offset.saturating_sub(r.start_offset));
+
+    for line in &lines {
+        if let Some(range) = ranges
+            .iter()
+            .find(|r| r.start_offset <= line.first_offset && line.first_offset < r.end_offset)
+        {
+            fc.lines.insert(line.number, range.count);
+            fc.statements.insert(line.number.to_string(), range.count);
+        }
+    }
+
+    let line_of = |offset: usize| -> u32 {
+        lines
+            .iter()
+            .take_while(|l| l.first_offset <= offset)
+            .last()
+            .map(|l| l.number)
+            .unwrap_or(1)
+    };
+
+    for (fn_idx, function) in functions.iter().enumerate() {
+        let Some(entry_range) = function.ranges.first() else {
+            continue;
+        };
+        if !function.function_name.is_empty() {
+            let entry = fc
+                .functions
+                .entry(function.function_name.clone())
+                .or_insert(0);
+            *entry = (*entry).max(entry_range.count);
+        }
+        // Nested block ranges record how often each block inside the function ran
+        if function.is_block_coverage {
+            for (range_idx, block) in function.ranges.iter().enumerate().skip(1) {
+                fc.branches.insert(
+                    format!("{}:{}", fn_idx, range_idx),
+                    BranchCoverage {
+                        line: line_of(block.start_offset),
+                        coverage: vec![block.count],
+                    },
+                );
+            }
+        }
+    }
+
+    fc.summary = summarize_file(&fc);
+    fc
+}
+
+/// Convert a `file://` URL to a path (percent-decoded); None for other schemes
+fn file_url_to_path(url: &str) -> Option<PathBuf> {
+    let rest = url.strip_prefix("file://")?;
+    let raw = rest.as_bytes();
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut i = 0;
+    while i < raw.len() {
+        let escape = match raw.get(i..i + 3) {
+            Some([b'%', hi, lo]) => hex_value(*hi).zip(hex_value(*lo)),
+            _ => None,
+        };
+        match escape {
+            Some((hi, lo)) => {
+                bytes.push((hi << 4) | lo);
+                i += 3;
+            }
+            None => {
+                bytes.push(raw[i]);
+                i += 1;
+            }
+        }
+    }
+    // Escapes that don't decode to UTF-8 are kept as written
+    let decoded = String::from_utf8(bytes).unwrap_or_else(|_| rest.to_string());
+    // file:///C:/x → C:/x on Windows
+    let decoded = match decoded.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => decoded[1..].to_string(),
+        _ => decoded,
+    };
+    Some(PathBuf::from(decoded))
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|d| d as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "function isAdult(age) {\n  if (age < 0) {\n    throw new Error('neg');\n  }\n  return age >= 18;\n}\n\nfunction unused() {\n  return 1;\n}\n\nisAdult(20);\n";
+
+    fn offset_of(needle: &str) -> usize {
+        SOURCE.find(needle).unwrap()
+    }
+
+    #[test]
+    fn test_parse_v8_json() {
+        let json = format!(
+            r#"{{"result":[
+                {{"scriptId":"1","url":"node:internal/x","functions":[]}},
+                {{"scriptId":"2","url":"file:///repo/src/age%20check.js","functions":[
+                    {{"functionName":"","isBlockCoverage":true,"ranges":[{{"startOffset":0,"endOffset":{len},"count":1}}]}},
+                    {{"functionName":"isAdult","isBlockCoverage":true,"ranges":[
+                        {{"startOffset":0,"endOffset":{isadult_end},"count":1}},
+                        {{"startOffset":{throw_start},"endOffset":{throw_end},"count":0}}
+                    ]}},
+                    {{"functionName":"unused","isBlockCoverage":false,"ranges":[{{"startOffset":{unused_start},"endOffset":{unused_end},"count":0}}]}}
+                ]}}
+            ]}}"#,
+            len = SOURCE.len(),
+            isadult_end = offset_of("\n\nfunction unused"),
+            throw_start = offset_of("{\n    throw"),
+            throw_end = offset_of("  return age"),
+            unused_start = offset_of("function unused"),
+            unused_end = offset_of("\n\nisAdult(20)"),
+        );
+
+        let report = parse_v8_json_with(&json, |path| {
+            assert_eq!(path, Path::new("/repo/src/age check.js"));
+            Some(SOURCE.to_string())
+        })
+        .unwrap();
+        assert_eq!(report.files.len(), 1, "node: internals are skipped");
+
+        let fc = report
+            .files
+            .get(&PathBuf::from("/repo/src/age check.js"))
+            .unwrap();
+        assert!(fc.is_line_covered(1));
+        assert!(!fc.is_line_covered(3), "throw block never ran");
+        assert!(fc.is_line_covered(5));
+        assert!(!fc.is_line_covered(9), "unused() never ran");
+        assert!(fc.is_line_covered(12));
+        assert_eq!(fc.uncovered_functions(), vec!["unused"]);
+        assert_eq!(fc.summary.branches_total, 1);
+        assert_eq!(fc.summary.branches_covered, 0);
+    }
+
+    #[test]
+    fn test_file_url_to_path() {
+        assert_eq!(
+            file_url_to_path("file:///a/b%20c.ts"),
+            Some(PathBuf::from("/a/b c.ts"))
+        );
+        assert_eq!(file_url_to_path("node:fs"), None);
+        // Non-ASCII after '%', truncated and non-UTF-8 escapes are kept literally
+        assert_eq!(file_url_to_path("file:///a%é"), Some(PathBuf::from("/a%é")));
+        assert_eq!(file_url_to_path("file:///a%2"), Some(PathBuf::from("/a%2")));
+        assert_eq!(
+            file_url_to_path("file:///a%FF%20b"),
+            Some(PathBuf::from("/a%FF%20b"))
+        );
+        assert_eq!(file_url_to_path("file:///a%20"), Some(PathBuf::from("/a ")));
+    }
+}
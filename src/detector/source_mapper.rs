@@ -296,7 +296,7 @@ impl SourceMapper {
         loop {
             if dir.join("package.json").exists()
                 || dir.join(".git").exists()
-                || dir.join(".rigorrc.json").exists()
+                || crate::config::find_config_in_dir(dir).is_some()
             {
                 return Some(dir.to_path_buf());
             }
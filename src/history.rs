@@ -146,6 +146,7 @@ mod tests {
             framework: TestFramework::Jest,
             test_type: TestType::Unit,
             source_file: None,
+            package: None,
         }
     }
 
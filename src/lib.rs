@@ -13,9 +13,12 @@ pub mod history;
 pub mod mcp;
 pub mod mutation;
 pub mod parser;
+pub mod plugin;
+pub mod registry;
 pub mod reporter;
 pub mod suggestions;
 pub mod watcher;
+pub mod workspace;
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -47,6 +50,9 @@ pub struct AnalysisResult {
     pub test_type: TestType,
     /// Path to the corresponding source file (if found)
     pub source_file: Option<PathBuf>,
+    /// Workspace package the file belongs to (monorepos only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
 }
 
 /// Quality score with grade
@@ -132,6 +138,16 @@ pub struct TransparentBreakdown {
     pub penalty_from_warnings: i32,
     /// Penalty from info-severity issues
     pub penalty_from_info: i32,
+    /// Penalty sizes and caps used (from `scoring` in config, or the defaults)
+    #[serde(default)]
+    pub penalty_model: PenaltyModel,
+    /// Rules moved by `scoring.categories`: rule id to category name, or `"penalty"`
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub category_overrides: std::collections::BTreeMap<String, String>,
+    /// Categories of the custom rules in the file's config: rule id to category name, or
+    /// `"penalty"`
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub custom_categories: std::collections::BTreeMap<String, String>,
     /// Final score after penalties (0-100)
     pub final_score: u8,
     /// When the final score differs from (total_before_penalties - penalty_total)
@@ -141,6 +157,18 @@ pub struct TransparentBreakdown {
     pub per_test_aggregated: Option<u8>,
 }
 
+/// Points each penalty-only issue costs, and the most each severity can cost in total
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PenaltyModel {
+    pub per_error: i32,
+    pub per_warning: i32,
+    pub per_info: i32,
+    pub max_from_errors: i32,
+    pub max_from_warnings: i32,
+    pub max_from_info: i32,
+}
+
 /// Letter grade
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Grade {
@@ -296,99 +324,24 @@ pub enum Rule {
     ParrotAssertion,
     /// Boilerplate padding (generic setup, low signal)
     BoilerplatePadding,
+    /// A mutant in the mapped source file survived the tests (from mutation results)
+    SurvivingMutant,
+    /// An assertion the tests still pass with when it is inverted (from `--mutate-tests`)
+    WeightlessAssertion,
+    /// Rule declared in config (`customRules`), serialized as its id
+    #[serde(untagged)]
+    Custom(registry::RuleId),
 }
 
 /// Scoring category name for transparent breakdown and verbose output.
 /// Returns the category name if this rule affects a category score; None if it only affects penalty.
 pub fn rule_scoring_category(rule: &Rule) -> Option<&'static str> {
-    use Rule::*;
-    match rule {
-        WeakAssertion
-        | NoAssertions
-        | TrivialAssertion
-        | AssertionIntentMismatch
-        | MutationResistant
-        | BoundarySpecificity
-        | StateVerification
-        | BehavioralCompleteness
-        | SideEffectNotVerified => Some("Assertion Quality"),
-        MissingErrorTest | ReturnPathCoverage => Some("Error Coverage"),
-        MissingBoundaryTest => Some("Boundary Conditions"),
-        SharedState => Some("Test Isolation"),
-        HardcodedValues | LimitedInputVariety | DuplicateTest => Some("Input Variety"),
-        // Phase 2.2 rules: penalty-only (do not map to a core scoring category)
-        TestComplexity
-        | ImplementationCoupling
-        | VacuousTest
-        | IncompleteMockVerification
-        | AsyncErrorMishandling
-        | RedundantTest
-        | UnreachableTestCode
-        | ExcessiveSetup
-        | TypeAssertionAbuse
-        | MissingCleanup => None,
-        // Phase 2.3 AI smells (dedicated category)
-        TautologicalAssertion
-        | OverMocking
-        | ShallowVariety
-        | HappyPathOnly
-        | ParrotAssertion
-        | BoilerplatePadding => Some("AI Smells"),
-        // These only affect penalty, not category score
-        DebugCode | FocusedTest | SkippedTest | EmptyTest | FlakyPattern | MockAbuse
-        | SnapshotOveruse | VagueTestName | MissingAwait | RtlPreferScreen | RtlPreferSemantic
-        | RtlPreferUserEvent => None,
-    }
+    rule.category()
 }
 
 impl std::fmt::Display for Rule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Rule::WeakAssertion => write!(f, "weak-assertion"),
-            Rule::MissingErrorTest => write!(f, "missing-error-test"),
-            Rule::MissingBoundaryTest => write!(f, "missing-boundary-test"),
-            Rule::SharedState => write!(f, "shared-state"),
-            Rule::HardcodedValues => write!(f, "hardcoded-values"),
-            Rule::NoAssertions => write!(f, "no-assertions"),
-            Rule::SkippedTest => write!(f, "skipped-test"),
-            Rule::EmptyTest => write!(f, "empty-test"),
-            Rule::DuplicateTest => write!(f, "duplicate-test"),
-            Rule::LimitedInputVariety => write!(f, "limited-input-variety"),
-            Rule::DebugCode => write!(f, "debug-code"),
-            Rule::FocusedTest => write!(f, "focused-test"),
-            Rule::FlakyPattern => write!(f, "flaky-pattern"),
-            Rule::MockAbuse => write!(f, "mock-abuse"),
-            Rule::SnapshotOveruse => write!(f, "snapshot-overuse"),
-            Rule::VagueTestName => write!(f, "vague-test-name"),
-            Rule::MissingAwait => write!(f, "missing-await"),
-            Rule::RtlPreferScreen => write!(f, "rtl-prefer-screen"),
-            Rule::RtlPreferSemantic => write!(f, "rtl-prefer-semantic"),
-            Rule::RtlPreferUserEvent => write!(f, "rtl-prefer-user-event"),
-            Rule::MutationResistant => write!(f, "mutation-resistant"),
-            Rule::BoundarySpecificity => write!(f, "boundary-specificity"),
-            Rule::StateVerification => write!(f, "state-verification"),
-            Rule::AssertionIntentMismatch => write!(f, "assertion-intent-mismatch"),
-            Rule::TrivialAssertion => write!(f, "trivial-assertion"),
-            Rule::ReturnPathCoverage => write!(f, "return-path-coverage"),
-            Rule::BehavioralCompleteness => write!(f, "behavioral-completeness"),
-            Rule::SideEffectNotVerified => write!(f, "side-effect-not-verified"),
-            Rule::TestComplexity => write!(f, "test-complexity"),
-            Rule::ImplementationCoupling => write!(f, "implementation-coupling"),
-            Rule::VacuousTest => write!(f, "vacuous-test"),
-            Rule::IncompleteMockVerification => write!(f, "incomplete-mock-verification"),
-            Rule::AsyncErrorMishandling => write!(f, "async-error-mishandling"),
-            Rule::RedundantTest => write!(f, "redundant-test"),
-            Rule::UnreachableTestCode => write!(f, "unreachable-test-code"),
-            Rule::ExcessiveSetup => write!(f, "excessive-setup"),
-            Rule::TypeAssertionAbuse => write!(f, "type-assertion-abuse"),
-            Rule::MissingCleanup => write!(f, "missing-cleanup"),
-            Rule::TautologicalAssertion => write!(f, "ai-smell-tautological-assertion"),
-            Rule::OverMocking => write!(f, "ai-smell-over-mocking"),
-            Rule::ShallowVariety => write!(f, "ai-smell-shallow-variety"),
-            Rule::HappyPathOnly => write!(f, "ai-smell-happy-path-only"),
-            Rule::ParrotAssertion => write!(f, "ai-smell-parrot-assertion"),
-            Rule::BoilerplatePadding => write!(f, "ai-smell-boilerplate-padding"),
-        }
+        f.write_str(self.id())
     }
 }
 
@@ -449,7 +402,7 @@ impl std::fmt::Display for TestFramework {
 }
 
 /// Type of test (affects scoring weights)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum TestType {
     /// Unit tests - test individual functions/modules
@@ -807,7 +760,7 @@ impl AssertionKind {
 ///
 /// * `path` - path to the test file
 /// * `work_dir` - project root (for config lookup and source mapping)
-/// * `config_path` - optional path to .rigorrc.json; if None, searches from work_dir
+/// * `config_path` - optional path to a config file (.rigorrc.json, .rigorrc.yaml, rigor.toml, package.json); if None, searches from work_dir
 pub fn analyze_file(
     path: &std::path::Path,
     work_dir: &std::path::Path,
@@ -3,9 +3,10 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use rigor::analyzer::engine::PackageStats;
 use rigor::analyzer::AnalysisEngine;
 use rigor::cache::AnalysisCache;
-use rigor::config::{build_ignore_set, is_ignored, load_config, CONFIG_FILENAME};
+use rigor::config::{build_ignore_set, is_ignored, load_config, ConfigResolver, CONFIG_FILENAME};
 use rigor::history::{
     append_run, find_project_root, format_delta, load_history, previous_score, save_history,
 };
@@ -13,6 +14,7 @@ use rigor::mutation::{self, report_mutation_result};
 use rigor::reporter::{ConsoleReporter, HtmlReporter, JsonReporter, SarifReporter};
 use rigor::suggestions::{extract_code_block, offer_apply, AiSuggestionGenerator};
 use rigor::watcher::TestWatcher;
+use rigor::workspace::Workspace;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
@@ -62,7 +64,7 @@ struct Args {
     #[arg(long)]
     no_source: bool,
 
-    /// Path to config file (default: search .rigorrc.json in current dir and parents)
+    /// Path to config file (default: search .rigorrc.json, .rigorrc.yaml, rigor.toml or package.json in current dir and parents)
     #[arg(long)]
     config: Option<PathBuf>,
 
@@ -90,11 +92,39 @@ struct Args {
     #[arg(long, value_name = "MODE", num_args = 0..=1, default_missing_value = "quick")]
     mutate: Option<Option<String>>,
 
+    /// Invert each assertion in the test files and rerun them; flag assertions the tests still pass without
+    #[arg(long)]
+    mutate_tests: bool,
+
+    /// Per-mutant timeout as a multiple of the unmutated test run time (default: 1.5)
+    #[arg(long, value_name = "FACTOR")]
+    mutation_timeout_factor: Option<f64>,
+
+    /// Milliseconds added to each per-mutant timeout (default: 5000)
+    #[arg(long, value_name = "MS")]
+    mutation_timeout_ms: Option<u64>,
+
+    /// Write mutation results as mutation-testing-report-schema JSON (Stryker-compatible)
+    #[arg(long, value_name = "PATH")]
+    mutation_json: Option<PathBuf>,
+
+    /// Write a self-contained HTML viewer for the mutation results
+    #[arg(long, value_name = "PATH")]
+    mutation_html: Option<PathBuf>,
+
+    /// Import mutation results instead of running mutants (mutation-testing-report-schema JSON, e.g. Stryker's mutation.json)
+    #[arg(long, value_name = "PATH")]
+    mutation_report: Option<PathBuf>,
+
     /// Only analyze files changed since last commit (git diff HEAD)
     #[arg(long)]
     changed: bool,
 
-    /// Disable caching (re-analyze all files even if unchanged)
+    /// Only analyze monorepo packages whose name or path matches the glob (repeatable, e.g. '@acme/*' or 'apps/**')
+    #[arg(long, value_name = "PACKAGE_GLOB")]
+    filter: Vec<String>,
+
+    /// Disable caching (re-analyze all files and re-run all mutants even if unchanged)
     #[arg(long)]
     no_cache: bool,
 
@@ -118,7 +148,7 @@ struct Args {
     #[arg(long, value_name = "N")]
     jobs: Option<usize>,
 
-    /// Path to coverage JSON file (Istanbul/c8/nyc format)
+    /// Path to coverage data: Istanbul/c8/nyc JSON, LCOV, Cobertura XML, raw V8 JSON, or a directory of them
     #[arg(long, value_name = "PATH")]
     coverage: Option<PathBuf>,
 
@@ -149,6 +179,73 @@ enum Commands {
         /// Directory in which to create config (default: current)
         #[arg(long)]
         dir: Option<PathBuf>,
+
+        /// Extend a built-in preset: recommended, strict, react, api, ai-review, legacy
+        #[arg(long)]
+        preset: Option<String>,
+    },
+
+    /// List the mutants generated for a source file without running them
+    Mutants {
+        /// Source file to mutate
+        source: PathBuf,
+
+        /// Show a unified diff for each mutant
+        #[arg(long)]
+        diff: bool,
+
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+
+        /// Only list the mutants this mode would run: quick, medium, full
+        #[arg(long, value_name = "MODE")]
+        mode: Option<String>,
+
+        /// Coverage report; mutants on unexecuted lines are marked and never sampled
+        #[arg(long, value_name = "PATH")]
+        coverage: Option<PathBuf>,
+
+        /// Path to config file (default: search from the source's directory)
+        #[arg(long, short)]
+        config: Option<PathBuf>,
+    },
+
+    /// List every rule with its category, default severity and inputs
+    Rules {
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+    },
+
+    /// Inspect configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigCommand {
+    /// Print a JSON Schema for .rigorrc.json (for editor completion and validation)
+    Schema,
+
+    /// Show the config that applies to a file and where each value comes from
+    Print {
+        /// Test file to resolve config for (need not exist)
+        file: PathBuf,
+
+        /// Path to config file (default: search from the file's directory)
+        #[arg(long, short)]
+        config: Option<PathBuf>,
+
+        /// Threshold as passed on the command line
+        #[arg(long, short)]
+        threshold: Option<u8>,
+
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
     },
 }
 
@@ -156,7 +253,7 @@ fn main() -> ExitCode {
     match run() {
         Ok(code) => code,
         Err(e) => {
-            eprintln!("{}: {}", "Error".red().bold(), e);
+            eprintln!("{}: {:#}", "Error".red().bold(), e);
             ExitCode::from(2)
         }
     }
@@ -197,9 +294,47 @@ fn run() -> Result<ExitCode> {
                 threshold,
                 framework,
                 dir,
+                preset,
+            } => {
+                return run_init(threshold, framework, dir.as_deref(), preset.as_deref());
+            }
+            Commands::Mutants {
+                source,
+                diff,
+                json,
+                mode,
+                coverage,
+                config,
             } => {
-                return run_init(threshold, framework, dir.as_deref());
+                return run_mutants(
+                    &source,
+                    diff,
+                    json,
+                    mode.as_deref(),
+                    coverage.as_deref(),
+                    config.as_deref(),
+                );
+            }
+            Commands::Rules { json } => {
+                return run_rules(json);
             }
+            Commands::Config { command } => match command {
+                ConfigCommand::Schema => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&rigor::config::config_schema())?
+                    );
+                    return Ok(ExitCode::SUCCESS);
+                }
+                ConfigCommand::Print {
+                    file,
+                    config,
+                    threshold,
+                    json,
+                } => {
+                    return run_config_print(&file, config.as_deref(), threshold, json);
+                }
+            },
         }
     }
 
@@ -224,9 +359,100 @@ fn run() -> Result<ExitCode> {
         path.as_path()
     };
 
+    // Clean up after a mutation run that was interrupted (Ctrl-C, crash, kill)
+    if let Some(ref root) = find_project_root(work_dir) {
+        report_journal_recovery(mutation::recover_mutation_journal(root), args.quiet);
+    }
+
     // Load config (CLI flags override config file)
     let config = load_config(work_dir, args.config.as_deref())?
         .merge_with_cli(args.threshold, args.config.as_deref());
+    rigor::plugin::check(&config.plugins)?;
+    // Files in subdirectories may have config files of their own layered on top
+    let resolver = config_resolver(&args, &config)?;
+
+    // Monorepo packages, for --filter and per-package stats. A malformed manifest only
+    // fails the run when --filter needs the workspace.
+    let workspace = if args.stdin || !work_dir.exists() {
+        None
+    } else {
+        let (workspace, skipped) = Workspace::detect(work_dir)?;
+        if let (Some(e), false) = (skipped.first(), args.filter.is_empty()) {
+            anyhow::bail!("{:#}", e);
+        }
+        for e in skipped {
+            eprintln!(
+                "{}: Ignoring workspace manifest: {:#}",
+                "Warning".yellow(),
+                e
+            );
+        }
+        workspace
+    };
+    if workspace.is_none() && !args.filter.is_empty() {
+        anyhow::bail!(
+            "--filter needs a monorepo workspace (package.json workspaces, pnpm-workspace.yaml, nx.json or turbo.json)"
+        );
+    }
+
+    // Load coverage data; the engine uses it to confirm source-dependent findings
+    let coverage_report = if let Some(ref coverage_path) = args.coverage {
+        match rigor::coverage::load_coverage(coverage_path) {
+            Ok(report) => {
+                warn_skipped_coverage(&report);
+                if !args.quiet {
+                    eprintln!(
+                        "{}: Loaded coverage data ({} files, {:.1}% line coverage)",
+                        "Coverage".cyan().bold(),
+                        report.files.len(),
+                        report.summary.lines_pct
+                    );
+                }
+                Some(report)
+            }
+            Err(e) => {
+                eprintln!("{}: Failed to load coverage: {}", "Warning".yellow(), e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Load imported mutation results; survivors become issues on the tests mapped to each source
+    let imported_mutations = if let Some(ref report_path) = args.mutation_report {
+        // Paths in the report are relative to the project it was generated in: the one
+        // containing the report, else the one being analyzed
+        let report_root = report_path
+            .canonicalize()
+            .ok()
+            .and_then(|p| find_project_root(&p))
+            .or_else(|| find_project_root(work_dir))
+            .unwrap_or_else(|| work_dir.to_path_buf());
+        match mutation::stryker::load_results(report_path, &report_root) {
+            Ok(results) => {
+                if !args.quiet {
+                    eprintln!(
+                        "{}: Loaded mutation report ({} files, {} mutants)",
+                        "Mutation".cyan().bold(),
+                        results.len(),
+                        results.iter().map(|r| r.total).sum::<usize>()
+                    );
+                }
+                results
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}: Failed to load mutation report: {}",
+                    "Warning".yellow(),
+                    e
+                );
+                Vec::new()
+            }
+        }
+    } else {
+        Vec::new()
+    };
 
     // --- Stdin mode: analyze test source from stdin (programmatic API) ---
     let mut cache = AnalysisCache::disabled();
@@ -269,7 +495,7 @@ fn run() -> Result<ExitCode> {
         };
 
         let test_patterns = config.get_test_patterns();
-        let test_files = if args.staged {
+        let mut test_files = if args.staged {
             let git_root = find_project_root(work_dir).unwrap_or_else(|| work_dir.to_path_buf());
             collect_staged_test_files(&git_root, ignore_set.as_ref(), &test_patterns)?
         } else if args.changed {
@@ -279,6 +505,22 @@ fn run() -> Result<ExitCode> {
             collect_test_files(&search_path, ignore_set.as_ref(), &test_patterns)?
         };
 
+        test_files.retain(|file| !resolver.is_ignored(file).unwrap_or(false));
+        if let (Some(workspace), false) = (&workspace, args.filter.is_empty()) {
+            let selected = workspace.filter(&args.filter)?;
+            if selected.is_empty() {
+                anyhow::bail!(
+                    "No workspace packages match --filter {}",
+                    args.filter.join(", ")
+                );
+            }
+            test_files.retain(|file| {
+                workspace
+                    .package_for(file)
+                    .is_some_and(|package| selected.contains(&package))
+            });
+        }
+
         if test_files.is_empty() {
             if args.staged || args.changed {
                 if !args.quiet {
@@ -311,14 +553,14 @@ fn run() -> Result<ExitCode> {
                 .ok();
         }
 
-        let engine = if args.no_source {
-            AnalysisEngine::new().without_source_analysis()
-        } else {
-            AnalysisEngine::new()
-        };
+        // Cached results depend on neither coverage nor mutation data, so bypass the cache
+        // when either is supplied
+        let external_data = coverage_report.is_some() || !imported_mutations.is_empty();
+        let engine = analysis_engine(&args, coverage_report.clone())
+            .with_mutation_results(imported_mutations.clone());
 
         let use_parallel = args.parallel || test_files.len() > 10;
-        let cache_opt = if args.no_cache {
+        let cache_opt = if args.no_cache || external_data {
             None
         } else {
             Some(&mut cache)
@@ -326,7 +568,7 @@ fn run() -> Result<ExitCode> {
         run_analyze_files(
             &engine,
             &test_files,
-            &config,
+            &resolver,
             cache_opt,
             use_parallel,
             args.quiet,
@@ -392,33 +634,228 @@ fn run() -> Result<ExitCode> {
         return Ok(ExitCode::SUCCESS);
     }
 
-    // Calculate aggregate stats
-    let stats = AnalysisEngine::aggregate_stats(&results);
+    // Run mutation testing before reporting so survivors show up as issues on the tests
+    let mut live_mutations = LiveMutations::None;
+    let mut engine_mutations = imported_mutations.clone();
+    if let Some(ref mutate_arg) = args.mutate {
+        let count = mutation::count_for_mode(mutate_arg.as_deref().unwrap_or("quick"));
+        let options = mutation_options(&args, &config, count);
+        for group in config.mutation.operators.keys() {
+            if !mutation::OPERATOR_GROUPS.contains(&group.as_str()) {
+                eprintln!(
+                    "{}: unknown mutation operator group \"{}\" (expected one of: {})",
+                    "Warning".yellow(),
+                    group,
+                    mutation::OPERATOR_GROUPS.join(", ")
+                );
+            }
+        }
 
-    // TODO: integrate coverage data into scoring once the coverage-aware rules are implemented.
-    // Currently we load and print a summary but don't use the report beyond that.
-    let _coverage_report = if let Some(ref coverage_path) = args.coverage {
-        match rigor::coverage::load_coverage(coverage_path) {
-            Ok(report) => {
+        // Collect all source files from analysis results, each with the tests mapped to it
+        let mutation_root = find_project_root(work_dir).unwrap_or_else(|| work_dir.to_path_buf());
+        let mut tests_by_source: std::collections::BTreeMap<PathBuf, Vec<&rigor::AnalysisResult>> =
+            std::collections::BTreeMap::new();
+        for result in &results {
+            if let Some(source) = result.source_file.as_ref().filter(|p| {
+                p.exists()
+                    && !config
+                        .mutation
+                        .excludes_file(p.strip_prefix(&mutation_root).unwrap_or(p))
+            }) {
+                tests_by_source
+                    .entry(source.clone())
+                    .or_default()
+                    .push(result);
+            }
+        }
+        let targets: Vec<mutation::MutationTarget> = tests_by_source
+            .into_iter()
+            .map(|(source, tests)| {
+                let mut target = mutation::MutationTarget::from_results(source, tests);
+                target.coverage = coverage_report
+                    .as_ref()
+                    .and_then(|report| report.get_file_coverage(&target.source_path))
+                    .cloned();
+                target
+            })
+            .collect();
+
+        // Packages of a monorepo journal their sandboxes under their own root, which the
+        // startup recovery from the work dir does not reach
+        report_journal_recovery(
+            mutation::recover_mutation_journals(targets.iter().map(|t| t.source_path.as_path())),
+            args.quiet,
+        );
+
+        let mut mutation_results = Vec::new();
+        if targets.is_empty() {
+            if !args.quiet
+                && results
+                    .iter()
+                    .any(|r| !is_likely_e2e_test(r.file_path.as_path()))
+            {
+                eprintln!(
+                    "{}: --mutate requires source files (test files must map to .ts sources, e.g. foo.test.ts → foo.ts). Cypress/e2e tests often have no single source.",
+                    "Warning".yellow()
+                );
+            }
+        } else if targets.len() == 1 {
+            // Single file mutation testing
+            let target = &targets[0];
+            match std::fs::read_to_string(&target.source_path)
+                .and_then(|content| mutation::run_mutation_test(target, &content, &options))
+            {
+                Ok(mutation_result) => {
+                    mutation_results = vec![mutation_result.clone()];
+                    live_mutations = LiveMutations::Single(mutation_result);
+                }
+                Err(e) => eprintln!(
+                    "{} {}: {}",
+                    "Mutation".red().bold(),
+                    target.source_path.display(),
+                    e
+                ),
+            }
+        } else {
+            // Multi-file batch mutation testing
+            if !args.quiet {
+                eprintln!(
+                    "{}: Running mutation testing on {} source files...",
+                    "Mutation".cyan().bold(),
+                    targets.len()
+                );
+            }
+
+            let use_parallel = args.parallel || targets.len() > 3;
+            let batch_result = mutation::run_batch_mutation_test(&targets, &options, use_parallel);
+            for (path, e) in &batch_result.failures {
+                eprintln!("{} {}: {}", "Mutation".red().bold(), path.display(), e);
+            }
+            mutation_results = batch_result.source_results.clone();
+            live_mutations = LiveMutations::Batch(batch_result);
+        }
+
+        if !mutation_results.is_empty() {
+            let project_root =
+                find_project_root(work_dir).unwrap_or_else(|| work_dir.to_path_buf());
+            let exports = [
+                (args.mutation_json.as_deref(), "JSON"),
+                (args.mutation_html.as_deref(), "HTML"),
+            ];
+            for (path, kind) in exports {
+                let Some(path) = path else { continue };
+                let content = if kind == "JSON" {
+                    mutation::stryker::to_json(&mutation_results, &project_root)
+                } else {
+                    mutation::stryker::to_html(&mutation_results, &project_root)
+                };
+                std::fs::write(path, content).with_context(|| {
+                    format!(
+                        "Failed to write mutation {} report to {}",
+                        kind,
+                        path.display()
+                    )
+                })?;
                 if !args.quiet {
                     eprintln!(
-                        "{}: Loaded coverage data ({} files, {:.1}% line coverage)",
-                        "Coverage".cyan().bold(),
-                        report.files.len(),
-                        report.summary.lines_pct
+                        "{}: Mutation {} report written to {}",
+                        "Report".green().bold(),
+                        kind,
+                        path.display()
                     );
                 }
-                Some(report)
             }
-            Err(e) => {
-                eprintln!("{}: Failed to load coverage: {}", "Warning".yellow(), e);
-                None
+        }
+
+        // Re-analyze the tests of sources with survivors so `surviving-mutant` issues go
+        // through the usual filtering and scoring
+        let with_survivors: Vec<PathBuf> = mutation_results
+            .iter()
+            .filter(|m| m.survived + m.no_coverage > 0)
+            .filter_map(|m| m.source_path.canonicalize().ok())
+            .collect();
+        let retest: Vec<PathBuf> = results
+            .iter()
+            .filter(|r| {
+                r.source_file
+                    .as_ref()
+                    .and_then(|p| p.canonicalize().ok())
+                    .is_some_and(|p| with_survivors.contains(&p))
+            })
+            .map(|r| r.file_path.clone())
+            .collect();
+        engine_mutations.extend(mutation_results);
+        if !retest.is_empty() && !args.stdin {
+            let engine = analysis_engine(&args, coverage_report.clone())
+                .with_mutation_results(engine_mutations.clone());
+            reanalyze(&engine, &retest, &resolver, &mut results);
+        }
+    }
+
+    // Test-side mutation: runs before reporting so weightless assertions show up as issues
+    let assertion_results = if args.mutate_tests && !args.stdin {
+        let targets: Vec<mutation::MutationTarget> = results
+            .iter()
+            .map(|r| mutation::MutationTarget::from_results(r.file_path.clone(), [r]))
+            .collect();
+        if !args.quiet {
+            eprintln!(
+                "{}: Inverting assertions in {} test file(s)...",
+                "Mutation".cyan().bold(),
+                targets.len()
+            );
+        }
+        let options = mutation_options(&args, &config, usize::MAX);
+        let use_parallel = args.parallel || targets.len() > 3;
+        let mut checked = Vec::new();
+        for (path, result) in
+            mutation::run_batch_assertion_mutation_test(&targets, &options, use_parallel)
+        {
+            match result {
+                Ok(check) => checked.push(check),
+                Err(e) => eprintln!("{}: {} skipped: {}", "Warning".yellow(), path.display(), e),
             }
         }
+        // Re-analyze test files with weightless assertions so `weightless-assertion` issues
+        // go through the usual filtering and scoring
+        let retest: Vec<PathBuf> = checked
+            .iter()
+            .filter(|check| check.survived + check.no_coverage > 0)
+            .map(|check| check.source_path.clone())
+            .collect();
+        if !retest.is_empty() {
+            let engine = analysis_engine(&args, coverage_report.clone())
+                .with_mutation_results(engine_mutations.clone())
+                .with_assertion_results(checked.clone());
+            reanalyze(&engine, &retest, &resolver, &mut results);
+        }
+        checked
     } else {
-        None
+        Vec::new()
     };
 
+    // Calculate aggregate stats, per package as well in a monorepo
+    let mut stats = AnalysisEngine::aggregate_stats(&results);
+    if let Some(ref workspace) = workspace {
+        for result in &mut results {
+            result.package = workspace
+                .package_for(&result.file_path)
+                .map(|package| package.name.clone());
+        }
+        for package in &workspace.packages {
+            let package_stats = AnalysisEngine::package_stats(&results, &package.name);
+            if package_stats.files_analyzed == 0 {
+                continue;
+            }
+            stats.packages.push(PackageStats {
+                name: package.name.clone(),
+                path: package.path.clone(),
+                stats: package_stats,
+                threshold: resolver.for_dir(&package.dir)?.threshold,
+            });
+        }
+    }
+
     // Output results
     if args.html {
         let reporter = HtmlReporter::new();
@@ -487,6 +924,22 @@ fn run() -> Result<ExitCode> {
             reporter.report_many(&results, &stats);
         }
 
+        // Imported mutation data for the sources under test, next to the static scores
+        let analyzed_sources: Vec<PathBuf> = results
+            .iter()
+            .filter_map(|r| r.source_file.as_ref())
+            .filter_map(|p| p.canonicalize().ok())
+            .collect();
+        for imported in &imported_mutations {
+            if imported
+                .source_path
+                .canonicalize()
+                .is_ok_and(|p| analyzed_sources.contains(&p))
+            {
+                mutation::report_imported_mutation_result(imported);
+            }
+        }
+
         // Persist trend history
         if let Some(ref root) = find_project_root(work_dir) {
             let mut h = load_history(root.as_path());
@@ -495,59 +948,16 @@ fn run() -> Result<ExitCode> {
         }
     }
 
-    // Run mutation testing if requested
-    if let Some(mutate_arg) = args.mutate {
-        let count = match mutate_arg.as_deref().unwrap_or("quick") {
-            "full" => usize::MAX,
-            "medium" => 30,
-            _ => 10,
-        };
-        let test_cmd = std::env::var("RIGOR_TEST_CMD").unwrap_or_else(|_| "npm test".to_string());
-
-        // Collect all source files from analysis results
-        let source_paths: Vec<PathBuf> = results
-            .iter()
-            .filter_map(|r| r.source_file.clone())
-            .filter(|p| p.exists())
-            .collect();
-
-        if source_paths.is_empty() {
-            if !args.quiet
-                && results
-                    .iter()
-                    .any(|r| !is_likely_e2e_test(r.file_path.as_path()))
-            {
-                eprintln!(
-                    "{}: --mutate requires source files (test files must map to .ts sources, e.g. foo.test.ts → foo.ts). Cypress/e2e tests often have no single source.",
-                    "Warning".yellow()
-                );
-            }
-        } else if source_paths.len() == 1 {
-            // Single file mutation testing
-            let source_path = &source_paths[0];
-            if let Ok(content) = std::fs::read_to_string(source_path) {
-                if let Ok(mutation_result) =
-                    mutation::run_mutation_test(source_path, &content, &test_cmd, count)
-                {
-                    report_mutation_result(&mutation_result);
-                }
-            }
-        } else {
-            // Multi-file batch mutation testing
-            if !args.quiet {
-                eprintln!(
-                    "{}: Running mutation testing on {} source files...",
-                    "Mutation".cyan().bold(),
-                    source_paths.len()
-                );
-            }
-
-            let use_parallel = args.parallel || source_paths.len() > 3;
-            if let Ok(batch_result) =
-                mutation::run_batch_mutation_test(&source_paths, &test_cmd, count, use_parallel)
-            {
-                mutation::report_batch_mutation_result(&batch_result);
-            }
+    // Mutation reports are for people: with --json/--sarif the findings are already issues
+    // in the output, and printing after it would break the document
+    if !args.json && !args.sarif {
+        for check in &assertion_results {
+            mutation::report_assertion_mutation_result(check);
+        }
+        match live_mutations {
+            LiveMutations::Single(ref result) => report_mutation_result(result),
+            LiveMutations::Batch(ref batch) => mutation::report_batch_mutation_result(batch),
+            LiveMutations::None => {}
         }
     }
 
@@ -626,6 +1036,24 @@ fn run() -> Result<ExitCode> {
         }
     }
 
+    // Each package must meet its own threshold
+    let failed_packages: Vec<&PackageStats> =
+        stats.packages.iter().filter(|p| !p.passed()).collect();
+    if !failed_packages.is_empty() {
+        if !args.quiet && !args.json {
+            for package in &failed_packages {
+                eprintln!(
+                    "\n{}: Package {} score {} is below threshold {}",
+                    "Failed".red().bold(),
+                    package.name,
+                    package.stats.average_score.value,
+                    package.threshold.unwrap_or_default()
+                );
+            }
+        }
+        return Ok(ExitCode::from(1));
+    }
+
     // Check threshold (config or CLI)
     let threshold = args.threshold.or(config.threshold);
     if let Some(threshold) = threshold {
@@ -655,20 +1083,312 @@ fn run() -> Result<ExitCode> {
     }
 }
 
+/// Engine for analyzing test files, with coverage data when loaded
+fn analysis_engine(
+    args: &Args,
+    coverage: Option<rigor::coverage::CoverageReport>,
+) -> AnalysisEngine {
+    let engine = if args.no_source {
+        AnalysisEngine::new().without_source_analysis()
+    } else {
+        AnalysisEngine::new()
+    };
+    match coverage {
+        Some(report) => engine.with_coverage(report),
+        None => engine,
+    }
+}
+
+/// Warn about files of a coverage directory that were not coverage data
+fn warn_skipped_coverage(report: &rigor::coverage::CoverageReport) {
+    for (path, reason) in &report.skipped {
+        eprintln!(
+            "{}: Skipping {}: {}",
+            "Warning".yellow(),
+            path.display(),
+            reason
+        );
+    }
+}
+
+/// Outcome of `--mutate`, printed after the analysis report
+enum LiveMutations {
+    None,
+    Single(mutation::MutationResult),
+    Batch(mutation::BatchMutationResult),
+}
+
+/// Analyze `files` again with `engine` and replace their results in `results`
+fn reanalyze(
+    engine: &AnalysisEngine,
+    files: &[PathBuf],
+    resolver: &ConfigResolver,
+    results: &mut [rigor::AnalysisResult],
+) {
+    let (mut updated, _) = run_analyze_files(engine, files, resolver, None, false, true);
+    attach_fix_metadata(&mut updated, false);
+    for result in updated {
+        if let Some(slot) = results.iter_mut().find(|r| r.file_path == result.file_path) {
+            *slot = result;
+        }
+    }
+}
+
+/// Print what recovering the mutation journal of an interrupted run cleaned up
+fn report_journal_recovery(result: std::io::Result<mutation::RecoveryReport>, quiet: bool) {
+    match result {
+        Ok(report) => {
+            for file in &report.restored_files {
+                eprintln!(
+                    "{}: Restored {} left mutated by an interrupted run",
+                    "Mutation".cyan().bold(),
+                    file.display()
+                );
+            }
+            if !quiet && !report.removed_sandboxes.is_empty() {
+                eprintln!(
+                    "{}: Removed {} stale mutation sandbox(es)",
+                    "Mutation".cyan().bold(),
+                    report.removed_sandboxes.len()
+                );
+            }
+        }
+        Err(e) => eprintln!(
+            "{}: Failed to recover mutation journal: {}",
+            "Warning".yellow(),
+            e
+        ),
+    }
+}
+
+/// Mutation run settings from the CLI flags and the `mutation` config block. RIGOR_TEST_CMD
+/// wins over the configured test command; otherwise each target uses its framework default.
+fn mutation_options(
+    args: &Args,
+    config: &rigor::config::Config,
+    count: usize,
+) -> mutation::MutationOptions {
+    let mut options = mutation::MutationOptions {
+        count,
+        incremental: !args.no_cache,
+        ..mutation::MutationOptions::from_config(&config.mutation)
+    };
+    if let Ok(command) = std::env::var("RIGOR_TEST_CMD") {
+        options.test_command = Some(command);
+    }
+    if let Some(factor) = args.mutation_timeout_factor {
+        options.timeout.factor = factor;
+    }
+    if let Some(ms) = args.mutation_timeout_ms {
+        options.timeout.extra = std::time::Duration::from_millis(ms);
+    }
+    options
+}
+
+fn run_config_print(
+    file: &Path,
+    config_path: Option<&Path>,
+    threshold: Option<u8>,
+    json: bool,
+) -> Result<ExitCode> {
+    use rigor::config::{load_config_layers, ConfigEntry, ConfigLayer, ConfigReport, Source};
+
+    let work_dir = match file.parent() {
+        Some(dir) if dir.is_dir() => dir,
+        _ => Path::new("."),
+    };
+    let (config, mut layers) = load_config_layers(work_dir, config_path)?;
+    let config = config.merge_with_cli(threshold, config_path);
+    if let Some(threshold) = threshold {
+        layers.push(ConfigLayer::cli_threshold(threshold));
+    }
+    let report = ConfigReport::new(&config, &layers, file);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    println!("{} {}", "Config for".bold(), report.file);
+    if report.sources.is_empty() {
+        println!("No config file found; using defaults");
+    } else {
+        println!("\n{}", "Sources (lowest precedence first)".bold());
+        for (i, source) in report.sources.iter().enumerate() {
+            println!("  {}. {}", i + 1, source);
+        }
+    }
+    let print_entries = |title: &str, entries: &[ConfigEntry]| {
+        println!("\n{}", title.bold());
+        let values: Vec<String> = entries.iter().map(|e| e.value.to_string()).collect();
+        let key_width = entries.iter().map(|e| e.key.len()).max().unwrap_or(0);
+        // Long values (options, override entries) push their source out of line
+        let value_width = values
+            .iter()
+            .map(String::len)
+            .filter(|&len| len <= 40)
+            .max();
+        for (entry, value) in entries.iter().zip(&values) {
+            let source = entry.source.to_string();
+            let source = match entry.source {
+                Source::Default => source.dimmed(),
+                _ => source.cyan(),
+            };
+            println!(
+                "  {:<key_width$}  {:<value_width$}  {}",
+                entry.key,
+                value,
+                source,
+                key_width = key_width,
+                value_width = value_width.unwrap_or(0)
+            );
+        }
+    };
+    print_entries("Effective for this file", &report.effective);
+    if !report.config.is_empty() {
+        print_entries("Merged config", &report.config);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_rules(json: bool) -> Result<ExitCode> {
+    use rigor::registry::{Maturity, RULES};
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(RULES)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+    let mut section = "";
+    for info in RULES {
+        if info.section != section {
+            section = info.section;
+            println!("\n{}", section.bold());
+        }
+        let severity = match info.default_severity {
+            rigor::Severity::Error => "error".red(),
+            rigor::Severity::Warning => "warning".yellow(),
+            rigor::Severity::Info => "info".blue(),
+        };
+        let mut notes: Vec<String> = vec![info.category.unwrap_or("penalty").to_string()];
+        if info.fixable {
+            notes.push("fixable".to_string());
+        }
+        if !info.inputs.is_empty() {
+            let inputs: Vec<&str> = info.inputs.iter().map(|i| i.id()).collect();
+            notes.push(format!("uses {}", inputs.join(", ")));
+        }
+        match info.maturity {
+            Maturity::Stable => {}
+            Maturity::Partial => notes.push("partial".to_string()),
+            Maturity::Stub => notes.push("stub".to_string()),
+        }
+        println!(
+            "  {:<34} {:<8} {}",
+            info.id,
+            severity,
+            format!("({})", notes.join("; ")).dimmed()
+        );
+        println!("  {:<34} {:<8} {}", "", "", info.description);
+    }
+    println!();
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_mutants(
+    source: &Path,
+    diff: bool,
+    json: bool,
+    mode: Option<&str>,
+    coverage: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<ExitCode> {
+    if let Some(mode) = mode {
+        if !mutation::MODES.iter().any(|(name, _)| *name == mode) {
+            anyhow::bail!("Unknown mode '{}': expected quick, medium or full", mode);
+        }
+    }
+    let content = std::fs::read_to_string(source)
+        .with_context(|| format!("Failed to read {}", source.display()))?;
+    let dir = source.parent().unwrap_or(Path::new("."));
+    let config = load_config(dir, config_path)?;
+    let root = find_project_root(dir).unwrap_or_else(|| dir.to_path_buf());
+    let label = source
+        .canonicalize()
+        .ok()
+        .and_then(|p| {
+            let root = root.canonicalize().ok()?;
+            p.strip_prefix(root).ok().map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| source.to_path_buf());
+    if config.mutation.excludes_file(&label) {
+        eprintln!(
+            "{}: {} is excluded by mutation.exclude and is never mutated",
+            "Info".blue(),
+            label.display()
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut target = mutation::MutationTarget::new(source);
+    if let Some(path) = coverage {
+        let report = rigor::coverage::load_coverage(path)
+            .with_context(|| format!("Failed to load coverage from {}", path.display()))?;
+        warn_skipped_coverage(&report);
+        target.coverage = report.get_file_coverage(source).cloned();
+    }
+    let options = mutation::MutationOptions::from_config(&config.mutation);
+    let preview = mutation::MutationPreview::new(&target, &content, &options);
+    let label = label.display().to_string();
+
+    if json {
+        let mut value = preview.to_json(&label, options.seed, diff, &content);
+        if let (Some(mode), Some(mutants)) = (mode, value["mutants"].as_array_mut()) {
+            mutants.retain(|m| {
+                m["modes"]
+                    .as_array()
+                    .is_some_and(|ms| ms.iter().any(|x| x == mode))
+            });
+        }
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        mutation::report_mutation_preview(
+            &preview,
+            &label,
+            options.seed,
+            mode,
+            diff.then_some(content.as_str()),
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
 fn run_init(
     threshold: Option<u8>,
     framework: Option<String>,
     dir: Option<&Path>,
+    preset: Option<&str>,
 ) -> Result<ExitCode> {
+    let preset = preset
+        .map(|name| {
+            rigor::config::find_preset(name).with_context(|| {
+                let names: Vec<&str> = rigor::config::PRESETS.iter().map(|p| p.name).collect();
+                format!(
+                    "Unknown preset '{}' (available: {})",
+                    name,
+                    names.join(", ")
+                )
+            })
+        })
+        .transpose()?;
     let cwd = std::env::current_dir().context("Failed to get current directory")?;
     let dir = dir.unwrap_or(&cwd);
     let config_path = dir.join(CONFIG_FILENAME);
 
-    if config_path.exists() {
+    if let Some(existing) = rigor::config::find_config_in_dir(dir) {
         eprintln!(
             "{}: {} already exists; use --dir to write elsewhere or remove it first",
             "Warning".yellow(),
-            config_path.display()
+            existing.display()
         );
         return Ok(ExitCode::SUCCESS);
     }
@@ -682,18 +1402,37 @@ fn run_init(
         _ => "auto",
     };
 
-    let threshold_value = threshold.unwrap_or(70);
-
-    let json = format!(
-        r#"{{
-  "threshold": {},
+    // A preset brings its own threshold and rules; only write what was asked for
+    let head = match preset {
+        Some(preset) => {
+            let threshold = threshold
+                .map(|t| format!("\n  \"threshold\": {},", t))
+                .unwrap_or_default();
+            format!(
+                r#"  "extends": "{}",{}
+  "framework": "{}","#,
+                preset.id(),
+                threshold,
+                framework_value
+            )
+        }
+        None => format!(
+            r#"  "threshold": {},
   "framework": "{}",
   "rules": {{
     "weak-assertion": "warning",
     "missing-error-test": "warning",
     "flaky-pattern": "warning",
     "snapshot-overuse": "off"
-  }},
+  }},"#,
+            threshold.unwrap_or(70),
+            framework_value
+        ),
+    };
+
+    let json = format!(
+        r#"{{
+{}
   "ignore": [
     "**/node_modules/**",
     "**/dist/**",
@@ -711,7 +1450,7 @@ fn run_init(
   ]
 }}
 "#,
-        threshold_value, framework_value
+        head
     );
     // Note: Users can also add these options to the config:
     // - "testRoot": "tests" - directory to search for tests recursively
@@ -720,13 +1459,22 @@ fn run_init(
     std::fs::write(&config_path, json)
         .with_context(|| format!("Failed to write config to {}", config_path.display()))?;
 
-    println!(
-        "{}: Created {} with threshold={}, framework={}",
-        "Done".green().bold(),
-        config_path.display(),
-        threshold_value,
-        framework_value
-    );
+    match preset {
+        Some(preset) => println!(
+            "{}: Created {} extending {}, framework={}",
+            "Done".green().bold(),
+            config_path.display(),
+            preset.id(),
+            framework_value
+        ),
+        None => println!(
+            "{}: Created {} with threshold={}, framework={}",
+            "Done".green().bold(),
+            config_path.display(),
+            threshold.unwrap_or(70),
+            framework_value
+        ),
+    }
     Ok(ExitCode::SUCCESS)
 }
 
@@ -739,11 +1487,13 @@ fn run_watch(args: &Args, path: &Path) -> Result<ExitCode> {
 
     let config = load_config(work_dir, args.config.as_deref())?
         .merge_with_cli(args.threshold, args.config.as_deref());
+    rigor::plugin::check(&config.plugins)?;
     let ignore_set = if config.ignore.is_empty() {
         None
     } else {
         Some(build_ignore_set(&config.ignore)?)
     };
+    let resolver = config_resolver(args, &config)?;
 
     let engine = if args.no_source {
         AnalysisEngine::new().without_source_analysis()
@@ -769,10 +1519,11 @@ fn run_watch(args: &Args, path: &Path) -> Result<ExitCode> {
                     .as_ref()
                     .map(|set| !is_ignored(p, set))
                     .unwrap_or(true)
+                    && !resolver.is_ignored(p).unwrap_or(false)
             })
             .collect();
         for path in filtered {
-            match engine.analyze(&path, Some(&config)) {
+            match analyze_file(&engine, &resolver, &path) {
                 Ok(result) => {
                     if args.quiet {
                         ConsoleReporter::new().report_quiet(&result);
@@ -988,20 +1739,46 @@ fn collect_changed_test_files(
     Ok(files)
 }
 
+/// Config for every analyzed file: the `--config` file, or the config files between each
+/// file and the repository root
+fn config_resolver(args: &Args, config: &rigor::config::Config) -> Result<ConfigResolver> {
+    match args.config {
+        Some(_) => ConfigResolver::fixed(config.clone()),
+        None => Ok(ConfigResolver::cascading(args.threshold)),
+    }
+}
+
+/// Analyze one file with the config that applies to it
+fn analyze_file(
+    engine: &AnalysisEngine,
+    resolver: &ConfigResolver,
+    file: &Path,
+) -> Result<rigor::AnalysisResult> {
+    let config = resolver.for_file(file)?;
+    engine.analyze(file, Some(&config))
+}
+
+/// Cache key part for the config that applies to `file`; None (not cached) when it can't
+/// be resolved, so the analysis reports the error
+fn config_hash(resolver: &ConfigResolver, file: &Path) -> Option<String> {
+    let config = resolver.for_file(file).ok()?;
+    Some(AnalysisCache::config_hash(&config))
+}
+
 /// Dispatch to the appropriate analyze path based on cache and parallelism.
 fn run_analyze_files(
     engine: &AnalysisEngine,
     files: &[PathBuf],
-    config: &rigor::config::Config,
+    resolver: &ConfigResolver,
     cache: Option<&mut AnalysisCache>,
     use_parallel: bool,
     quiet: bool,
 ) -> (Vec<rigor::AnalysisResult>, bool) {
     match (cache, use_parallel) {
-        (Some(c), true) => analyze_files_parallel_cached(engine, files, config, c, quiet),
-        (Some(c), false) => analyze_files_sequential_cached(engine, files, config, c, quiet),
-        (None, true) => analyze_files_parallel(engine, files, config, quiet),
-        (None, false) => analyze_files_sequential(engine, files, config, quiet),
+        (Some(c), true) => analyze_files_parallel_cached(engine, files, resolver, c, quiet),
+        (Some(c), false) => analyze_files_sequential_cached(engine, files, resolver, c, quiet),
+        (None, true) => analyze_files_parallel(engine, files, resolver, quiet),
+        (None, false) => analyze_files_sequential(engine, files, resolver, quiet),
     }
 }
 
@@ -1009,13 +1786,13 @@ fn run_analyze_files(
 fn analyze_files_sequential(
     engine: &AnalysisEngine,
     files: &[PathBuf],
-    config: &rigor::config::Config,
+    resolver: &ConfigResolver,
     quiet: bool,
 ) -> (Vec<rigor::AnalysisResult>, bool) {
     let mut results = Vec::new();
     let mut had_errors = false;
     for file in files {
-        match engine.analyze(file, Some(config)) {
+        match analyze_file(engine, resolver, file) {
             Ok(result) => results.push(result),
             Err(e) => {
                 if !quiet {
@@ -1037,7 +1814,7 @@ fn analyze_files_sequential(
 fn analyze_files_sequential_cached(
     engine: &AnalysisEngine,
     files: &[PathBuf],
-    config: &rigor::config::Config,
+    resolver: &ConfigResolver,
     cache: &mut AnalysisCache,
     quiet: bool,
 ) -> (Vec<rigor::AnalysisResult>, bool) {
@@ -1046,12 +1823,13 @@ fn analyze_files_sequential_cached(
     let mut cache_hits = 0;
 
     for file in files {
-        // Try to read file content for caching
+        // Try to read file content and resolve its config for caching
         let test_content = std::fs::read_to_string(file).ok();
+        let config_hash = config_hash(resolver, file);
 
         // Check cache first
-        if let Some(ref content) = test_content {
-            if let Some(cached) = cache.get(file, content, None) {
+        if let (Some(content), Some(hash)) = (&test_content, &config_hash) {
+            if let Some(cached) = cache.get(file, content, None, hash) {
                 results.push(cached);
                 cache_hits += 1;
                 continue;
@@ -1059,11 +1837,11 @@ fn analyze_files_sequential_cached(
         }
 
         // Analyze the file
-        match engine.analyze(file, Some(config)) {
+        match analyze_file(engine, resolver, file) {
             Ok(result) => {
                 // Store in cache
-                if let Some(ref content) = test_content {
-                    cache.set(file, content, None, result.clone());
+                if let (Some(content), Some(hash)) = (&test_content, &config_hash) {
+                    cache.set(file, content, None, hash, result.clone());
                 }
                 results.push(result);
             }
@@ -1097,7 +1875,7 @@ fn analyze_files_sequential_cached(
 fn analyze_files_parallel(
     engine: &AnalysisEngine,
     files: &[PathBuf],
-    config: &rigor::config::Config,
+    resolver: &ConfigResolver,
     quiet: bool,
 ) -> (Vec<rigor::AnalysisResult>, bool) {
     use rayon::prelude::*;
@@ -1107,7 +1885,7 @@ fn analyze_files_parallel(
 
     let results: Vec<_> = files
         .par_iter()
-        .filter_map(|file| match engine.analyze(file, Some(config)) {
+        .filter_map(|file| match analyze_file(engine, resolver, file) {
             Ok(result) => Some(result),
             Err(e) => {
                 had_errors.store(true, Ordering::Relaxed);
@@ -1131,7 +1909,7 @@ fn analyze_files_parallel(
 fn analyze_files_parallel_cached(
     engine: &AnalysisEngine,
     files: &[PathBuf],
-    config: &rigor::config::Config,
+    resolver: &ConfigResolver,
     cache: &mut AnalysisCache,
     quiet: bool,
 ) -> (Vec<rigor::AnalysisResult>, bool) {
@@ -1141,19 +1919,23 @@ fn analyze_files_parallel_cached(
     let had_errors = AtomicBool::new(false);
     let cache_hits = AtomicUsize::new(0);
 
-    // Collect (path, content, result, should_cache). should_cache is true for freshly
-    // analyzed files that need writing to cache, false for cache hits.
-    type Item = (PathBuf, String, rigor::AnalysisResult, bool);
+    // Collect (path, content, config hash, result, should_cache). should_cache is true for
+    // freshly analyzed files that need writing to cache, false for cache hits.
+    type Item = (PathBuf, String, Option<String>, rigor::AnalysisResult, bool);
     let collected: Vec<Item> = files
         .par_iter()
         .filter_map(|file| {
             let content = std::fs::read_to_string(file).ok()?;
-            if let Some(cached) = cache.get(file, &content, None) {
+            let hash = config_hash(resolver, file);
+            if let Some(cached) = hash
+                .as_ref()
+                .and_then(|hash| cache.get(file, &content, None, hash))
+            {
                 cache_hits.fetch_add(1, Ordering::Relaxed);
-                return Some((file.clone(), content, cached, false));
+                return Some((file.clone(), content, hash, cached, false));
             }
-            match engine.analyze(file, Some(config)) {
-                Ok(result) => Some((file.clone(), content, result, true)),
+            match analyze_file(engine, resolver, file) {
+                Ok(result) => Some((file.clone(), content, hash, result, true)),
                 Err(e) => {
                     had_errors.store(true, Ordering::Relaxed);
                     if !quiet {
@@ -1170,13 +1952,14 @@ fn analyze_files_parallel_cached(
         })
         .collect();
 
-    for (path, content, ref result, should_cache) in &collected {
-        if *should_cache {
-            cache.set(path, content, None, result.clone());
+    for (path, content, hash, ref result, should_cache) in &collected {
+        if let (Some(hash), true) = (hash, should_cache) {
+            cache.set(path, content, None, hash, result.clone());
         }
     }
 
-    let results: Vec<rigor::AnalysisResult> = collected.into_iter().map(|(_, _, r, _)| r).collect();
+    let results: Vec<rigor::AnalysisResult> =
+        collected.into_iter().map(|(_, _, _, r, _)| r).collect();
     let hits = cache_hits.load(Ordering::Relaxed);
     if !quiet && hits > 0 {
         eprintln!(
@@ -393,13 +393,25 @@ fn run_mutation_score(file: &str, count: usize) -> anyhow::Result<serde_json::Va
         _ => anyhow::bail!("No source file found for {}", file),
     };
     let content = std::fs::read_to_string(&source_path)?;
-    let test_cmd = std::env::var("RIGOR_TEST_CMD").unwrap_or_else(|_| "npm test".to_string());
-    let mutation_result = mutation::run_mutation_test(&source_path, &content, &test_cmd, count)?;
+    let config = crate::config::load_config(path.parent().unwrap_or(Path::new(".")), None)
+        .unwrap_or_default();
+    let mut options = mutation::MutationOptions {
+        count,
+        ..mutation::MutationOptions::from_config(&config.mutation)
+    };
+    if let Ok(command) = std::env::var("RIGOR_TEST_CMD") {
+        options.test_command = Some(command);
+    }
+    let target = mutation::MutationTarget::from_results(source_path, [&result]);
+    let mutation_result = mutation::run_mutation_test(&target, &content, &options)?;
     Ok(serde_json::json!({
         "total": mutation_result.total,
         "killed": mutation_result.killed,
         "survived": mutation_result.survived,
-        "scorePercent": if mutation_result.total > 0 { (mutation_result.killed as f32 / mutation_result.total as f32 * 100.0) as u32 } else { 0 }
+        "timedOut": mutation_result.timed_out,
+        "noCoverage": mutation_result.no_coverage,
+        "errors": mutation_result.errors,
+        "scorePercent": if mutation_result.valid() > 0 { mutation_result.score() as u32 } else { 0 }
     }))
 }
 
@@ -489,96 +501,20 @@ fn run_get_improvement_plan(file: &str) -> anyhow::Result<serde_json::Value> {
     Ok(out)
 }
 
-fn rule_description_and_examples(rule_id: &str) -> Option<(String, String, String, &'static str)> {
-    let (description, example_bad, example_good, category) = match rule_id {
-        "weak-assertion" => (
-            "Flags assertions that are too vague (e.g. toBeTruthy, toBeDefined) instead of asserting the exact expected value.",
-            "expect(result).toBeTruthy()",
-            "expect(result).toBe(true) or expect(result).toEqual(expected)",
-            "Assertion Quality",
-        ),
-        "missing-error-test" => (
-            "The source can throw but there is no test that expects the error (toThrow/rejects).",
-            "No it('throws...') or expect(() => fn()).toThrow()",
-            "it('throws for invalid input', () => { expect(() => parse(invalid)).toThrow(ValidationError); })",
-            "Error Coverage",
-        ),
-        "missing-boundary-test" => (
-            "Boundary or edge values (0, empty, limits) from source are not covered by tests.",
-            "Tests only use normal values",
-            "Add expect(fn(0)).toBe(...); expect(fn('')).toBe(...);",
-            "Boundary Conditions",
-        ),
-        "shared-state" => (
-            "Shared mutable state between tests can cause order-dependent failures.",
-            "let counter = 0; used in multiple tests without reset",
-            "Use beforeEach to reset or const for immutable test data",
-            "Test Isolation",
-        ),
-        "hardcoded-values" => (
-            "Tests use hardcoded real-looking data (e.g. emails) instead of fixtures.",
-            "expect(email).toBe('user@example.com')",
-            "Use faker or test fixtures",
-            "Input Variety",
-        ),
-        "no-assertions" => (
-            "Test has no assertions so it does not verify behavior.",
-            "it('works', () => { doSomething(); });",
-            "it('works', () => { expect(doSomething()).toBe(expected); });",
-            "Assertion Quality",
-        ),
-        "debug-code" => (
-            "Debug code (console.log, debugger, .only) left in tests.",
-            "console.log(x); or it.only(...)",
-            "Remove console.log and .only before committing",
-            "penalty",
-        ),
-        "focused-test" => (
-            "Focused test (.only) will skip other tests when run.",
-            "it.only('...', () => ...)",
-            "it('...', () => ...)",
-            "penalty",
-        ),
-        "flaky-pattern" => (
-            "Non-deterministic or slow patterns (Date.now(), Math.random(), fetch without mock).",
-            "expect(Date.now()).toBeGreaterThan(0)",
-            "Use fake timers and mock fetch/axios",
-            "penalty",
-        ),
-        "vague-test-name" => (
-            "Test name does not describe the scenario or expected outcome.",
-            "it('test 1', ...)",
-            "it('returns 404 when user not found', ...)",
-            "penalty",
-        ),
-        "trivial-assertion" => (
-            "Assertion always passes regardless of code under test (e.g. expect(1).toBe(1)).",
-            "expect(1).toBe(1)",
-            "expect(actualResult).toBe(expected)",
-            "Assertion Quality",
-        ),
-        _ => return None,
-    };
-    Some((
-        description.to_string(),
-        example_bad.to_string(),
-        example_good.to_string(),
-        category,
-    ))
-}
-
 fn run_explain_rule(rule_id: &str) -> anyhow::Result<serde_json::Value> {
     if rule_id.is_empty() {
         anyhow::bail!("ruleId is required");
     }
-    let (description, example_bad, example_good, category) = rule_description_and_examples(rule_id)
+    let info = crate::registry::find(rule_id)
         .ok_or_else(|| anyhow::anyhow!("Unknown rule: {}", rule_id))?;
     Ok(serde_json::json!({
-        "ruleId": rule_id,
-        "category": category,
-        "description": description,
-        "exampleBad": example_bad,
-        "exampleGood": example_good,
+        "ruleId": info.id,
+        "category": info.category.unwrap_or("penalty"),
+        "description": info.description,
+        "exampleBad": info.example_bad,
+        "exampleGood": info.example_good,
+        "defaultSeverity": info.default_severity,
+        "fixable": info.fixable,
     }))
 }
 
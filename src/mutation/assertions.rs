@@ -0,0 +1,243 @@
+//! Test-side mutation: find assertions that carry no weight.
+//!
+//! Deleting an assertion can never make a passing test fail, so each assertion is
+//! neutralized by inverting it instead (`expect(x).toBe(1)` → `expect(x).not.toBe(1)`,
+//! `assert(ok)` → `assert(!(ok))`). A load-bearing assertion fails once inverted; one
+//! that still passes either never runs (a callback that isn't called, an unawaited
+//! promise) or checks nothing, and the test would pass just as well without it.
+//!
+//! Runs reuse [`MutationRun`](super::MutationRun): `Killed` means the inverted assertion failed the tests
+//! (load-bearing), `Survived` means the suite stayed green.
+
+use super::operators::{Mutation, MutationOperator};
+use crate::parser::{TestFileParser, TypeScriptParser};
+use std::path::Path;
+use tree_sitter::Node;
+
+/// One mutation per invertible assertion in the (non-skipped) tests of `test_path`
+pub fn generate_assertion_mutations(test_path: &Path, content: &str) -> Vec<Mutation> {
+    let Ok(mut parser) = TypeScriptParser::for_file(test_path) else {
+        return Vec::new();
+    };
+    let Ok(tree) = parser.parse(content) else {
+        return Vec::new();
+    };
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+
+    let tests = TestFileParser::new(content).extract_tests(&tree);
+    let mut mutations: Vec<Mutation> = Vec::new();
+    for test in tests.iter().filter(|t| !t.is_skipped) {
+        for assertion in &test.assertions {
+            let Some(line_start) = line_starts.get(assertion.location.line - 1) else {
+                continue;
+            };
+            let start = line_start + assertion.location.column - 1;
+            let end = start + assertion.raw.len();
+            if content.get(start..end) != Some(assertion.raw.as_str())
+                || mutations.iter().any(|m| m.start == start && m.end == end)
+            {
+                continue;
+            }
+            let Some(call) = tree
+                .root_node()
+                .descendant_for_byte_range(start, end)
+                .filter(|n| n.kind() == "call_expression" && n.byte_range() == (start..end))
+            else {
+                continue;
+            };
+            let Some(replacement) = inverted(call, content) else {
+                continue;
+            };
+            mutations.push(Mutation {
+                start,
+                end,
+                line: assertion.location.line,
+                column: assertion.location.column,
+                original: assertion.raw.clone(),
+                replacement,
+                description: format!("invert assertion in \"{}\"", test.name),
+                operator: MutationOperator::AssertionInversion,
+                scope: test_range(&line_starts, test.location.line, test.location.end_line)
+                    .unwrap_or(0..content.len()),
+            });
+        }
+    }
+    mutations
+}
+
+/// Text of `call` with its assertion inverted, or `None` for shapes that can't be inverted
+fn inverted(call: Node, src: &str) -> Option<String> {
+    let text = |n: Node| &src[n.byte_range()];
+    let splice = |range: std::ops::Range<usize>, with: &str| {
+        let base = call.start_byte();
+        let mut out = text(call).to_string();
+        out.replace_range(range.start - base..range.end - base, with);
+        out
+    };
+    let function = call.child_by_field_name("function")?;
+    let args = call.child_by_field_name("arguments")?;
+
+    match function.kind() {
+        // assert(value[, message])
+        "identifier" if text(function) == "assert" => {
+            let first = args.named_child(0)?;
+            Some(splice(first.byte_range(), &format!("!({})", text(first))))
+        }
+        "member_expression" => {
+            let object = function.child_by_field_name("object")?;
+            let property = function.child_by_field_name("property")?;
+            let name = text(property);
+
+            if text(object) == "assert" {
+                let negated = negated_assert_method(name)?;
+                return Some(splice(property.byte_range(), negated));
+            }
+
+            // Cypress: .should('be.visible') ↔ .should('not.be.visible')
+            if name == "should" || name == "and" {
+                let first = args.named_child(0)?;
+                if first.kind() != "string" || first.byte_range().len() < 2 {
+                    return None;
+                }
+                let inner = first.start_byte() + 1..first.end_byte() - 1;
+                let chainer = &src[inner.clone()];
+                let flipped = match chainer.strip_prefix("not.") {
+                    Some(rest) => rest.to_string(),
+                    None => format!("not.{}", chainer),
+                };
+                return Some(splice(inner, &flipped));
+            }
+
+            // expect(x).not.m() → expect(x).m(); expect(x)[.resolves].m() → ….not.m()
+            let negated = object.kind() == "member_expression"
+                && object
+                    .child_by_field_name("property")
+                    .is_some_and(|p| text(p) == "not");
+            if negated {
+                let inner = object.child_by_field_name("object")?;
+                is_expect_chain(inner, src)
+                    .then(|| splice(inner.end_byte()..property.start_byte(), "."))
+            } else {
+                is_expect_chain(object, src)
+                    .then(|| splice(property.start_byte()..property.start_byte(), "not."))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `expect(x)`, optionally followed by `.resolves` / `.rejects`
+fn is_expect_chain(node: Node, src: &str) -> bool {
+    match node.kind() {
+        "call_expression" => node
+            .child_by_field_name("function")
+            .is_some_and(|f| &src[f.byte_range()] == "expect"),
+        "member_expression" => {
+            let property = node.child_by_field_name("property");
+            let object = node.child_by_field_name("object");
+            matches!(
+                property.map(|p| &src[p.byte_range()]),
+                Some("resolves" | "rejects")
+            ) && object.is_some_and(|o| is_expect_chain(o, src))
+        }
+        _ => false,
+    }
+}
+
+/// node:assert / chai `assert.*` methods and their opposites
+fn negated_assert_method(name: &str) -> Option<&'static str> {
+    const PAIRS: &[(&str, &str)] = &[
+        ("equal", "notEqual"),
+        ("strictEqual", "notStrictEqual"),
+        ("deepEqual", "notDeepEqual"),
+        ("deepStrictEqual", "notDeepStrictEqual"),
+        ("match", "doesNotMatch"),
+        ("isTrue", "isFalse"),
+        ("isNull", "isNotNull"),
+        ("isUndefined", "isDefined"),
+        ("include", "notInclude"),
+        ("throws", "doesNotThrow"),
+        ("rejects", "doesNotReject"),
+    ];
+    PAIRS.iter().find_map(|&(a, b)| {
+        if name == a {
+            Some(b)
+        } else if name == b {
+            Some(a)
+        } else {
+            None
+        }
+    })
+}
+
+fn test_range(
+    line_starts: &[usize],
+    line: usize,
+    end_line: Option<usize>,
+) -> Option<std::ops::Range<usize>> {
+    let start = *line_starts.get(line.checked_sub(1)?)?;
+    let end = *line_starts.get(end_line?)?;
+    Some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replacements(content: &str) -> Vec<String> {
+        generate_assertion_mutations(Path::new("a.test.ts"), content)
+            .into_iter()
+            .map(|m| m.replacement)
+            .collect()
+    }
+
+    #[test]
+    fn test_expect_chains_are_inverted() {
+        let content = r#"
+it('works', async () => {
+  expect(total).toBe(3);
+  expect(list).not.toContain(0);
+  await expect(load()).resolves.toEqual({ ok: true });
+});
+"#;
+        assert_eq!(
+            replacements(content),
+            vec![
+                "expect(total).not.toBe(3)",
+                "expect(list).toContain(0)",
+                "expect(load()).resolves.not.toEqual({ ok: true })",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assert_and_cypress_forms() {
+        let content = r#"
+it('works', () => {
+  assert(user.active, 'active');
+  assert.strictEqual(a, 1);
+  assert.notDeepEqual(b, []);
+  cy.get('.btn').should('be.visible');
+  cy.get('.err').should('not.exist');
+});
+"#;
+        assert_eq!(
+            replacements(content),
+            vec![
+                "assert(!(user.active), 'active')",
+                "assert.notStrictEqual(a, 1)",
+                "assert.deepEqual(b, [])",
+                "cy.get('.btn').should('not.be.visible')",
+                "cy.get('.err').should('exist')",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skipped_tests_are_ignored() {
+        let content = "it.skip('later', () => { expect(a).toBe(1); });";
+        assert!(replacements(content).is_empty());
+    }
+}
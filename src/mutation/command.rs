@@ -0,0 +1,142 @@
+//! Test command templates for mutation runs.
+//!
+//! A template is split into words before placeholders are filled in, so paths and test
+//! names never need shell quoting. A word that is exactly `{testFile}` expands to one
+//! argument per test file; inside a larger word the files are joined with spaces.
+
+use crate::TestFramework;
+use std::path::PathBuf;
+
+/// Fallback when neither the config nor the framework gives a command
+pub const DEFAULT_TEST_COMMAND: &str = "npm test";
+
+/// Command template that runs only the given test files, per framework
+pub fn default_for_framework(framework: TestFramework) -> Option<&'static str> {
+    match framework {
+        TestFramework::Jest => Some("npx jest {testFile}"),
+        TestFramework::Vitest => Some("npx vitest run {testFile}"),
+        TestFramework::Mocha => Some("npx mocha {testFile}"),
+        TestFramework::Playwright => Some("npx playwright test {testFile}"),
+        // `--spec` takes a single comma-separated argument; let the project script decide
+        TestFramework::Cypress | TestFramework::Unknown => None,
+    }
+}
+
+/// Values substituted into a test command template
+#[derive(Debug, Clone, Default)]
+pub struct CommandContext {
+    /// Test files to run, relative to the directory the command runs in
+    pub test_files: Vec<PathBuf>,
+    /// Mutated source file, relative to the directory the command runs in
+    pub source_file: PathBuf,
+    /// Names of the tests in `test_files`
+    pub test_names: Vec<String>,
+}
+
+/// Expand `template` into a program and its arguments
+pub fn expand(template: &str, ctx: &CommandContext) -> Vec<String> {
+    let test_files: Vec<String> = ctx
+        .test_files
+        .iter()
+        .map(|p| p.to_string_lossy().into_owned())
+        .collect();
+    let source_file = ctx.source_file.to_string_lossy();
+    let test_name_pattern = test_name_pattern(&ctx.test_names);
+
+    let mut argv = Vec::new();
+    for word in template.split_whitespace() {
+        if word == "{testFile}" {
+            argv.extend(test_files.iter().cloned());
+            continue;
+        }
+        argv.push(
+            word.replace("{testFile}", &test_files.join(" "))
+                .replace("{sourceFile}", &source_file)
+                .replace("{testNamePattern}", &test_name_pattern),
+        );
+    }
+    if argv.is_empty() {
+        argv = DEFAULT_TEST_COMMAND
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+    }
+    argv
+}
+
+/// Regex matching any of `names` (for `jest -t` / `vitest -t`); matches everything when empty
+fn test_name_pattern(names: &[String]) -> String {
+    if names.is_empty() {
+        return ".*".to_string();
+    }
+    let escaped: Vec<String> = names.iter().map(|n| regex::escape(n)).collect();
+    format!("({})", escaped.join("|"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> CommandContext {
+        CommandContext {
+            test_files: vec![
+                PathBuf::from("src/age.test.ts"),
+                PathBuf::from("src/my dir/age.spec.ts"),
+            ],
+            source_file: PathBuf::from("src/age.ts"),
+            test_names: vec!["adult (18+)".to_string(), "minor".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_test_file_word_expands_to_one_arg_per_file() {
+        let argv = expand("npx vitest run {testFile}", &ctx());
+        assert_eq!(
+            argv,
+            vec![
+                "npx",
+                "vitest",
+                "run",
+                "src/age.test.ts",
+                "src/my dir/age.spec.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_embedded_placeholders() {
+        let argv = expand(
+            "npx jest --findRelatedTests={sourceFile} -t {testNamePattern}",
+            &ctx(),
+        );
+        assert_eq!(
+            argv,
+            vec![
+                "npx",
+                "jest",
+                "--findRelatedTests=src/age.ts",
+                "-t",
+                r"(adult \(18\+\)|minor)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_test_files_runs_whole_suite() {
+        let argv = expand("npx vitest run {testFile}", &CommandContext::default());
+        assert_eq!(argv, vec!["npx", "vitest", "run"]);
+        assert_eq!(
+            expand("  ", &CommandContext::default()),
+            vec!["npm", "test"]
+        );
+    }
+
+    #[test]
+    fn test_framework_defaults() {
+        assert_eq!(
+            default_for_framework(TestFramework::Vitest),
+            Some("npx vitest run {testFile}")
+        );
+        assert_eq!(default_for_framework(TestFramework::Unknown), None);
+    }
+}
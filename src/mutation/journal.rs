@@ -0,0 +1,346 @@
+//! Crash-safe journal of in-flight mutation sandboxes.
+//!
+//! Every sandbox registers itself in `.rigor-mutation-journal.json` at the project root
+//! before any mutant is written, and records the file it is about to mutate together with
+//! its original content. The journal is rewritten atomically (temp file + rename), so a
+//! Ctrl-C, panic or SIGKILL at any point leaves either the previous or the next state.
+//! On startup `recover` restores any journaled file still holding a mutant and removes
+//! sandboxes whose owning process is gone. Each package of a monorepo keeps its own
+//! journal, so before mutating `recover_for_sources` also recovers every root the run's
+//! sandboxes will mirror.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const JOURNAL_VERSION: u32 = 1;
+pub(crate) const JOURNAL_FILENAME: &str = ".rigor-mutation-journal.json";
+
+/// Serializes read-modify-write cycles between worker threads of this process
+static JOURNAL_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalData {
+    version: u32,
+    sandboxes: Vec<SandboxRecord>,
+}
+
+impl Default for JournalData {
+    fn default() -> Self {
+        Self {
+            version: JOURNAL_VERSION,
+            sandboxes: Vec::new(),
+        }
+    }
+}
+
+/// A sandbox directory owned by a (possibly dead) rigor process
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SandboxRecord {
+    dir: PathBuf,
+    pid: u32,
+    /// File currently holding a mutant, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mutated: Option<MutatedFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MutatedFile {
+    path: PathBuf,
+    original: String,
+}
+
+/// What `recover` cleaned up after an interrupted run
+#[derive(Debug, Default)]
+pub struct RecoveryReport {
+    /// Files that still contained a mutant and were restored
+    pub restored_files: Vec<PathBuf>,
+    /// Stale sandbox directories that were removed
+    pub removed_sandboxes: Vec<PathBuf>,
+}
+
+impl RecoveryReport {
+    pub fn is_empty(&self) -> bool {
+        self.restored_files.is_empty() && self.removed_sandboxes.is_empty()
+    }
+}
+
+/// Handle to the journal of one project root
+#[derive(Debug, Clone)]
+pub(crate) struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    pub(crate) fn new(project_root: &Path) -> Self {
+        Self {
+            path: project_root.join(JOURNAL_FILENAME),
+        }
+    }
+
+    /// Register a sandbox before anything is written into it
+    pub(crate) fn add_sandbox(&self, dir: &Path) -> std::io::Result<()> {
+        self.update(|data| {
+            data.sandboxes.push(SandboxRecord {
+                dir: dir.to_path_buf(),
+                pid: std::process::id(),
+                mutated: None,
+            });
+        })
+    }
+
+    /// Record that `path` (inside `dir`) is about to be overwritten with a mutant
+    pub(crate) fn begin_mutation(
+        &self,
+        dir: &Path,
+        path: &Path,
+        original: &str,
+    ) -> std::io::Result<()> {
+        self.update(|data| {
+            if let Some(record) = data.sandboxes.iter_mut().find(|r| r.dir == dir) {
+                record.mutated = Some(MutatedFile {
+                    path: path.to_path_buf(),
+                    original: original.to_string(),
+                });
+            }
+        })
+    }
+
+    /// Record that the mutated file in `dir` has been restored
+    pub(crate) fn end_mutation(&self, dir: &Path) -> std::io::Result<()> {
+        self.update(|data| {
+            if let Some(record) = data.sandboxes.iter_mut().find(|r| r.dir == dir) {
+                record.mutated = None;
+            }
+        })
+    }
+
+    /// Drop a sandbox from the journal once its directory is gone
+    pub(crate) fn remove_sandbox(&self, dir: &Path) -> std::io::Result<()> {
+        self.update(|data| data.sandboxes.retain(|r| r.dir != dir))
+    }
+
+    fn update(&self, f: impl FnOnce(&mut JournalData)) -> std::io::Result<()> {
+        let _guard = JOURNAL_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut data = self.load();
+        f(&mut data);
+        self.store(&data)
+    }
+
+    fn load(&self) -> JournalData {
+        fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn store(&self, data: &JournalData) -> std::io::Result<()> {
+        if data.sandboxes.is_empty() {
+            return match fs::remove_file(&self.path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            };
+        }
+        let json = serde_json::to_string_pretty(data).map_err(std::io::Error::other)?;
+        let tmp = self
+            .path
+            .with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+/// Restore files and remove sandboxes left behind by rigor processes that are no longer
+/// running. Sandboxes owned by a live process (e.g. a concurrent `rigor --mutate`) are kept.
+pub fn recover(project_root: &Path) -> std::io::Result<RecoveryReport> {
+    let journal = Journal::new(project_root);
+    let mut report = RecoveryReport::default();
+    if !journal.path.exists() {
+        return Ok(report);
+    }
+
+    journal.update(|data| {
+        data.sandboxes.retain(|record| {
+            if record.pid != std::process::id() && process_alive(record.pid) {
+                return true;
+            }
+            if let Some(mutated) = &record.mutated {
+                let still_mutated = fs::read_to_string(&mutated.path)
+                    .map(|current| current != mutated.original)
+                    .unwrap_or(false);
+                if still_mutated && fs::write(&mutated.path, &mutated.original).is_ok() {
+                    report.restored_files.push(mutated.path.clone());
+                }
+            }
+            if record.dir.exists() && fs::remove_dir_all(&record.dir).is_ok() {
+                report.removed_sandboxes.push(record.dir.clone());
+            }
+            record.dir.exists()
+        });
+    })?;
+
+    Ok(report)
+}
+
+/// Run `recover` on the project root of every source a mutation run is about to sandbox.
+/// Each root is recovered once; the reports are merged.
+pub fn recover_for_sources<'a>(
+    sources: impl IntoIterator<Item = &'a Path>,
+) -> std::io::Result<RecoveryReport> {
+    let mut roots: Vec<PathBuf> = sources
+        .into_iter()
+        .map(super::sandbox::project_root_for)
+        .collect();
+    roots.sort();
+    roots.dedup();
+
+    let mut report = RecoveryReport::default();
+    for root in roots {
+        let recovered = recover(&root)?;
+        report.restored_files.extend(recovered.restored_files);
+        report.removed_sandboxes.extend(recovered.removed_sandboxes);
+    }
+    Ok(report)
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 performs the permission/existence check without delivering anything
+    let rc = unsafe { libc::kill(pid, 0) };
+    rc == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    // No cheap liveness probe; treat journaled sandboxes as stale
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A pid that cannot belong to a running process
+    const DEAD_PID: u32 = i32::MAX as u32;
+
+    fn write_record(root: &Path, record: SandboxRecord) {
+        let journal = Journal::new(root);
+        journal
+            .store(&JournalData {
+                version: JOURNAL_VERSION,
+                sandboxes: vec![record],
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_recover_restores_mutated_file_and_removes_sandbox() {
+        let root = tempfile::tempdir().unwrap();
+        let sandbox = tempfile::tempdir().unwrap().keep();
+        let file = sandbox.join("age.ts");
+        fs::write(&file, "return age > 18;").unwrap();
+
+        write_record(
+            root.path(),
+            SandboxRecord {
+                dir: sandbox.clone(),
+                pid: DEAD_PID,
+                mutated: Some(MutatedFile {
+                    path: file.clone(),
+                    original: "return age >= 18;".to_string(),
+                }),
+            },
+        );
+
+        let report = recover(root.path()).unwrap();
+        assert_eq!(report.restored_files, vec![file]);
+        assert_eq!(report.removed_sandboxes, vec![sandbox.clone()]);
+        assert!(!sandbox.exists());
+        assert!(!root.path().join(JOURNAL_FILENAME).exists());
+    }
+
+    #[test]
+    fn test_recover_restores_file_outside_sandbox() {
+        let root = tempfile::tempdir().unwrap();
+        let file = root.path().join("age.ts");
+        fs::write(&file, "return age > 18;").unwrap();
+
+        write_record(
+            root.path(),
+            SandboxRecord {
+                dir: root.path().join("missing-sandbox"),
+                pid: DEAD_PID,
+                mutated: Some(MutatedFile {
+                    path: file.clone(),
+                    original: "return age >= 18;".to_string(),
+                }),
+            },
+        );
+
+        let report = recover(root.path()).unwrap();
+        assert_eq!(report.restored_files, vec![file.clone()]);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "return age >= 18;");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_recover_keeps_sandbox_of_live_process() {
+        let root = tempfile::tempdir().unwrap();
+        let sandbox = tempfile::tempdir().unwrap();
+
+        // pid 1 is always alive
+        write_record(
+            root.path(),
+            SandboxRecord {
+                dir: sandbox.path().to_path_buf(),
+                pid: 1,
+                mutated: None,
+            },
+        );
+
+        let report = recover(root.path()).unwrap();
+        assert!(report.is_empty());
+        assert!(sandbox.path().exists());
+        assert!(root.path().join(JOURNAL_FILENAME).exists());
+    }
+
+    #[test]
+    fn test_recover_for_sources_finds_package_journals() {
+        let repo = tempfile::tempdir().unwrap();
+        fs::write(repo.path().join("package.json"), "{}").unwrap();
+        let package = repo.path().join("packages").join("a");
+        fs::create_dir_all(package.join("src")).unwrap();
+        fs::write(package.join("package.json"), "{}").unwrap();
+        let source = package.join("src").join("age.ts");
+        fs::write(&source, "return age >= 18;").unwrap();
+        let package = package.canonicalize().unwrap();
+
+        let sandbox = tempfile::tempdir().unwrap().keep();
+        write_record(
+            &package,
+            SandboxRecord {
+                dir: sandbox.clone(),
+                pid: DEAD_PID,
+                mutated: None,
+            },
+        );
+
+        // The monorepo root has no journal of its own
+        assert!(recover(repo.path()).unwrap().is_empty());
+
+        let report = recover_for_sources([source.as_path(), source.as_path()]).unwrap();
+        assert_eq!(report.removed_sandboxes, vec![sandbox.clone()]);
+        assert!(!sandbox.exists());
+        assert!(!package.join(JOURNAL_FILENAME).exists());
+    }
+
+    #[test]
+    fn test_recover_without_journal_is_noop() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(recover(root.path()).unwrap().is_empty());
+    }
+}
@@ -1,40 +1,104 @@
 //! Fast mutation mode - run a small set of strategic mutants and report kill rate.
 
+mod assertions;
+mod command;
+mod journal;
 mod operators;
+mod preview;
 mod relevance;
 mod reporter;
 mod runner;
 mod sampler;
+mod sandbox;
+mod store;
+pub mod stryker;
 
-pub use operators::{apply_mutation, Mutation, MutationOperator};
-pub use relevance::{relevance_summary, RelevanceSummary, SurvivedAtLine};
+pub use assertions::generate_assertion_mutations;
+pub use journal::{
+    recover as recover_mutation_journal, recover_for_sources as recover_mutation_journals,
+    RecoveryReport,
+};
+pub use operators::{
+    apply_mutation, function_name_at, Mutation, MutationOperator, OperatorSettings, OPERATOR_GROUPS,
+};
+pub use preview::{unified_diff, MutationPreview};
+pub use relevance::{hint_for, relevance_summary, RelevanceSummary, SurvivedAtLine};
 pub use reporter::report as report_mutation_result;
+pub use reporter::report_assertions as report_assertion_mutation_result;
 pub use reporter::report_batch as report_batch_mutation_result;
+pub use reporter::report_imported as report_imported_mutation_result;
+pub use reporter::report_preview as report_mutation_preview;
 
+use sandbox::{Sandbox, SandboxPool};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+use store::{StoreSet, StoredRun};
 
 /// Result of running mutation testing on a source file
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MutationResult {
     /// Path to the source file that was mutated
     pub source_path: std::path::PathBuf,
-    /// Total mutants generated
+    /// Total mutants run
     pub total: usize,
     /// Number of mutants killed (tests failed)
     pub killed: usize,
     /// Number of mutants that survived (tests passed)
     pub survived: usize,
+    /// Number of mutants whose test run exceeded the timeout (counted as detected)
+    pub timed_out: usize,
+    /// Number of mutants on lines no test executes (counted as undetected)
+    pub no_coverage: usize,
+    /// Number of mutants that failed to compile or run (excluded from the score)
+    pub errors: usize,
     /// Details per mutation
     pub details: Vec<MutationRun>,
 }
 
 impl MutationResult {
-    /// Calculate mutation score as percentage
+    /// Tally the outcomes of `runs`
+    pub fn from_runs(source_path: &Path, runs: Vec<MutationRun>) -> Self {
+        let count = |status: MutantStatus| runs.iter().filter(|r| r.status == status).count();
+        Self {
+            source_path: source_path.to_path_buf(),
+            total: runs.len(),
+            killed: count(MutantStatus::Killed),
+            survived: count(MutantStatus::Survived),
+            timed_out: count(MutantStatus::Timeout),
+            no_coverage: count(MutantStatus::NoCoverage),
+            errors: count(MutantStatus::CompileError) + count(MutantStatus::RuntimeError),
+            details: runs,
+        }
+    }
+
+    /// Mutants the tests caught (killed or timed out)
+    pub fn detected(&self) -> usize {
+        self.killed + self.timed_out
+    }
+
+    /// Mutants that count towards the score (everything but compile/runtime errors)
+    pub fn valid(&self) -> usize {
+        self.total.saturating_sub(self.errors)
+    }
+
+    /// Mutation score counting only mutants on code the tests execute
+    pub fn covered_score(&self) -> f32 {
+        let covered = self.valid().saturating_sub(self.no_coverage);
+        if covered == 0 {
+            100.0
+        } else {
+            (self.detected() as f32 / covered as f32) * 100.0
+        }
+    }
+
+    /// Calculate mutation score as percentage of valid mutants that were detected
     pub fn score(&self) -> f32 {
-        if self.total == 0 {
+        if self.valid() == 0 {
             100.0
         } else {
-            (self.killed as f32 / self.total as f32) * 100.0
+            (self.detected() as f32 / self.valid() as f32) * 100.0
         }
     }
 }
@@ -52,82 +116,513 @@ pub struct BatchMutationResult {
     pub total_killed: usize,
     /// Total survived mutants across all files
     pub total_survived: usize,
+    /// Total timed-out mutants across all files
+    pub total_timed_out: usize,
+    /// Total uncovered mutants across all files
+    pub total_no_coverage: usize,
+    /// Total mutants that failed to compile or run across all files
+    pub total_errors: usize,
+    /// Source files that couldn't be mutation-tested (unreadable, or tests failing
+    /// unmutated), with the reason
+    pub failures: Vec<(PathBuf, std::io::Error)>,
+}
+
+/// What happened when the tests ran against one mutant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MutantStatus {
+    /// Tests failed: the mutant was caught
+    Killed,
+    /// Tests passed: the change went unnoticed
+    Survived,
+    /// Tests ran past the per-mutant timeout (usually an infinite loop); counts as caught
+    Timeout,
+    /// The mutant did not type-check or transpile; excluded from the score
+    CompileError,
+    /// The test command could not be started or the mutant could not be written;
+    /// excluded from the score
+    RuntimeError,
+    /// No test executes the mutated line; counts as not caught
+    NoCoverage,
+}
+
+impl MutantStatus {
+    /// True if the tests caught the mutant
+    pub fn is_detected(self) -> bool {
+        matches!(self, Self::Killed | Self::Timeout)
+    }
+
+    /// True if the mutant went unnoticed by the tests
+    pub fn is_undetected(self) -> bool {
+        matches!(self, Self::Survived | Self::NoCoverage)
+    }
+
+    /// Upper-case label used in reports
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Killed => "KILLED",
+            Self::Survived => "SURVIVED",
+            Self::Timeout => "TIMEOUT",
+            Self::CompileError => "COMPILE ERROR",
+            Self::RuntimeError => "RUNTIME ERROR",
+            Self::NoCoverage => "NO COVERAGE",
+        }
+    }
 }
 
 /// Outcome of running tests against one mutant
 #[derive(Debug, Clone)]
 pub struct MutationRun {
     pub mutation: Mutation,
-    pub killed: bool,
+    pub status: MutantStatus,
     pub stdout: String,
     pub stderr: String,
+    /// Wall-clock time of the test run
+    pub duration: Duration,
+    /// Outcome reused from a previous run (mutant and tests unchanged); no output is kept
+    pub cached: bool,
+}
+
+/// Per-mutant time limit, derived from how long the tests take on the unmutated source
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MutantTimeout {
+    /// Multiplier applied to the baseline run duration
+    pub factor: f64,
+    /// Fixed allowance added on top, for process startup and noisy machines
+    pub extra: Duration,
+}
+
+impl Default for MutantTimeout {
+    fn default() -> Self {
+        Self {
+            factor: 1.5,
+            extra: Duration::from_secs(5),
+        }
+    }
+}
+
+impl MutantTimeout {
+    /// Time limit for one mutant given the baseline run duration
+    pub fn for_baseline(&self, baseline: Duration) -> Duration {
+        baseline.mul_f64(self.factor.max(1.0)) + self.extra
+    }
 }
 
-/// Run mutation testing: generate mutants, run tests, report.
+/// Mutant budget per source file for each `--mutate` mode
+pub const MODES: &[(&str, usize)] = &[("quick", 10), ("medium", 30), ("full", usize::MAX)];
+
+/// Budget for a `--mutate` mode; unknown modes get the `quick` budget
+pub fn count_for_mode(mode: &str) -> usize {
+    MODES
+        .iter()
+        .find(|(name, _)| *name == mode)
+        .map_or(MODES[0].1, |&(_, count)| count)
+}
+
+/// Settings for a mutation testing run
+#[derive(Debug, Clone)]
+pub struct MutationOptions {
+    /// Test command template (see [`MutationTarget`] for placeholders). When unset, each
+    /// target uses its framework's default, falling back to `npm test`.
+    pub test_command: Option<String>,
+    /// Maximum number of mutants per source file
+    pub count: usize,
+    /// Per-mutant timeout
+    pub timeout: MutantTimeout,
+    /// Reuse outcomes of unchanged mutants from `.rigor-mutation-cache.json`
+    pub incremental: bool,
+    /// Enabled operator groups, custom operators and excluded functions
+    pub operators: OperatorSettings,
+    /// Seed for picking `count` mutants out of a larger set
+    pub seed: u64,
+}
+
+impl Default for MutationOptions {
+    fn default() -> Self {
+        Self {
+            test_command: None,
+            count: 10,
+            timeout: MutantTimeout::default(),
+            incremental: true,
+            operators: OperatorSettings::default(),
+            seed: 0,
+        }
+    }
+}
+
+impl MutationOptions {
+    /// Options from the `mutation` config block, with the default count and timeout
+    pub fn from_config(config: &crate::config::MutationConfig) -> Self {
+        Self {
+            test_command: config.test_command.clone(),
+            operators: OperatorSettings::from_config(config),
+            seed: config.seed.unwrap_or(0),
+            ..Default::default()
+        }
+    }
+
+    /// Command template used for `target`
+    pub fn test_command_for(&self, target: &MutationTarget) -> &str {
+        self.test_command
+            .as_deref()
+            .or_else(|| command::default_for_framework(target.framework))
+            .unwrap_or(command::DEFAULT_TEST_COMMAND)
+    }
+}
+
+/// A source file to mutate and the tests mapped to it.
+///
+/// The test command template may reference `{testFile}` (these test files), `{sourceFile}`
+/// and `{testNamePattern}` (a regex matching `test_names`).
+#[derive(Debug, Clone)]
+pub struct MutationTarget {
+    pub source_path: PathBuf,
+    pub test_files: Vec<PathBuf>,
+    pub test_names: Vec<String>,
+    /// Framework of the test files, used to pick a default test command
+    pub framework: crate::TestFramework,
+    /// Line coverage of the source; mutants on unexecuted lines are reported as
+    /// [`MutantStatus::NoCoverage`] without running, and the budget goes to covered code
+    pub coverage: Option<crate::coverage::FileCoverage>,
+}
+
+impl MutationTarget {
+    /// Target with no known tests: the test command runs the whole suite
+    pub fn new(source_path: impl Into<PathBuf>) -> Self {
+        Self {
+            source_path: source_path.into(),
+            test_files: Vec::new(),
+            test_names: Vec::new(),
+            framework: crate::TestFramework::Unknown,
+            coverage: None,
+        }
+    }
+
+    /// Target for `source_path`, run against the analyzed test files mapped to it
+    pub fn from_results<'a>(
+        source_path: impl Into<PathBuf>,
+        results: impl IntoIterator<Item = &'a crate::AnalysisResult>,
+    ) -> Self {
+        let mut target = Self::new(source_path);
+        for result in results {
+            target.test_files.push(result.file_path.clone());
+            if let Some(ref scores) = result.test_scores {
+                target
+                    .test_names
+                    .extend(scores.iter().map(|t| t.name.clone()));
+            }
+            if target.framework == crate::TestFramework::Unknown {
+                target.framework = result.framework;
+            }
+        }
+        target
+    }
+}
+
+/// Run mutation testing: generate mutants, run tests in a sandboxed copy of the project, report.
+///
+/// Fails if the test command does not pass on the unmutated source, since every mutant
+/// would then look killed.
 pub fn run_mutation_test(
-    source_path: &Path,
+    target: &MutationTarget,
     source_content: &str,
-    test_command: &str,
-    count: usize,
+    options: &MutationOptions,
+) -> std::io::Result<MutationResult> {
+    let sandbox = Sandbox::create(&sandbox::project_root_for(&target.source_path))?;
+    let stores = StoreSet::default();
+    let result = run_mutation_test_in(&sandbox, target, source_content, options, &stores);
+    stores.save();
+    result
+}
+
+/// Mutants considered for `target`: what the configured operators generate, minus no-ops,
+/// in source order. [`MutationPreview`] lists the same mutants.
+fn candidate_mutations(
+    target: &MutationTarget,
+    source_content: &str,
+    options: &MutationOptions,
+) -> Vec<Mutation> {
+    let mut mutations: Vec<Mutation> =
+        operators::generate_mutations_with(&target.source_path, source_content, &options.operators)
+            .into_iter()
+            .filter(|m| apply_mutation(source_content, m) != source_content)
+            .collect();
+    mutations.sort_by_key(|m| m.start);
+    mutations
+}
+
+/// Covered mutants a run executes and uncovered ones it reports as
+/// [`MutantStatus::NoCoverage`], sampled from `candidates` with the options' budget
+fn select_for_run(
+    candidates: &[Mutation],
+    target: &MutationTarget,
+    options: &MutationOptions,
+) -> (Vec<Mutation>, Vec<Mutation>) {
+    let (covered, uncovered) =
+        sampler::partition_by_coverage(candidates.to_vec(), target.coverage.as_ref());
+    let selected = sampler::select_mutations(&covered, options.count, options.seed);
+    let uncovered =
+        sampler::select_uncovered(&uncovered, covered.len(), options.count, options.seed);
+    (selected, uncovered)
+}
+
+fn run_mutation_test_in(
+    sandbox: &Sandbox,
+    target: &MutationTarget,
+    source_content: &str,
+    options: &MutationOptions,
+    stores: &StoreSet,
+) -> std::io::Result<MutationResult> {
+    let source_path = target.source_path.as_path();
+    let mutations = candidate_mutations(target, source_content, options);
+    let (selected, uncovered) = select_for_run(&mutations, target, options);
+    let no_coverage: Vec<MutationRun> = uncovered
+        .into_iter()
+        .map(|mutation| MutationRun {
+            mutation,
+            status: MutantStatus::NoCoverage,
+            stdout: String::new(),
+            stderr: String::new(),
+            duration: Duration::ZERO,
+            cached: false,
+        })
+        .collect();
+
+    if selected.is_empty() {
+        return Ok(MutationResult::from_runs(source_path, no_coverage));
+    }
+
+    let argv = command::expand(
+        options.test_command_for(target),
+        &command_context(sandbox, target),
+    );
+    let tests_hash = store::tests_hash(&argv, &target.test_files).filter(|_| options.incremental);
+    let ids: Vec<String> = selected
+        .iter()
+        .map(|m| store::mutant_id(source_content, m))
+        .collect();
+
+    // Reuse outcomes of mutants whose function and tests are unchanged
+    let mut runs: Vec<Option<MutationRun>> = match tests_hash {
+        Some(ref hash) => stores.with(sandbox.project_root(), |store| {
+            selected
+                .iter()
+                .zip(&ids)
+                .map(|(mutation, id)| {
+                    let (status, duration) = store.lookup(source_path, id, hash)?;
+                    Some(MutationRun {
+                        mutation: mutation.clone(),
+                        status,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        duration,
+                        cached: true,
+                    })
+                })
+                .collect()
+        }),
+        None => vec![None; selected.len()],
+    };
+
+    let pending: Vec<Mutation> = selected
+        .iter()
+        .zip(&runs)
+        .filter(|(_, run)| run.is_none())
+        .map(|(mutation, _)| mutation.clone())
+        .collect();
+    if !pending.is_empty() {
+        let baseline = runner::run_baseline(sandbox, &argv)?;
+        let fresh = runner::run_mutations(
+            sandbox,
+            source_path,
+            source_content,
+            &pending,
+            &argv,
+            options.timeout.for_baseline(baseline),
+        )?;
+        // One run per pending mutant, in order: no-op mutants were dropped above
+        for (slot, run) in runs.iter_mut().filter(|r| r.is_none()).zip(fresh) {
+            *slot = Some(run);
+        }
+    }
+
+    if let Some(hash) = tests_hash {
+        let live: HashSet<String> = mutations
+            .iter()
+            .map(|m| store::mutant_id(source_content, m))
+            .collect();
+        // Runtime errors depend on the environment, not the mutant, so they are retried
+        let outcomes: Vec<(String, StoredRun)> = runs
+            .iter()
+            .zip(&ids)
+            .filter_map(|(run, id)| Some((run.as_ref()?, id)))
+            .filter(|(run, _)| !run.cached && run.status != MutantStatus::RuntimeError)
+            .map(|(run, id)| {
+                let stored = StoredRun {
+                    status: run.status,
+                    duration_ms: run.duration.as_millis() as u64,
+                    tests_hash: hash.clone(),
+                };
+                (id.clone(), stored)
+            })
+            .collect();
+        stores.with(sandbox.project_root(), |store| {
+            store.update(source_path, &live, outcomes)
+        });
+    }
+
+    let runs = runs.into_iter().flatten().chain(no_coverage).collect();
+    Ok(MutationResult::from_runs(source_path, runs))
+}
+
+/// Placeholder values for `target`, with paths relative to the sandbox root (where the
+/// test command runs). Test files outside the project are dropped.
+fn command_context(sandbox: &Sandbox, target: &MutationTarget) -> command::CommandContext {
+    let relative = |path: &Path| {
+        sandbox
+            .map(path)
+            .and_then(|p| p.strip_prefix(sandbox.dir()).ok().map(Path::to_path_buf))
+    };
+    command::CommandContext {
+        test_files: target
+            .test_files
+            .iter()
+            .filter_map(|p| relative(p))
+            .collect(),
+        source_file: relative(&target.source_path).unwrap_or_default(),
+        test_names: target.test_names.clone(),
+    }
+}
+
+/// Invert each assertion in a test file (`target.source_path`, usually built with
+/// [`MutationTarget::from_results`] for the test's own result) and run its tests.
+/// Survivors are assertions the tests pass without; pass the results to
+/// [`AnalysisEngine::with_assertion_results`](crate::analyzer::AnalysisEngine::with_assertion_results)
+/// to report them.
+pub fn run_assertion_mutation_test(
+    target: &MutationTarget,
+    test_content: &str,
+    options: &MutationOptions,
 ) -> std::io::Result<MutationResult> {
-    let mutations = operators::generate_mutations(source_content);
-    let selected = sampler::select_mutations(&mutations, count);
-    let runs = runner::run_mutations(source_path, source_content, &selected, test_command)?;
-    let killed = runs.iter().filter(|r| r.killed).count();
-    let survived = runs.len() - killed;
-
-    Ok(MutationResult {
-        source_path: source_path.to_path_buf(),
-        total: selected.len(),
-        killed,
-        survived,
-        details: runs,
+    let sandbox = Sandbox::create(&sandbox::project_root_for(&target.source_path))?;
+    run_assertion_mutation_test_in(&sandbox, target, test_content, options)
+}
+
+/// [`run_assertion_mutation_test`] for several test files, one sandbox per worker.
+/// Returns one result per target, in order, paired with the test file it belongs to.
+pub fn run_batch_assertion_mutation_test(
+    targets: &[MutationTarget],
+    options: &MutationOptions,
+    parallel: bool,
+) -> Vec<(PathBuf, std::io::Result<MutationResult>)> {
+    run_each(targets, parallel, |sandbox, target, content| {
+        run_assertion_mutation_test_in(sandbox, target, content, options)
     })
 }
 
-/// Run mutation testing on multiple source files
-pub fn run_batch_mutation_test(
-    source_paths: &[PathBuf],
-    test_command: &str,
-    count_per_file: usize,
+fn run_assertion_mutation_test_in(
+    sandbox: &Sandbox,
+    target: &MutationTarget,
+    test_content: &str,
+    options: &MutationOptions,
+) -> std::io::Result<MutationResult> {
+    let test_path = target.source_path.as_path();
+    let mutations = assertions::generate_assertion_mutations(test_path, test_content);
+    if mutations.is_empty() {
+        return Ok(MutationResult::from_runs(test_path, Vec::new()));
+    }
+    let argv = command::expand(
+        options.test_command_for(target),
+        &command_context(sandbox, target),
+    );
+    let baseline = runner::run_baseline(sandbox, &argv)?;
+    let runs = runner::run_mutations(
+        sandbox,
+        test_path,
+        test_content,
+        &mutations,
+        &argv,
+        options.timeout.for_baseline(baseline),
+    )?;
+    Ok(MutationResult::from_runs(test_path, runs))
+}
+
+/// Run `run` for every target, each worker in its own pooled sandbox. A target that can't
+/// be read or run yields its error instead of being dropped.
+fn run_each(
+    targets: &[MutationTarget],
     parallel: bool,
-) -> std::io::Result<BatchMutationResult> {
-    let results: Vec<MutationResult> = if parallel {
+    run: impl Fn(&Sandbox, &MutationTarget, &str) -> std::io::Result<MutationResult> + Sync,
+) -> Vec<(PathBuf, std::io::Result<MutationResult>)> {
+    let pool = SandboxPool::default();
+    let run_one = |target: &MutationTarget| -> (PathBuf, std::io::Result<MutationResult>) {
+        let path = &target.source_path;
+        let result = std::fs::read_to_string(path).and_then(|content| {
+            let sandbox = pool.acquire(&sandbox::project_root_for(path))?;
+            let result = run(&sandbox, target, &content);
+            // Only clean sandboxes go back to the pool; a failed one is dropped and removed
+            if result.is_ok() {
+                pool.release(sandbox);
+            }
+            result
+        });
+        (path.clone(), result)
+    };
+
+    if parallel {
         use rayon::prelude::*;
-        source_paths
-            .par_iter()
-            .filter_map(|path| {
-                let content = std::fs::read_to_string(path).ok()?;
-                run_mutation_test(path, &content, test_command, count_per_file).ok()
-            })
-            .collect()
+        targets.par_iter().map(run_one).collect()
     } else {
-        source_paths
-            .iter()
-            .filter_map(|path| {
-                let content = std::fs::read_to_string(path).ok()?;
-                run_mutation_test(path, &content, test_command, count_per_file).ok()
-            })
-            .collect()
-    };
+        targets.iter().map(run_one).collect()
+    }
+}
+
+/// Run mutation testing on multiple source files. Each worker owns a sandbox, so a test
+/// run only ever sees one mutant. A file that can't be tested lands in `failures` without
+/// affecting the others.
+pub fn run_batch_mutation_test(
+    targets: &[MutationTarget],
+    options: &MutationOptions,
+    parallel: bool,
+) -> BatchMutationResult {
+    let stores = StoreSet::default();
+    let mut results = Vec::new();
+    let mut failures = Vec::new();
+    for (path, result) in run_each(targets, parallel, |sandbox, target, content| {
+        run_mutation_test_in(sandbox, target, content, options, &stores)
+    }) {
+        match result {
+            Ok(result) => results.push(result),
+            Err(e) => failures.push((path, e)),
+        }
+    }
+    stores.save();
 
     let total_mutants: usize = results.iter().map(|r| r.total).sum();
     let total_killed: usize = results.iter().map(|r| r.killed).sum();
-    let total_survived = total_mutants - total_killed;
+    let total_survived: usize = results.iter().map(|r| r.survived).sum();
+    let total_timed_out: usize = results.iter().map(|r| r.timed_out).sum();
+    let total_no_coverage: usize = results.iter().map(|r| r.no_coverage).sum();
+    let total_errors: usize = results.iter().map(|r| r.errors).sum();
 
-    let overall_score = if total_mutants == 0 {
+    let valid = total_mutants - total_errors;
+    let overall_score = if valid == 0 {
         100.0
     } else {
-        (total_killed as f32 / total_mutants as f32) * 100.0
+        ((total_killed + total_timed_out) as f32 / valid as f32) * 100.0
     };
 
-    Ok(BatchMutationResult {
+    BatchMutationResult {
         source_results: results,
         overall_score,
         total_mutants,
         total_killed,
         total_survived,
-    })
+        total_timed_out,
+        total_no_coverage,
+        total_errors,
+        failures,
+    }
 }
 
 #[cfg(test)]
@@ -143,6 +638,8 @@ mod tests {
             original: ">=".to_string(),
             replacement: ">".to_string(),
             description: desc.to_string(),
+            operator: MutationOperator::Boundary,
+            scope: 0..0,
         }
     }
 
@@ -153,6 +650,9 @@ mod tests {
             total: 10,
             killed: 8,
             survived: 2,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![],
         };
         let score = result.score();
@@ -166,6 +666,9 @@ mod tests {
             total: 5,
             killed: 5,
             survived: 0,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![],
         };
         assert!((result.score() - 100.0).abs() < 0.01);
@@ -178,6 +681,9 @@ mod tests {
             total: 5,
             killed: 0,
             survived: 5,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![],
         };
         assert!((result.score() - 0.0).abs() < 0.01);
@@ -190,6 +696,9 @@ mod tests {
             total: 0,
             killed: 0,
             survived: 0,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![],
         };
         // total == 0 → 100.0 (no mutants = perfect score)
@@ -203,6 +712,9 @@ mod tests {
             total: 4,
             killed: 3,
             survived: 1,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![],
         };
         let r2 = MutationResult {
@@ -210,6 +722,9 @@ mod tests {
             total: 6,
             killed: 5,
             survived: 1,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![],
         };
 
@@ -224,6 +739,10 @@ mod tests {
             total_mutants,
             total_killed,
             total_survived,
+            total_timed_out: 0,
+            total_no_coverage: 0,
+            total_errors: 0,
+            failures: vec![],
         };
 
         assert_eq!(batch.total_mutants, 10);
@@ -233,16 +752,220 @@ mod tests {
         assert_eq!(batch.source_results.len(), 2);
     }
 
+    fn make_run(status: MutantStatus) -> MutationRun {
+        MutationRun {
+            mutation: make_mutation(">= to >"),
+            status,
+            stdout: String::new(),
+            stderr: String::new(),
+            duration: Duration::ZERO,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn test_mutation_result_from_runs_scores_outcomes() {
+        let runs = vec![
+            make_run(MutantStatus::Killed),
+            make_run(MutantStatus::Timeout),
+            make_run(MutantStatus::Survived),
+            make_run(MutantStatus::NoCoverage),
+            make_run(MutantStatus::CompileError),
+            make_run(MutantStatus::RuntimeError),
+        ];
+        let result = MutationResult::from_runs(Path::new("src/foo.ts"), runs);
+        assert_eq!(result.total, 6);
+        assert_eq!(
+            (result.killed, result.timed_out, result.survived),
+            (1, 1, 1)
+        );
+        assert_eq!((result.no_coverage, result.errors), (1, 2));
+        // Errors are excluded: 2 detected out of 4 valid
+        assert_eq!(result.valid(), 4);
+        assert!((result.score() - 50.0).abs() < 0.01);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_incremental_run_reuses_unchanged_mutants() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("package.json"), "{}").unwrap();
+        let source = root.path().join("age.ts");
+        let content = "export function isAdult(age) { return age >= 18; }";
+        std::fs::write(&source, content).unwrap();
+        let test = root.path().join("age.test.ts");
+        std::fs::write(&test, "expect(isAdult(18)).toBe(true);").unwrap();
+
+        let mut target = MutationTarget::new(&source);
+        target.test_files.push(test.clone());
+        let options = MutationOptions {
+            test_command: Some("true".to_string()),
+            count: usize::MAX,
+            ..Default::default()
+        };
+
+        let first = run_mutation_test(&target, content, &options).unwrap();
+        assert!(first.total > 0);
+        assert!(first.details.iter().all(|r| !r.cached));
+        let second = run_mutation_test(&target, content, &options).unwrap();
+        assert_eq!(second.total, first.total);
+        assert!(second.details.iter().all(|r| r.cached));
+
+        std::fs::write(&test, "expect(isAdult(17)).toBe(false);").unwrap();
+        let third = run_mutation_test(&target, content, &options).unwrap();
+        assert!(third.details.iter().all(|r| !r.cached));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_batch_reports_failing_files_and_keeps_the_rest() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("package.json"), "{}").unwrap();
+        let good = root.path().join("age.ts");
+        std::fs::write(&good, "export function isAdult(age) { return age >= 18; }").unwrap();
+        let broken = root.path().join("broken.ts");
+        std::fs::write(&broken, "export const ok = (n) => n >= 1; // BROKEN").unwrap();
+        let missing = root.path().join("missing.ts");
+
+        // Stand-in test runner whose baseline fails for `broken.ts` only
+        let options = MutationOptions {
+            test_command: Some("grep -qv BROKEN {sourceFile}".to_string()),
+            count: usize::MAX,
+            ..Default::default()
+        };
+        let targets: Vec<MutationTarget> = [&good, &broken, &missing]
+            .into_iter()
+            .map(MutationTarget::new)
+            .collect();
+
+        let batch = run_batch_mutation_test(&targets, &options, false);
+        assert_eq!(batch.source_results.len(), 1);
+        assert_eq!(batch.source_results[0].source_path, good);
+        let failed: Vec<&PathBuf> = batch.failures.iter().map(|(path, _)| path).collect();
+        assert_eq!(failed, vec![&broken, &missing]);
+        assert!(batch.failures[0]
+            .1
+            .to_string()
+            .contains("fails on the unmutated source"));
+        assert_eq!(batch.failures[1].1.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_inverted_assertions_that_still_pass_survive() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("package.json"), "{}").unwrap();
+        let test = root.path().join("cart.test.ts");
+        let content =
+            "it('totals', () => {\n  expect(total).toBe(3);\n  expect(count).toBe(1);\n});\n";
+        std::fs::write(&test, content).unwrap();
+
+        // Stand-in test runner that only "checks" the first assertion
+        let target = MutationTarget::new(&test);
+        let options = MutationOptions {
+            test_command: Some("grep -qF expect(total).toBe(3) {sourceFile}".to_string()),
+            ..Default::default()
+        };
+
+        let result = run_assertion_mutation_test(&target, content, &options).unwrap();
+        let outcomes: Vec<(usize, MutantStatus)> = result
+            .details
+            .iter()
+            .map(|r| (r.mutation.line, r.status))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![(2, MutantStatus::Killed), (3, MutantStatus::Survived)]
+        );
+        assert_eq!(std::fs::read_to_string(&test).unwrap(), content);
+    }
+
+    #[test]
+    fn test_mutant_timeout_scales_baseline() {
+        let timeout = MutantTimeout::default();
+        assert_eq!(
+            timeout.for_baseline(Duration::from_secs(2)),
+            Duration::from_secs(8)
+        );
+    }
+
+    #[test]
+    fn test_sampler_partitions_by_line_coverage() {
+        let on_line = |line| Mutation {
+            line,
+            ..make_mutation(">= to >")
+        };
+        let mutations = vec![on_line(1), on_line(2), on_line(3)];
+        let mut coverage = crate::coverage::FileCoverage::default();
+        coverage.lines.insert(1, 4);
+        coverage.lines.insert(2, 0);
+
+        let (covered, uncovered) =
+            sampler::partition_by_coverage(mutations.clone(), Some(&coverage));
+        // Line 3 has no coverage data, so it is not treated as unexecuted
+        assert_eq!(
+            covered.iter().map(|m| m.line).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(uncovered.len(), 1);
+        assert_eq!(uncovered[0].line, 2);
+
+        let (covered, uncovered) = sampler::partition_by_coverage(mutations, None);
+        assert_eq!((covered.len(), uncovered.len()), (3, 0));
+    }
+
+    #[test]
+    fn test_sampler_samples_uncovered_at_the_covered_rate() {
+        let mutations: Vec<Mutation> = (1..=10)
+            .map(|line| Mutation {
+                line,
+                ..make_mutation("+ to -")
+            })
+            .collect();
+        let (covered, uncovered) = mutations.split_at(6);
+
+        // 3 of 6 covered mutants run, so half the uncovered ones are reported
+        let picked = sampler::select_uncovered(uncovered, covered.len(), 3, 0);
+        assert_eq!(picked.len(), 2);
+        // Nothing is sampled away when every covered mutant runs
+        let picked = sampler::select_uncovered(uncovered, covered.len(), 10, 0);
+        assert_eq!(picked.len(), uncovered.len());
+    }
+
+    #[test]
+    fn test_uncovered_mutants_are_not_run() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("package.json"), "{}").unwrap();
+        let source = root.path().join("age.ts");
+        let content = "export function isAdult(age) {\n  return age >= 18;\n}";
+        std::fs::write(&source, content).unwrap();
+
+        let mut target = MutationTarget::new(&source);
+        let mut coverage = crate::coverage::FileCoverage::default();
+        coverage.lines.insert(2, 0);
+        target.coverage = Some(coverage);
+        // A failing command would abort the baseline if anything ran
+        let options = MutationOptions {
+            test_command: Some("rigor-no-such-binary".to_string()),
+            ..Default::default()
+        };
+
+        let result = run_mutation_test(&target, content, &options).unwrap();
+        assert!(result.total > 0);
+        assert_eq!(result.no_coverage, result.total);
+        assert!((result.score() - 0.0).abs() < 0.01);
+    }
+
     #[test]
     fn test_sampler_select_all_when_fewer_than_count() {
         let mutations = vec![make_mutation(">= to >"), make_mutation("true to false")];
-        let selected = sampler::select_mutations(&mutations, 10);
+        let selected = sampler::select_mutations(&mutations, 10, 0);
         assert_eq!(selected.len(), 2);
     }
 
     #[test]
     fn test_sampler_select_empty() {
-        let selected = sampler::select_mutations(&[], 5);
+        let selected = sampler::select_mutations(&[], 5, 0);
         assert!(selected.is_empty());
     }
 
@@ -255,10 +978,40 @@ mod tests {
             make_mutation("<= to <"),
             make_mutation("=== to !="),
         ];
-        let selected = sampler::select_mutations(&mutations, 3);
+        let selected = sampler::select_mutations(&mutations, 3, 0);
         assert_eq!(selected.len(), 3);
     }
 
+    #[test]
+    fn test_sampler_stratifies_by_function_and_is_seeded() {
+        // Three functions: one with eight mutants, two with one each
+        let in_scope = |scope: std::ops::Range<usize>, line| Mutation {
+            line,
+            scope,
+            ..make_mutation("+ to -")
+        };
+        let mut mutations: Vec<Mutation> = (1..=8).map(|line| in_scope(0..100, line)).collect();
+        mutations.push(in_scope(100..200, 20));
+        mutations.push(in_scope(200..300, 30));
+
+        let lines = |seed| {
+            sampler::select_mutations(&mutations, 4, seed)
+                .iter()
+                .map(|m| m.line)
+                .collect::<Vec<_>>()
+        };
+        for seed in [0, 1, 42] {
+            let picked = lines(seed);
+            assert!(picked.contains(&20) && picked.contains(&30), "{:?}", picked);
+            assert_eq!(picked.iter().filter(|&&l| l <= 8).count(), 2);
+            assert_eq!(picked, lines(seed), "same seed, same sample");
+        }
+        assert!(
+            (0..20).any(|seed| lines(seed) != lines(0)),
+            "different seeds sample different mutants"
+        );
+    }
+
     #[test]
     fn test_sampler_prefers_boundary_operators() {
         let mutations = vec![
@@ -268,7 +1021,7 @@ mod tests {
             make_mutation("<= to <"),       // priority 3 (boundary)
             make_mutation("* to /"),        // priority 1
         ];
-        let selected = sampler::select_mutations(&mutations, 2);
+        let selected = sampler::select_mutations(&mutations, 2, 0);
         // Should prefer the two boundary operators
         assert!(
             selected.iter().any(|m| m.description == ">= to >"),
@@ -1,6 +1,14 @@
-//! Mutation operators - generate mutants from source code.
+//! Mutation operators - generate mutants from the TypeScript syntax tree.
+//!
+//! Operators match tree-sitter node kinds (`binary_expression`, `return_statement`,
+//! `optional_chain`, `non_null_expression`, ...) so text inside strings, comments, generic
+//! type arguments or arrow functions is never mistaken for an operator.
 
-use regex::Regex;
+use crate::config::CustomOperator;
+use crate::parser::TypeScriptParser;
+use std::collections::HashSet;
+use std::path::Path;
+use tree_sitter::Node;
 
 /// A single mutation: replace one span of text with another
 #[derive(Debug, Clone)]
@@ -12,214 +20,719 @@ pub struct Mutation {
     pub original: String,
     pub replacement: String,
     pub description: String,
+    pub operator: MutationOperator,
+    /// Byte range of the outermost enclosing function (the whole file at top level)
+    pub scope: std::ops::Range<usize>,
 }
 
-/// Operator that produces replacement for a matched pattern
-#[derive(Debug, Clone)]
-pub struct MutationOperator {
-    pub pattern: Regex,
-    pub replacements: Vec<&'static str>,
-    pub description: &'static str,
+/// Kind of change an operator makes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MutationOperator {
+    /// `>=` → `>`, `<` → `<=`, ...
+    Boundary,
+    /// `===` → `!==`, `!=` → `==`, ...
+    Equality,
+    /// `+` → `-`, `*` → `/`, ...
+    Arithmetic,
+    /// `a + b` → `a` / `b`
+    ArithmeticOperand,
+    /// `&&` ↔ `||`, `??` → `||`
+    Logical,
+    /// `true` ↔ `false`
+    Boolean,
+    /// `if (c)` → `if (!(c))`, also loops and ternaries
+    ConditionalNegation,
+    /// `++` ↔ `--`, `+=` ↔ `-=`
+    Increment,
+    /// `"text"` → `""`, `""` → `" "`
+    String,
+    /// `[a, b]` → `[]`, `[]` → `[0]`, `xs[0]` → `xs[1]`
+    Array,
+    /// `return x` → `return null` / `return undefined`
+    Return,
+    /// `a?.b` → `a.b`
+    OptionalChaining,
+    /// `x!` → `x`
+    NonNullAssertion,
+    /// `doWork();` → `;`
+    CallStatement,
+    /// `expect(x).toBe(y)` → `expect(x).not.toBe(y)` (test files only, see `--mutate-tests`)
+    AssertionInversion,
+    /// Project-specific replacement from `mutation.customOperators`
+    Custom,
+    /// Mutator from an imported report that rigor doesn't generate itself
+    Other,
+}
+
+/// Operator groups that can be switched off with `mutation.operators` in the config
+pub const OPERATOR_GROUPS: &[&str] = &[
+    "boundary",
+    "equality",
+    "arithmetic",
+    "logical",
+    "boolean",
+    "conditional",
+    "increment",
+    "string",
+    "array",
+    "return",
+    "optional-chaining",
+    "non-null",
+    "call-statement",
+    "custom",
+];
+
+impl MutationOperator {
+    /// Config group name (see [`OPERATOR_GROUPS`])
+    pub fn group(self) -> &'static str {
+        use MutationOperator::*;
+        match self {
+            Boundary => "boundary",
+            Equality => "equality",
+            Arithmetic | ArithmeticOperand => "arithmetic",
+            Logical => "logical",
+            Boolean => "boolean",
+            ConditionalNegation => "conditional",
+            Increment => "increment",
+            String => "string",
+            Array => "array",
+            Return => "return",
+            OptionalChaining => "optional-chaining",
+            NonNullAssertion => "non-null",
+            CallStatement => "call-statement",
+            Custom => "custom",
+            AssertionInversion => "assertion",
+            Other => "other",
+        }
+    }
+}
+
+/// Which operators run on a source file, from the `mutation` config block
+#[derive(Debug, Clone, Default)]
+pub struct OperatorSettings {
+    /// Groups switched off (see [`OPERATOR_GROUPS`])
+    pub disabled_groups: HashSet<String>,
+    /// Project-specific operators
+    pub custom: Vec<CustomOperator>,
+    /// Functions whose bodies are never mutated
+    pub exclude_functions: Vec<String>,
+}
+
+impl OperatorSettings {
+    /// Settings from the `mutation` config block
+    pub fn from_config(config: &crate::config::MutationConfig) -> Self {
+        Self {
+            disabled_groups: config
+                .operators
+                .iter()
+                .filter(|(_, enabled)| !**enabled)
+                .map(|(group, _)| group.clone())
+                .collect(),
+            custom: config.custom_operators.clone(),
+            exclude_functions: config.exclude_functions.clone(),
+        }
+    }
+}
+
+impl Mutation {
+    /// Stryker mutator name (mutation-testing-report-schema `mutatorName`); rigor-only
+    /// operators get names in the same style
+    pub fn mutator_name(&self) -> &'static str {
+        use MutationOperator::*;
+        match self.operator {
+            Boundary | Equality => "EqualityOperator",
+            Arithmetic => "ArithmeticOperator",
+            ArithmeticOperand => "ArithmeticOperand",
+            Logical => "LogicalOperator",
+            Boolean => "BooleanLiteral",
+            ConditionalNegation => "ConditionalExpression",
+            Increment if self.original.ends_with('=') => "AssignmentOperator",
+            Increment => "UpdateOperator",
+            String => "StringLiteral",
+            Array if self.original.starts_with('[') => "ArrayDeclaration",
+            Array => "ArrayIndex",
+            Return => "ReturnValue",
+            OptionalChaining => "OptionalChaining",
+            NonNullAssertion => "NonNullAssertion",
+            CallStatement => "StatementRemoval",
+            AssertionInversion => "AssertionInversion",
+            Custom => "Custom",
+            Other => "Other",
+        }
+    }
+
+    /// One-line "`original`→`replacement`" for messages
+    pub fn change_summary(&self) -> String {
+        format!(
+            "`{}`→`{}`",
+            summarize(&self.original),
+            summarize(&self.replacement)
+        )
+    }
+}
+
+/// First line of `text`, shortened for one-line descriptions
+pub(super) fn summarize(text: &str) -> String {
+    const MAX_CHARS: usize = 40;
+    let first = text.lines().next().unwrap_or("").trim();
+    if first.chars().count() > MAX_CHARS || first.len() < text.trim().len() {
+        let short: String = first.chars().take(MAX_CHARS).collect();
+        format!("{}…", short)
+    } else {
+        first.to_string()
+    }
 }
 
-fn make_ops() -> Vec<MutationOperator> {
-    vec![
-        // --- Boundary / comparison (existing) ---
-        MutationOperator {
-            pattern: Regex::new(r">=").unwrap(),
-            replacements: vec![">"],
-            description: ">= to >",
-        },
-        MutationOperator {
-            pattern: Regex::new(r"<=").unwrap(),
-            replacements: vec!["<"],
-            description: "<= to <",
-        },
-        MutationOperator {
-            pattern: Regex::new(r" > ").unwrap(),
-            replacements: vec![" >="],
-            description: "> to >=",
-        },
-        MutationOperator {
-            pattern: Regex::new(r" < ").unwrap(),
-            replacements: vec![" <="],
-            description: "< to <=",
-        },
-        // --- Boolean (existing) ---
-        MutationOperator {
-            pattern: Regex::new(r"\btrue\b").unwrap(),
-            replacements: vec!["false"],
-            description: "true to false",
-        },
-        MutationOperator {
-            pattern: Regex::new(r"\bfalse\b").unwrap(),
-            replacements: vec!["true"],
-            description: "false to true",
-        },
-        // --- Arithmetic (existing) ---
-        MutationOperator {
-            pattern: Regex::new(r" \+ ").unwrap(),
-            replacements: vec![" - "],
-            description: "+ to -",
-        },
-        MutationOperator {
-            pattern: Regex::new(r" - ").unwrap(),
-            replacements: vec![" + "],
-            description: "- to +",
-        },
-        MutationOperator {
-            pattern: Regex::new(r" \* ").unwrap(),
-            replacements: vec![" / "],
-            description: "* to /",
-        },
-        // --- Equality (existing) ---
-        MutationOperator {
-            pattern: Regex::new(r" === ").unwrap(),
-            replacements: vec![" != "],
-            description: "=== to !=",
-        },
-        MutationOperator {
-            pattern: Regex::new(r" !== ").unwrap(),
-            replacements: vec![" == "],
-            description: "!= to ==",
-        },
-        // --- 2. String mutations ---
-        // Non-empty double-quoted string -> empty string
-        MutationOperator {
-            pattern: Regex::new(r#""[^"]+""#).unwrap(),
-            replacements: vec![r#""""#],
-            description: "string to empty string",
-        },
-        // Non-empty single-quoted string -> empty string
-        MutationOperator {
-            pattern: Regex::new(r"'[^']+'").unwrap(),
-            replacements: vec!["''"],
-            description: "string to empty string (single quote)",
-        },
-        // Empty double-quoted string -> one space (so mutant is still valid)
-        MutationOperator {
-            pattern: Regex::new(r#""""#).unwrap(),
-            replacements: vec![r#"" ""#],
-            description: "empty string to space",
-        },
-        // Empty single-quoted string -> one space
-        MutationOperator {
-            pattern: Regex::new(r"''").unwrap(),
-            replacements: vec!["' '"],
-            description: "empty string to space (single quote)",
-        },
-        // --- 4. Array mutations ---
-        // Array literal with comma (2+ elements) -> empty array
-        MutationOperator {
-            pattern: Regex::new(r"\[[^\]]*,[^\]]*\]").unwrap(),
-            replacements: vec!["[]"],
-            description: "array literal to empty array",
-        },
-        // Empty array -> [0]
-        MutationOperator {
-            pattern: Regex::new(r"\[\s*\]").unwrap(),
-            replacements: vec!["[0]"],
-            description: "empty array to [0]",
-        },
-        // Index [0] -> [1] (off-by-one)
-        MutationOperator {
-            pattern: Regex::new(r"\[0\]").unwrap(),
-            replacements: vec!["[1]"],
-            description: "index 0 to 1",
-        },
-        // --- 6. Return value mutations ---
-        MutationOperator {
-            pattern: Regex::new(r"return\s+[^;]+;").unwrap(),
-            replacements: vec!["return null;", "return undefined;"],
-            description: "return to null/undefined",
-        },
-        // --- 7. Increment / decrement ---
-        MutationOperator {
-            pattern: Regex::new(r"\+\+").unwrap(),
-            replacements: vec!["--"],
-            description: "++ to --",
-        },
-        MutationOperator {
-            pattern: Regex::new(r"--").unwrap(),
-            replacements: vec!["++"],
-            description: "-- to ++",
-        },
-        MutationOperator {
-            pattern: Regex::new(r"\+= ?1\b").unwrap(),
-            replacements: vec!["-= 1"],
-            description: "+= 1 to -= 1",
-        },
-        MutationOperator {
-            pattern: Regex::new(r"-= ?1\b").unwrap(),
-            replacements: vec!["+= 1"],
-            description: "-= 1 to += 1",
-        },
-        // --- TypeScript-specific operators ---
-        // Optional chaining: ?. -> . (may cause runtime errors if value is null)
-        MutationOperator {
-            pattern: Regex::new(r"\?\.").unwrap(),
-            replacements: vec!["."],
-            description: "?. to . (optional chaining removed)",
-        },
-        // Nullish coalescing: ?? -> || (different falsy behavior)
-        MutationOperator {
-            pattern: Regex::new(r" \?\? ").unwrap(),
-            replacements: vec![" || "],
-            description: "?? to || (nullish to logical or)",
-        },
-        // Non-null assertion at end of expressions: )! -> )
-        MutationOperator {
-            pattern: Regex::new(r"\)!\.").unwrap(),
-            replacements: vec![")."],
-            description: ")! to ) (non-null assertion removed)",
-        },
-        // Non-null assertion: identifier! followed by . or ) or ; or ,
-        MutationOperator {
-            pattern: Regex::new(r"!\.").unwrap(),
-            replacements: vec!["."],
-            description: "!. to . (non-null assertion removed)",
-        },
-    ]
+/// Nodes whose subtree only describes types (never executed)
+const TYPE_ONLY_KINDS: &[&str] = &[
+    "type_annotation",
+    "type_arguments",
+    "type_parameters",
+    "type_alias_declaration",
+    "interface_declaration",
+    "enum_declaration",
+    "ambient_declaration",
+    "abstract_method_signature",
+    "function_signature",
+    "type_predicate_annotation",
+    "asserts_annotation",
+    "literal_type",
+    "decorator",
+];
+
+/// Generate all possible mutations with every built-in operator enabled.
+#[cfg(test)]
+pub fn generate_mutations_for_path(path: &Path, source: &str) -> Vec<Mutation> {
+    generate_mutations_with(path, source, &OperatorSettings::default())
 }
 
-/// Generate all possible mutations for the given source content.
-pub fn generate_mutations(source: &str) -> Vec<Mutation> {
-    let ops = make_ops();
+/// Generate mutations, choosing the TS or TSX grammar from the file extension. Disabled
+/// operator groups and excluded functions are skipped; custom operators are added.
+pub fn generate_mutations_with(
+    path: &Path,
+    source: &str,
+    settings: &OperatorSettings,
+) -> Vec<Mutation> {
+    let Ok(mut parser) = TypeScriptParser::for_file(path) else {
+        return Vec::new();
+    };
+    let Ok(tree) = parser.parse(source) else {
+        return Vec::new();
+    };
+
     let mut out = Vec::new();
+    visit(tree.root_node(), source, &mut out);
+    if !settings.custom.is_empty() {
+        custom_mutations(tree.root_node(), source, &settings.custom, &mut out);
+        out.sort_by_key(|m| m.start);
+    }
 
-    for op in ops {
-        for cap in op.pattern.find_iter(source) {
-            let start = cap.start();
-            let end = cap.end();
-            let original = source[start..end].to_string();
-            let (line, column) = line_column(source, start);
+    let mut excluded = Vec::new();
+    if !settings.exclude_functions.is_empty() {
+        excluded_functions(
+            tree.root_node(),
+            source,
+            &settings.exclude_functions,
+            &mut excluded,
+        );
+    }
+    out.retain(|m| {
+        !settings.disabled_groups.contains(m.operator.group())
+            && !excluded.iter().any(|range| range.contains(&m.start))
+    });
+    out
+}
 
-            for &replacement in &op.replacements {
-                if replacement == original {
-                    continue;
+/// Replace every node whose text is exactly a custom operator's `from`
+fn custom_mutations(node: Node, source: &str, custom: &[CustomOperator], out: &mut Vec<Mutation>) {
+    if TYPE_ONLY_KINDS.contains(&node.kind())
+        || matches!(
+            node.kind(),
+            "import_statement" | "comment" | "string_fragment"
+        )
+    {
+        return;
+    }
+    let node_text = text(node, source);
+    for op in custom.iter().filter(|op| op.from == node_text) {
+        // An expression statement without `;` spans the same text as its expression
+        let duplicate = out.iter().any(|m| {
+            m.operator == MutationOperator::Custom
+                && m.start == node.start_byte()
+                && m.end == node.end_byte()
+                && m.replacement == op.to
+        });
+        if !duplicate {
+            push(
+                out,
+                source,
+                node,
+                &op.to,
+                &op.name,
+                MutationOperator::Custom,
+            );
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        custom_mutations(child, source, custom, out);
+    }
+}
+
+/// Byte ranges of functions named in `names`
+fn excluded_functions(
+    node: Node,
+    source: &str,
+    names: &[String],
+    out: &mut Vec<std::ops::Range<usize>>,
+) {
+    if FUNCTION_KINDS.contains(&node.kind())
+        && function_name(node, source).is_some_and(|name| names.iter().any(|n| n == name))
+    {
+        out.push(node.byte_range());
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        excluded_functions(child, source, names, out);
+    }
+}
+
+/// Declared name of a function, or the name it is bound to (`const f = () => ...`,
+/// `{ f: function () {} }`, `exports.f = ...`)
+fn function_name<'a>(node: Node, source: &'a str) -> Option<&'a str> {
+    if let Some(name) = node.child_by_field_name("name") {
+        return Some(text(name, source));
+    }
+    let parent = node.parent()?;
+    let name = match parent.kind() {
+        "variable_declarator" | "public_field_definition" => parent.child_by_field_name("name"),
+        "pair" => parent.child_by_field_name("key"),
+        "assignment_expression" => parent
+            .child_by_field_name("left")
+            .map(|left| left.child_by_field_name("property").unwrap_or(left)),
+        _ => None,
+    }?;
+    Some(text(name, source))
+}
+
+fn visit(node: Node, source: &str, out: &mut Vec<Mutation>) {
+    if TYPE_ONLY_KINDS.contains(&node.kind()) {
+        return;
+    }
+    // Import specifiers and `export ... from` are module wiring, not behavior
+    if node.kind() == "import_statement" {
+        return;
+    }
+
+    match node.kind() {
+        "binary_expression" => binary_mutations(node, source, out),
+        "true" => push(
+            out,
+            source,
+            node,
+            "false",
+            "true to false",
+            MutationOperator::Boolean,
+        ),
+        "false" => push(
+            out,
+            source,
+            node,
+            "true",
+            "false to true",
+            MutationOperator::Boolean,
+        ),
+        "if_statement" | "while_statement" | "do_statement" => {
+            if let Some(cond) = node.child_by_field_name("condition") {
+                negate_condition(cond, source, out);
+            }
+        }
+        "ternary_expression" => {
+            if let Some(cond) = node.child_by_field_name("condition") {
+                negate_condition(cond, source, out);
+            }
+        }
+        "update_expression" => {
+            if let Some(op) = child_of_kind(node, &["++", "--"]) {
+                let (replacement, description) = if op.kind() == "++" {
+                    ("--", "++ to --")
+                } else {
+                    ("++", "-- to ++")
+                };
+                push(
+                    out,
+                    source,
+                    op,
+                    replacement,
+                    description,
+                    MutationOperator::Increment,
+                );
+            }
+        }
+        "augmented_assignment_expression" => {
+            if let Some(op) = node.child_by_field_name("operator") {
+                let swap = match op.kind() {
+                    "+=" => Some(("-=", "+= to -=")),
+                    "-=" => Some(("+=", "-= to +=")),
+                    _ => None,
+                };
+                if let Some((replacement, description)) = swap {
+                    push(
+                        out,
+                        source,
+                        op,
+                        replacement,
+                        description,
+                        MutationOperator::Increment,
+                    );
                 }
-                out.push(Mutation {
-                    start,
-                    end,
-                    line,
-                    column,
-                    original: original.clone(),
-                    replacement: replacement.to_string(),
-                    description: op.description.to_string(),
-                });
             }
         }
+        "string" => string_mutations(node, source, out),
+        "array" => {
+            if node.named_child_count() >= 2 {
+                push(
+                    out,
+                    source,
+                    node,
+                    "[]",
+                    "array literal to empty array",
+                    MutationOperator::Array,
+                );
+            } else if node.named_child_count() == 0 {
+                push(
+                    out,
+                    source,
+                    node,
+                    "[0]",
+                    "empty array to [0]",
+                    MutationOperator::Array,
+                );
+            }
+        }
+        "subscript_expression" => {
+            if let Some(index) = node.child_by_field_name("index") {
+                if index.kind() == "number" && text(index, source) == "0" {
+                    push(
+                        out,
+                        source,
+                        index,
+                        "1",
+                        "index 0 to 1",
+                        MutationOperator::Array,
+                    );
+                }
+            }
+        }
+        "return_statement" => return_mutations(node, source, out),
+        "optional_chain" => {
+            // `a?.b` keeps the dot; `a?.[i]` and `f?.()` drop the token entirely
+            let replacement = match node.parent().map(|p| p.kind()) {
+                Some("member_expression") => ".",
+                _ => "",
+            };
+            push(
+                out,
+                source,
+                node,
+                replacement,
+                "?. to . (optional chaining removed)",
+                MutationOperator::OptionalChaining,
+            );
+        }
+        // `f?.()` exposes the token directly on the call rather than as an `optional_chain`
+        "?." if node.parent().is_some_and(|p| p.kind() == "call_expression") => push(
+            out,
+            source,
+            node,
+            "",
+            "?. to . (optional chaining removed)",
+            MutationOperator::OptionalChaining,
+        ),
+        "non_null_expression" => {
+            if let Some(inner) = node.named_child(0) {
+                let replacement = text(inner, source).to_string();
+                push(
+                    out,
+                    source,
+                    node,
+                    &replacement,
+                    "x! to x (non-null assertion removed)",
+                    MutationOperator::NonNullAssertion,
+                );
+            }
+        }
+        "expression_statement" => call_statement_mutation(node, source, out),
+        _ => {}
     }
 
-    out
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, source, out);
+    }
 }
 
-fn line_column(source: &str, byte_offset: usize) -> (usize, usize) {
-    let before = &source[..byte_offset];
-    let line = before.lines().count().max(1);
-    let last_newline = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
-    let column = before[last_newline..].len() + 1;
-    (line, column)
+fn binary_mutations(node: Node, source: &str, out: &mut Vec<Mutation>) {
+    let Some(op) = node.child_by_field_name("operator") else {
+        return;
+    };
+    use MutationOperator::*;
+    let swaps: &[(&str, &str, MutationOperator)] = match op.kind() {
+        ">=" => &[(">", ">= to >", Boundary)],
+        "<=" => &[("<", "<= to <", Boundary)],
+        ">" => &[(">=", "> to >=", Boundary)],
+        "<" => &[("<=", "< to <=", Boundary)],
+        "===" => &[("!==", "=== to !==", Equality)],
+        "!==" => &[("===", "!== to ===", Equality)],
+        "==" => &[("!=", "== to !=", Equality)],
+        "!=" => &[("==", "!= to ==", Equality)],
+        "+" => &[("-", "+ to -", Arithmetic)],
+        "-" => &[("+", "- to +", Arithmetic)],
+        "*" => &[("/", "* to /", Arithmetic)],
+        "/" => &[("*", "/ to *", Arithmetic)],
+        "%" => &[("*", "% to *", Arithmetic)],
+        "&&" => &[("||", "&& to ||", Logical)],
+        "||" => &[("&&", "|| to &&", Logical)],
+        "??" => &[("||", "?? to || (nullish to logical or)", Logical)],
+        _ => &[],
+    };
+    for &(replacement, description, operator) in swaps {
+        push(out, source, op, replacement, description, operator);
+    }
+
+    // Replace `a + b` by one of its operands: catches tests that never vary the other input
+    if matches!(op.kind(), "+" | "-" | "*" | "/" | "%") {
+        let operands = [
+            (node.child_by_field_name("left"), "left"),
+            (node.child_by_field_name("right"), "right"),
+        ];
+        for (operand, side) in operands {
+            let Some(operand) = operand else { continue };
+            let replacement = text(operand, source).to_string();
+            push(
+                out,
+                source,
+                node,
+                &replacement,
+                &format!("{} expression to {} operand", op.kind(), side),
+                MutationOperator::ArithmeticOperand,
+            );
+        }
+    }
+}
+
+fn negate_condition(cond: Node, source: &str, out: &mut Vec<Mutation>) {
+    let cond_text = text(cond, source);
+    let replacement = if cond.kind() == "parenthesized_expression" {
+        format!("(!{})", cond_text)
+    } else {
+        format!("!({})", cond_text)
+    };
+    push(
+        out,
+        source,
+        cond,
+        &replacement,
+        "condition negated",
+        MutationOperator::ConditionalNegation,
+    );
+}
+
+fn string_mutations(node: Node, source: &str, out: &mut Vec<Mutation>) {
+    if let Some(parent) = node.parent() {
+        match parent.kind() {
+            // `export { x } from "./x"`, `"use strict"`, `{ "key": value }`
+            "export_statement" | "expression_statement" => return,
+            "pair" if parent.child_by_field_name("key") == Some(node) => return,
+            "arguments" if is_module_call(parent, source) => return,
+            _ => {}
+        }
+    }
+
+    let raw = text(node, source);
+    let quote = &raw[..1];
+    if raw.len() > 2 {
+        let description = if quote == "'" {
+            "string to empty string (single quote)"
+        } else {
+            "string to empty string"
+        };
+        push(
+            out,
+            source,
+            node,
+            &format!("{quote}{quote}"),
+            description,
+            MutationOperator::String,
+        );
+    } else {
+        let description = if quote == "'" {
+            "empty string to space (single quote)"
+        } else {
+            "empty string to space"
+        };
+        push(
+            out,
+            source,
+            node,
+            &format!("{quote} {quote}"),
+            description,
+            MutationOperator::String,
+        );
+    }
+}
+
+/// True for the argument list of `require("x")` / `import("x")`
+fn is_module_call(arguments: Node, source: &str) -> bool {
+    arguments
+        .parent()
+        .filter(|call| call.kind() == "call_expression")
+        .and_then(|call| call.child_by_field_name("function"))
+        .is_some_and(|f| matches!(text(f, source), "require" | "import"))
+}
+
+fn return_mutations(node: Node, source: &str, out: &mut Vec<Mutation>) {
+    let Some(value) = node.named_child(0) else {
+        return;
+    };
+    if matches!(value.kind(), "null" | "undefined") {
+        return;
+    }
+    let semicolon = if text(node, source).ends_with(';') {
+        ";"
+    } else {
+        ""
+    };
+    for literal in ["null", "undefined"] {
+        push(
+            out,
+            source,
+            node,
+            &format!("return {}{}", literal, semicolon),
+            "return to null/undefined",
+            MutationOperator::Return,
+        );
+    }
+}
+
+/// `doWork();` inside a function body → `;` (skips `super()`, which constructors require)
+fn call_statement_mutation(node: Node, source: &str, out: &mut Vec<Mutation>) {
+    let Some(expr) = node.named_child(0) else {
+        return;
+    };
+    let call = match expr.kind() {
+        "await_expression" => expr.named_child(0),
+        _ => Some(expr),
+    };
+    let Some(call) = call.filter(|c| c.kind() == "call_expression") else {
+        return;
+    };
+    if call
+        .child_by_field_name("function")
+        .is_some_and(|f| f.kind() == "super")
+    {
+        return;
+    }
+    if !inside_function_body(node) {
+        return;
+    }
+    push(
+        out,
+        source,
+        node,
+        ";",
+        "call statement removed",
+        MutationOperator::CallStatement,
+    );
+}
+
+/// Function-like nodes: a mutant's scope, and where call statements may be removed
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function_declaration",
+    "generator_function",
+];
+
+/// Name of the nearest named function containing byte `offset` of `source` (parsed as
+/// `root`), e.g. to find the tests that call a mutated function
+pub fn function_name_at<'a>(root: Node, source: &'a str, offset: usize) -> Option<&'a str> {
+    let mut current = root.descendant_for_byte_range(offset, offset);
+    while let Some(n) = current {
+        if FUNCTION_KINDS.contains(&n.kind()) {
+            if let Some(name) = function_name(n, source) {
+                return Some(name);
+            }
+        }
+        current = n.parent();
+    }
+    None
+}
+
+fn inside_function_body(node: Node) -> bool {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if FUNCTION_KINDS.contains(&n.kind()) {
+            return true;
+        }
+        current = n.parent();
+    }
+    false
+}
+
+/// Outermost function containing `node`, so closures are re-run along with their parent
+fn enclosing_scope(node: Node, source: &str) -> std::ops::Range<usize> {
+    let mut scope = 0..source.len();
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if FUNCTION_KINDS.contains(&n.kind()) {
+            scope = n.byte_range();
+        }
+        current = n.parent();
+    }
+    scope
+}
+
+fn child_of_kind<'a>(node: Node<'a>, kinds: &[&str]) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .find(|c| kinds.contains(&c.kind()));
+    found
+}
+
+fn text<'a>(node: Node, source: &'a str) -> &'a str {
+    &source[node.byte_range()]
+}
+
+fn push(
+    out: &mut Vec<Mutation>,
+    source: &str,
+    node: Node,
+    replacement: &str,
+    description: &str,
+    operator: MutationOperator,
+) {
+    let original = text(node, source);
+    if original == replacement {
+        return;
+    }
+    let pos = node.start_position();
+    out.push(Mutation {
+        start: node.start_byte(),
+        end: node.end_byte(),
+        line: pos.row + 1,
+        column: pos.column + 1,
+        original: original.to_string(),
+        replacement: replacement.to_string(),
+        description: description.to_string(),
+        operator,
+        scope: enclosing_scope(node, source),
+    });
 }
 
 /// Apply a single mutation to source content.
@@ -239,6 +752,17 @@ pub fn apply_mutation(source: &str, mutation: &Mutation) -> String {
 mod tests {
     use super::*;
 
+    fn generate_mutations(source: &str) -> Vec<Mutation> {
+        generate_mutations_for_path(Path::new("source.ts"), source)
+    }
+
+    fn of(mutations: &[Mutation], operator: MutationOperator) -> Vec<&Mutation> {
+        mutations
+            .iter()
+            .filter(|m| m.operator == operator)
+            .collect()
+    }
+
     #[test]
     fn test_apply_mutation() {
         let s = "if (x >= 0) return true;";
@@ -246,75 +770,129 @@ mod tests {
         assert!(!mutations.is_empty());
     }
 
+    #[test]
+    fn test_boundary_mutation_position() {
+        let s = "function f(x) {\n  return x >= 0;\n}";
+        let mutations = generate_mutations(s);
+        let boundary = of(&mutations, MutationOperator::Boundary);
+        assert_eq!(boundary.len(), 1);
+        assert_eq!(boundary[0].description, ">= to >");
+        assert_eq!((boundary[0].line, boundary[0].column), (2, 12));
+        assert_eq!(
+            apply_mutation(s, boundary[0]),
+            "function f(x) {\n  return x > 0;\n}"
+        );
+    }
+
+    #[test]
+    fn test_skips_comments_strings_and_types() {
+        let s = r#"// if a >= b return true
+const label = "a >= b";
+type Pair = Map<string, Array<number>>;
+const f = (a: number): boolean => a > 1;
+interface Opts { retries: 3 }
+"#;
+        let mutations = generate_mutations(s);
+        let boundary = of(&mutations, MutationOperator::Boundary);
+        assert_eq!(boundary.len(), 1, "only `a > 1`: {:?}", boundary);
+        assert_eq!(boundary[0].original, ">");
+        assert!(of(&mutations, MutationOperator::Boolean).is_empty());
+    }
+
     #[test]
     fn test_string_mutations() {
-        let s = r#"const x = "hello"; const y = '';"#;
+        let s = r#"import x from "./x"; const a = "hello"; const b = '';"#;
         let mutations = generate_mutations(s);
-        let string_ops: Vec<_> = mutations
-            .iter()
-            .filter(|m| m.description.contains("string") || m.description.contains("empty"))
-            .collect();
-        assert!(!string_ops.is_empty(), "should have string mutations");
-        let applied = apply_mutation(s, &mutations[0]);
-        assert_ne!(applied, s);
+        let strings = of(&mutations, MutationOperator::String);
+        assert_eq!(strings.len(), 2, "import source is skipped");
+        assert_eq!(strings[0].replacement, r#""""#);
+        assert_eq!(strings[1].replacement, "' '");
     }
 
     #[test]
     fn test_array_mutations() {
         let s = "const a = [1, 2]; const b = []; const c = arr[0];";
         let mutations = generate_mutations(s);
-        let array_ops: Vec<_> = mutations
+        let replacements: Vec<_> = of(&mutations, MutationOperator::Array)
             .iter()
-            .filter(|m| m.description.contains("array") || m.description.contains("index"))
+            .map(|m| m.replacement.as_str())
             .collect();
-        assert!(!array_ops.is_empty());
+        assert_eq!(replacements, vec!["[]", "[0]", "1"]);
     }
 
     #[test]
     fn test_return_mutations() {
         let s = "function f() { return 42; }";
         let mutations = generate_mutations(s);
-        let return_ops: Vec<_> = mutations
-            .iter()
-            .filter(|m| m.description.contains("return"))
-            .collect();
-        assert!(!return_ops.is_empty());
         let null_mut = mutations
             .iter()
             .find(|m| m.replacement == "return null;")
             .unwrap();
         let applied = apply_mutation(s, null_mut);
-        assert!(applied.contains("return null;"));
+        assert_eq!(applied, "function f() { return null; }");
     }
 
     #[test]
     fn test_increment_decrement_mutations() {
-        let s = "let i = 0; i++; i--; i += 1; i -= 1;";
+        let s = "let i = 0; i++; i--; i += 2; i -= 1;";
+        let mutations = generate_mutations(s);
+        let descriptions: Vec<_> = of(&mutations, MutationOperator::Increment)
+            .iter()
+            .map(|m| m.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec!["++ to --", "-- to ++", "+= to -=", "-= to +="]
+        );
+    }
+
+    #[test]
+    fn test_conditional_negation() {
+        let s = "function f(a) { if (a.ok) { go(); } return a.n > 0 ? 1 : 2; }";
+        let mutations = generate_mutations(s);
+        let negated: Vec<_> = of(&mutations, MutationOperator::ConditionalNegation)
+            .iter()
+            .map(|m| apply_mutation(s, m))
+            .collect();
+        assert_eq!(negated.len(), 2);
+        assert!(negated[0].contains("if (!(a.ok))"));
+        assert!(negated[1].contains("return !(a.n > 0) ? 1 : 2"));
+    }
+
+    #[test]
+    fn test_call_statement_removal() {
+        let s = "setup();\nclass A extends B { constructor() { super(); this.init(); } }\nasync function f() { await save(x); }";
+        let mutations = generate_mutations(s);
+        let removed: Vec<_> = of(&mutations, MutationOperator::CallStatement)
+            .iter()
+            .map(|m| m.original.as_str())
+            .collect();
+        assert_eq!(removed, vec!["this.init();", "await save(x);"]);
+    }
+
+    #[test]
+    fn test_arithmetic_operand_replacement() {
+        let s = "const total = price * qty;";
         let mutations = generate_mutations(s);
-        let inc_ops: Vec<_> = mutations
+        let operands: Vec<_> = of(&mutations, MutationOperator::ArithmeticOperand)
             .iter()
-            .filter(|m| {
-                m.description.contains("++")
-                    || m.description.contains("--")
-                    || m.description.contains("+=")
-                    || m.description.contains("-=")
-            })
+            .map(|m| apply_mutation(s, m))
             .collect();
-        assert!(!inc_ops.is_empty());
+        assert_eq!(operands, vec!["const total = price;", "const total = qty;"]);
     }
 
     #[test]
     fn test_typescript_optional_chaining_mutations() {
-        let s = "const name = user?.profile?.name;";
+        let s = "const name = user?.profile?.name; const first = list?.[0]; cb?.();";
         let mutations = generate_mutations(s);
-        let ts_ops: Vec<_> = mutations
+        let applied: Vec<_> = of(&mutations, MutationOperator::OptionalChaining)
             .iter()
-            .filter(|m| m.description.contains("optional chaining"))
+            .map(|m| apply_mutation(s, m))
             .collect();
-        assert!(
-            !ts_ops.is_empty(),
-            "Should have optional chaining mutations"
-        );
+        assert_eq!(applied.len(), 4);
+        assert!(applied.iter().any(|a| a.contains("user.profile?.name")));
+        assert!(applied.iter().any(|a| a.contains("list[0]")));
+        assert!(applied.iter().any(|a| a.contains(" cb();")));
     }
 
     #[test]
@@ -325,23 +903,69 @@ mod tests {
             .iter()
             .filter(|m| m.description.contains("nullish"))
             .collect();
-        assert!(
-            !ts_ops.is_empty(),
-            "Should have nullish coalescing mutations"
-        );
+        assert_eq!(ts_ops.len(), 1);
+        assert_eq!(ts_ops[0].replacement, "||");
     }
 
     #[test]
     fn test_typescript_non_null_assertion_mutations() {
         let s = "const element = document.getElementById('app')!.innerText;";
         let mutations = generate_mutations(s);
-        let ts_ops: Vec<_> = mutations
-            .iter()
-            .filter(|m| m.description.contains("non-null"))
-            .collect();
-        assert!(
-            !ts_ops.is_empty(),
-            "Should have non-null assertion mutations"
+        let ts_ops = of(&mutations, MutationOperator::NonNullAssertion);
+        assert_eq!(ts_ops.len(), 1);
+        assert_eq!(
+            apply_mutation(s, ts_ops[0]),
+            "const element = document.getElementById('app').innerText;"
         );
     }
+
+    #[test]
+    fn test_scope_is_outermost_function() {
+        let s = "const a = 1 > 0;\nfunction f() { return [1, 2].map((x) => x > 1); }";
+        let mutations = generate_mutations(s);
+        let boundary = of(&mutations, MutationOperator::Boundary);
+        assert_eq!(boundary[0].scope, 0..s.len());
+        assert_eq!(&s[boundary[1].scope.clone()], &s[17..]);
+    }
+
+    #[test]
+    fn test_operator_settings() {
+        let s = "function log(msg) { console.log('debug: ' + msg); }\n\
+                 const round = (x) => Math.floor(x) >= 0 ? 'up' : 'down';";
+        let settings = OperatorSettings {
+            disabled_groups: HashSet::from(["string".to_string()]),
+            custom: vec![CustomOperator {
+                name: "floor-to-ceil".to_string(),
+                from: "Math.floor".to_string(),
+                to: "Math.ceil".to_string(),
+            }],
+            exclude_functions: vec!["log".to_string()],
+        };
+        let mutations = generate_mutations_with(Path::new("source.ts"), s, &settings);
+
+        assert!(of(&mutations, MutationOperator::String).is_empty());
+        assert!(mutations.iter().all(|m| m.line == 2), "log() is excluded");
+        let custom = of(&mutations, MutationOperator::Custom);
+        assert_eq!(custom.len(), 1);
+        assert_eq!(custom[0].description, "floor-to-ceil");
+        assert!(apply_mutation(s, custom[0]).contains("Math.ceil(x) >= 0"));
+        assert_eq!(of(&mutations, MutationOperator::Boundary).len(), 1);
+    }
+
+    #[test]
+    fn test_function_name_at() {
+        let s = "export const total = (xs) => xs.map((x) => x * 2);\nclass Cart { add(item) { return 1; } }";
+        let tree = TypeScriptParser::new().unwrap().parse(s).unwrap();
+        let at = |needle: &str| function_name_at(tree.root_node(), s, s.find(needle).unwrap());
+        assert_eq!(at("x * 2"), Some("total"));
+        assert_eq!(at("return 1"), Some("add"));
+        assert_eq!(at("class"), None);
+    }
+
+    #[test]
+    fn test_tsx_grammar_from_path() {
+        let s = "const el = <div hidden={count > 0}>hi</div>;";
+        let mutations = generate_mutations_for_path(Path::new("App.tsx"), s);
+        assert_eq!(of(&mutations, MutationOperator::Boundary).len(), 1);
+    }
 }
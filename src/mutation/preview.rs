@@ -0,0 +1,220 @@
+//! Preview mutants without running them: what a source file generates, which mutants
+//! each `--mutate` mode would pick, and what each change looks like as a diff.
+
+use super::operators::Mutation;
+use super::{sampler, MutationOptions, MutationTarget, MODES};
+
+/// Mutants generated for one source file with the configured operators
+#[derive(Debug, Clone)]
+pub struct MutationPreview {
+    /// Every generated mutant, in source order
+    pub mutations: Vec<Mutation>,
+    /// Per mutant: false when coverage shows no test executes its line
+    pub covered: Vec<bool>,
+}
+
+impl MutationPreview {
+    /// Generate the mutants `run_mutation_test` would consider for `target`
+    pub fn new(target: &MutationTarget, source_content: &str, options: &MutationOptions) -> Self {
+        let mutations = super::candidate_mutations(target, source_content, options);
+        let covered = mutations
+            .iter()
+            .map(|m| sampler::is_covered(m, target.coverage.as_ref()))
+            .collect();
+        Self { mutations, covered }
+    }
+
+    /// Indices of the mutants a run capped at `count` would execute. Uncovered mutants are
+    /// never run when coverage is known (they are reported as `NO COVERAGE`).
+    pub fn selected(&self, count: usize, seed: u64) -> Vec<usize> {
+        let candidates: Vec<usize> = (0..self.mutations.len())
+            .filter(|&i| self.covered[i])
+            .collect();
+        let pool: Vec<Mutation> = candidates
+            .iter()
+            .map(|&i| self.mutations[i].clone())
+            .collect();
+        sampler::select_indices(&pool, count, seed)
+            .into_iter()
+            .map(|i| candidates[i])
+            .collect()
+    }
+
+    /// Per mutant, the `--mutate` modes that would run it
+    pub fn modes(&self, seed: u64) -> Vec<Vec<&'static str>> {
+        let mut modes = vec![Vec::new(); self.mutations.len()];
+        for &(mode, count) in MODES {
+            for i in self.selected(count, seed) {
+                modes[i].push(mode);
+            }
+        }
+        modes
+    }
+
+    /// JSON listing for `rigor mutants --json`; `source_label` names the file
+    pub fn to_json(
+        &self,
+        source_label: &str,
+        seed: u64,
+        diff: bool,
+        source_content: &str,
+    ) -> serde_json::Value {
+        let modes = self.modes(seed);
+        let mutants: Vec<serde_json::Value> = self
+            .mutations
+            .iter()
+            .zip(&modes)
+            .zip(&self.covered)
+            .enumerate()
+            .map(|(i, ((m, modes), covered))| {
+                let mut entry = serde_json::json!({
+                    "id": i + 1,
+                    "operator": m.operator.group(),
+                    "mutatorName": m.mutator_name(),
+                    "line": m.line,
+                    "column": m.column,
+                    "original": m.original,
+                    "replacement": m.replacement,
+                    "description": m.description,
+                    "covered": covered,
+                    "modes": modes,
+                });
+                if diff {
+                    entry["diff"] = unified_diff(source_label, source_content, m, 2).into();
+                }
+                entry
+            })
+            .collect();
+        let selected: serde_json::Map<String, serde_json::Value> = MODES
+            .iter()
+            .map(|&(mode, count)| (mode.to_string(), self.selected(count, seed).len().into()))
+            .collect();
+        serde_json::json!({
+            "source": source_label,
+            "total": self.mutations.len(),
+            "uncovered": self.covered.iter().filter(|c| !**c).count(),
+            "seed": seed,
+            "selected": selected,
+            "mutants": mutants,
+        })
+    }
+}
+
+/// Unified diff of `mutation` applied to `source`, with `context` lines around the change
+pub fn unified_diff(label: &str, source: &str, mutation: &Mutation, context: usize) -> String {
+    let line_start = source[..mutation.start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source[mutation.end..]
+        .find('\n')
+        .map_or(source.len(), |i| mutation.end + i);
+    let old_block = &source[line_start..line_end];
+    let new_block = format!(
+        "{}{}{}",
+        &source[line_start..mutation.start],
+        mutation.replacement,
+        &source[mutation.end..line_end]
+    );
+
+    let lines: Vec<&str> = source.lines().collect();
+    let first = source[..line_start].matches('\n').count();
+    let old_count = old_block.lines().count().max(1);
+    let before = first.saturating_sub(context);
+    let after = (first + old_count + context).min(lines.len());
+    let new_count = new_block.lines().count();
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", label, label);
+    out.push_str(&format!(
+        "@@ -{},{} +{},{} @@\n",
+        before + 1,
+        after - before,
+        before + 1,
+        after - before - old_count + new_count
+    ));
+    for line in &lines[before..first] {
+        out.push_str(&format!(" {}\n", line));
+    }
+    for line in old_block.lines() {
+        out.push_str(&format!("-{}\n", line));
+    }
+    for line in new_block.lines() {
+        out.push_str(&format!("+{}\n", line));
+    }
+    for line in &lines[(first + old_count).min(lines.len())..after] {
+        out.push_str(&format!(" {}\n", line));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "export function isAdult(age) {\n  return age >= 18;\n}\n";
+
+    #[test]
+    fn test_unified_diff() {
+        let target = MutationTarget::new("age.ts");
+        let preview = MutationPreview::new(&target, SOURCE, &MutationOptions::default());
+        let boundary = preview
+            .mutations
+            .iter()
+            .find(|m| m.description == ">= to >")
+            .unwrap();
+
+        assert_eq!(
+            unified_diff("src/age.ts", SOURCE, boundary, 1),
+            "--- a/src/age.ts\n+++ b/src/age.ts\n@@ -1,3 +1,3 @@\n \
+             export function isAdult(age) {\n-  return age >= 18;\n+  return age > 18;\n }\n"
+        );
+    }
+
+    #[test]
+    fn test_selection_matches_run() {
+        let source = "export function grade(score) {\n  if (score >= 90) return 'A';\n  if (score > 80 && score <= 89) return 'B';\n  return score < 0 ? null : 'C';\n}\n\nexport function isAdult(age) {\n  return age >= 18 || age === 17;\n}\n";
+        let mut target = MutationTarget::new("grade.ts");
+        let mut coverage = crate::coverage::FileCoverage::default();
+        coverage.lines.insert(4, 0);
+        target.coverage = Some(coverage);
+
+        for (count, seed) in [(3, 0), (5, 7), (10, 42)] {
+            let options = MutationOptions {
+                count,
+                seed,
+                ..Default::default()
+            };
+            let preview = MutationPreview::new(&target, source, &options);
+            let previewed: Vec<&Mutation> = preview
+                .selected(count, seed)
+                .into_iter()
+                .map(|i| &preview.mutations[i])
+                .collect();
+            let candidates = super::super::candidate_mutations(&target, source, &options);
+            let (run, _) = super::super::select_for_run(&candidates, &target, &options);
+            assert!(!run.is_empty());
+            assert_eq!(
+                previewed
+                    .iter()
+                    .map(|m| (m.start, &m.description))
+                    .collect::<Vec<_>>(),
+                run.iter()
+                    .map(|m| (m.start, &m.description))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_modes_respect_coverage() {
+        let mut target = MutationTarget::new("age.ts");
+        let mut coverage = crate::coverage::FileCoverage::default();
+        coverage.lines.insert(2, 0);
+        target.coverage = Some(coverage);
+        let preview = MutationPreview::new(&target, SOURCE, &MutationOptions::default());
+
+        assert!(!preview.mutations.is_empty());
+        assert!(preview.covered.iter().all(|c| !c));
+        assert!(preview.modes(0).iter().all(|m| m.is_empty()));
+        let json = preview.to_json("age.ts", 0, false, SOURCE);
+        assert_eq!(json["selected"]["full"], 0);
+        assert_eq!(json["uncovered"], preview.mutations.len());
+    }
+}
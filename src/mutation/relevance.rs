@@ -20,9 +20,10 @@
 
 use std::collections::HashMap;
 
-use super::{MutationResult, MutationRun};
+use super::{MutantStatus, MutationResult, MutationRun};
 
-/// Summary of test relevance: which source locations had no test coverage (survived mutants).
+/// Summary of test relevance: which source locations had no test coverage (survived or
+/// uncovered mutants).
 #[derive(Debug, Clone)]
 pub struct RelevanceSummary {
     /// Source path (same as mutation result).
@@ -76,15 +77,28 @@ fn suggestion_hint(description: &str) -> &'static str {
     }
 }
 
+/// Relevance hint for an undetected mutant: what kind of test would catch it.
+pub fn hint_for(run: &MutationRun) -> &'static str {
+    if run.status == MutantStatus::NoCoverage {
+        "No test executes this code; add a test that reaches it before asserting on its behavior."
+    } else {
+        suggestion_hint(&run.mutation.description)
+    }
+}
+
 /// Build a relevance summary from mutation testing results.
 pub fn relevance_summary(result: &MutationResult) -> RelevanceSummary {
-    let kill_rate_percent = if result.total > 0 {
-        (result.killed as f32 / result.total as f32 * 100.0) as u32
+    let kill_rate_percent = if result.valid() > 0 {
+        result.score() as u32
     } else {
         0
     };
 
-    let survived: Vec<&MutationRun> = result.details.iter().filter(|r| !r.killed).collect();
+    let survived: Vec<&MutationRun> = result
+        .details
+        .iter()
+        .filter(|r| r.status.is_undetected())
+        .collect();
     let mut survived_by_line: HashMap<usize, Vec<SurvivedAtLine>> = HashMap::new();
     let mut seen_hints = std::collections::HashSet::new();
     let mut suggestions = Vec::new();
@@ -100,12 +114,24 @@ pub fn relevance_summary(result: &MutationResult) -> RelevanceSummary {
         };
         survived_by_line.entry(line).or_default().push(entry);
 
-        let hint = suggestion_hint(&run.mutation.description);
+        let hint = hint_for(run);
         if seen_hints.insert(hint) {
             suggestions.push(hint.to_string());
         }
     }
 
+    // A low kill rate means the problem is the suite's assertions, not a few missed lines
+    const LOW_KILL_RATE: u32 = 60;
+    if !survived.is_empty() && result.valid() > 0 && kill_rate_percent < LOW_KILL_RATE {
+        suggestions.insert(
+            0,
+            format!(
+                "Only {}% of mutants were detected: strengthen the existing assertions before adding new tests.",
+                kill_rate_percent
+            ),
+        );
+    }
+
     let lines_with_survived = survived_by_line.len();
 
     RelevanceSummary {
@@ -120,7 +146,7 @@ pub fn relevance_summary(result: &MutationResult) -> RelevanceSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mutation::Mutation;
+    use crate::mutation::{MutantStatus, Mutation, MutationOperator};
 
     #[test]
     fn test_relevance_summary_from_result() {
@@ -129,6 +155,9 @@ mod tests {
             total: 3,
             killed: 1,
             survived: 2,
+            timed_out: 0,
+            no_coverage: 0,
+            errors: 0,
             details: vec![
                 MutationRun {
                     mutation: Mutation {
@@ -139,10 +168,14 @@ mod tests {
                         original: ">=".to_string(),
                         replacement: ">".to_string(),
                         description: ">= to >".to_string(),
+                        operator: MutationOperator::Boundary,
+                        scope: 0..0,
                     },
-                    killed: true,
+                    status: MutantStatus::Killed,
                     stdout: String::new(),
                     stderr: String::new(),
+                    duration: std::time::Duration::ZERO,
+                    cached: false,
                 },
                 MutationRun {
                     mutation: Mutation {
@@ -153,10 +186,14 @@ mod tests {
                         original: "return 42;".to_string(),
                         replacement: "return null;".to_string(),
                         description: "return to null/undefined".to_string(),
+                        operator: MutationOperator::Return,
+                        scope: 0..0,
                     },
-                    killed: false,
+                    status: MutantStatus::Survived,
                     stdout: String::new(),
                     stderr: String::new(),
+                    duration: std::time::Duration::ZERO,
+                    cached: false,
                 },
                 MutationRun {
                     mutation: Mutation {
@@ -167,10 +204,14 @@ mod tests {
                         original: "return 0;".to_string(),
                         replacement: "return undefined;".to_string(),
                         description: "return to null/undefined".to_string(),
+                        operator: MutationOperator::Return,
+                        scope: 0..0,
                     },
-                    killed: false,
+                    status: MutantStatus::Survived,
                     stdout: String::new(),
                     stderr: String::new(),
+                    duration: std::time::Duration::ZERO,
+                    cached: false,
                 },
             ],
         };
@@ -186,6 +227,7 @@ mod tests {
                 .unwrap_or(0),
             2
         );
-        assert!(!summary.suggestions.is_empty());
+        assert!(summary.suggestions[0].starts_with("Only 33% of mutants were detected"));
+        assert!(summary.suggestions[1].contains("return value"));
     }
 }
@@ -1,7 +1,8 @@
 //! Report mutation testing results.
 
+use super::preview::{unified_diff, MutationPreview};
 use super::relevance::relevance_summary;
-use super::{BatchMutationResult, MutationResult};
+use super::{BatchMutationResult, MutantStatus, MutationResult, MODES};
 use colored::Colorize;
 
 /// Print mutation result to stdout, including a relevance section when mutants survived.
@@ -9,8 +10,8 @@ pub fn report(result: &MutationResult) {
     let total = result.total;
     let killed = result.killed;
     let survived = result.survived;
-    let pct = if total > 0 {
-        (killed as f32 / total as f32 * 100.0) as u32
+    let pct = if result.valid() > 0 {
+        result.score() as u32
     } else {
         0
     };
@@ -19,33 +20,189 @@ pub fn report(result: &MutationResult) {
     println!("{}", "Mutation testing".bold());
     println!("   Source: {}", result.source_path.display());
     println!(
-        "   Mutants: {} total, {} killed, {} survived",
-        total, killed, survived
+        "   Mutants: {} total, {} killed, {} survived{}",
+        total,
+        killed,
+        survived,
+        outcome_extras(result.timed_out, result.no_coverage, result.errors)
     );
-    println!("   Score: {}%", pct);
+    if result.no_coverage > 0 && result.valid() > result.no_coverage {
+        println!(
+            "   Score: {}% ({}% of mutants on covered code)",
+            pct,
+            result.covered_score() as u32
+        );
+    } else {
+        println!("   Score: {}%", pct);
+    }
 
     if !result.details.is_empty() {
         println!();
         println!("   {}", "Details:".bold());
         for run in &result.details {
-            let status = if run.killed {
-                "KILLED".green()
-            } else {
-                "SURVIVED".red()
+            let label = run.status.label();
+            let status = match run.status {
+                MutantStatus::Killed | MutantStatus::Timeout => label.green(),
+                MutantStatus::Survived | MutantStatus::NoCoverage => label.red(),
+                MutantStatus::CompileError | MutantStatus::RuntimeError => label.yellow(),
             };
             println!(
-                "   {} L{}:{} {} → {}",
+                "   {} L{}:{} {} → {}{}",
                 status,
                 run.mutation.line,
                 run.mutation.column,
                 run.mutation.original.dimmed(),
-                run.mutation.replacement.dimmed()
+                run.mutation.replacement.dimmed(),
+                if run.cached {
+                    " (cached)".dimmed().to_string()
+                } else {
+                    String::new()
+                }
+            );
+        }
+        let cached = result.details.iter().filter(|r| r.cached).count();
+        if cached > 0 {
+            println!(
+                "   {}",
+                format!(
+                    "{} of {} outcome(s) reused from the previous run (mutant and tests unchanged)",
+                    cached, total
+                )
+                .dimmed()
             );
         }
     }
 
-    // Relevance: interpret survived mutants as "tests not relevant to these behaviors"
-    if survived > 0 {
+    print_relevance(result);
+
+    println!();
+}
+
+/// Print a mutation result loaded from a report (e.g. Stryker): score and relevance only,
+/// since the per-mutant details already live in that report
+pub fn report_imported(result: &MutationResult) {
+    println!();
+    println!(
+        "{} {}",
+        "Mutation report".bold(),
+        result.source_path.display()
+    );
+    println!(
+        "   Score: {}% ({}/{} detected{})",
+        result.score() as u32,
+        result.detected(),
+        result.valid(),
+        outcome_extras(result.timed_out, result.no_coverage, result.errors)
+    );
+    print_relevance(result);
+    println!();
+}
+
+/// Print the outcome of inverting each assertion in a test file (`--mutate-tests`)
+pub fn report_assertions(result: &MutationResult) {
+    println!();
+    println!(
+        "{} {}",
+        "Assertion check".bold(),
+        result.source_path.display()
+    );
+    println!(
+        "   Assertions: {} inverted, {} load-bearing, {} carry no weight{}",
+        result.total,
+        result.detected(),
+        result.survived,
+        outcome_extras(0, 0, result.errors)
+    );
+    for run in result.details.iter().filter(|r| r.status.is_undetected()) {
+        println!(
+            "   {} L{}:{} {}",
+            "NO WEIGHT".red(),
+            run.mutation.line,
+            run.mutation.column,
+            run.mutation.original.dimmed()
+        );
+    }
+    println!();
+}
+
+/// Print the mutants of a source file and the modes that would run each. `only_mode`
+/// limits the list to one mode's sample; `diff_source` (the file content) adds diffs.
+pub fn report_preview(
+    preview: &MutationPreview,
+    source_label: &str,
+    seed: u64,
+    only_mode: Option<&str>,
+    diff_source: Option<&str>,
+) {
+    let modes = preview.modes(seed);
+    let uncovered = preview.covered.iter().filter(|c| !**c).count();
+
+    println!();
+    println!("{} {}", "Mutants".bold(), source_label);
+    println!(
+        "   {} generated{}",
+        preview.mutations.len(),
+        if uncovered > 0 {
+            format!(", {} on lines no test executes", uncovered)
+        } else {
+            String::new()
+        }
+    );
+    let selected: Vec<String> = MODES
+        .iter()
+        .map(|&(mode, count)| format!("{} {}", mode, preview.selected(count, seed).len()))
+        .collect();
+    println!("   Selected: {} (seed {})", selected.join(", "), seed);
+
+    if preview.mutations.is_empty() {
+        println!();
+        return;
+    }
+    println!();
+    println!("   {}", "Q M F".dimmed());
+    for (i, m) in preview.mutations.iter().enumerate() {
+        if only_mode.is_some_and(|mode| !modes[i].contains(&mode)) {
+            continue;
+        }
+        let marks: Vec<String> = MODES
+            .iter()
+            .map(|(mode, _)| {
+                if modes[i].contains(mode) {
+                    "●".green().to_string()
+                } else {
+                    "·".dimmed().to_string()
+                }
+            })
+            .collect();
+        println!(
+            "   {}  {:<8} {} {}{}",
+            marks.join(" "),
+            format!("L{}:{}", m.line, m.column),
+            format!("{:<17}", m.operator.group()).cyan(),
+            m.change_summary(),
+            if preview.covered[i] {
+                String::new()
+            } else {
+                " (no coverage)".dimmed().to_string()
+            }
+        );
+        if let Some(source) = diff_source {
+            for line in unified_diff(source_label, source, m, 2).lines() {
+                let line = match line.chars().next() {
+                    Some('+') if !line.starts_with("+++") => line.green().to_string(),
+                    Some('-') if !line.starts_with("---") => line.red().to_string(),
+                    _ => line.dimmed().to_string(),
+                };
+                println!("        {}", line);
+            }
+        }
+    }
+    println!();
+}
+
+/// Relevance: interpret survived mutants as "tests not relevant to these behaviors"
+fn print_relevance(result: &MutationResult) {
+    if result.survived + result.no_coverage > 0 {
         let rel = relevance_summary(result);
         println!();
         println!("   {}", "Test relevance".bold());
@@ -68,8 +225,6 @@ pub fn report(result: &MutationResult) {
             }
         }
     }
-
-    println!();
 }
 
 /// Print batch mutation results to stdout
@@ -79,8 +234,15 @@ pub fn report_batch(result: &BatchMutationResult) {
     println!("   Files analyzed: {}", result.source_results.len());
     println!("   Total mutants: {}", result.total_mutants);
     println!(
-        "   Killed: {} ({}%), Survived: {}",
-        result.total_killed, result.overall_score as u32, result.total_survived
+        "   Killed: {} ({}%), Survived: {}{}",
+        result.total_killed,
+        result.overall_score as u32,
+        result.total_survived,
+        outcome_extras(
+            result.total_timed_out,
+            result.total_no_coverage,
+            result.total_errors
+        )
     );
     println!(
         "   {}: {:.1}%",
@@ -102,11 +264,11 @@ pub fn report_batch(result: &BatchMutationResult) {
                 format!("{}%", pct).red()
             };
             println!(
-                "   {} {} ({}/{} killed)",
+                "   {} {} ({}/{} detected)",
                 status,
                 file_result.source_path.display(),
-                file_result.killed,
-                file_result.total
+                file_result.detected(),
+                file_result.valid()
             );
         }
     }
@@ -115,9 +277,9 @@ pub fn report_batch(result: &BatchMutationResult) {
     let mut files_with_survivors: Vec<_> = result
         .source_results
         .iter()
-        .filter(|r| r.survived > 0)
+        .filter(|r| r.survived + r.no_coverage > 0)
         .collect();
-    files_with_survivors.sort_by_key(|b| std::cmp::Reverse(b.survived));
+    files_with_survivors.sort_by_key(|b| std::cmp::Reverse(b.survived + b.no_coverage));
 
     if !files_with_survivors.is_empty() {
         println!();
@@ -130,10 +292,25 @@ pub fn report_batch(result: &BatchMutationResult) {
                     .file_name()
                     .map(|n| n.to_string_lossy().into_owned())
                     .unwrap_or_else(|| "?".to_string()),
-                file_result.survived
+                file_result.survived + file_result.no_coverage
             );
         }
     }
 
     println!();
 }
+
+/// ", 2 timed out, 1 errors (not scored)" - only the outcomes that occurred
+fn outcome_extras(timed_out: usize, no_coverage: usize, errors: usize) -> String {
+    let mut out = String::new();
+    if timed_out > 0 {
+        out.push_str(&format!(", {} timed out", timed_out));
+    }
+    if no_coverage > 0 {
+        out.push_str(&format!(", {} not covered", no_coverage));
+    }
+    if errors > 0 {
+        out.push_str(&format!(", {} errors (not scored)", errors));
+    }
+    out
+}
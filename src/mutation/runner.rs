@@ -1,19 +1,57 @@
 //! Run tests against each mutant.
 
 use super::operators::{apply_mutation, Mutation};
-use std::fs;
+use super::sandbox::Sandbox;
+use super::{MutantStatus, MutationRun};
+use std::io::Read;
 use std::path::Path;
-use std::process::Command;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use super::MutationRun;
+/// How often a running test command is checked for completion
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
 
-/// Run tests for each mutation: overwrite source, run cmd, restore.
+/// Run the tests once against the unmutated sandbox and return how long they took.
+/// Fails if they don't pass: every mutant would then look killed.
+pub fn run_baseline(sandbox: &Sandbox, argv: &[String]) -> std::io::Result<Duration> {
+    let output = run_test_command(argv, sandbox.dir(), None);
+    let command = argv.join(" ");
+    match output.exit {
+        Exit::Finished(status) if status.success() => Ok(output.duration),
+        Exit::Finished(status) => Err(std::io::Error::other(format!(
+            "`{}` fails on the unmutated source ({}), so mutants can't be judged:\n{}",
+            command,
+            status,
+            output.tail()
+        ))),
+        Exit::SpawnFailed(e) => Err(std::io::Error::other(format!(
+            "could not run `{}`: {}",
+            command, e
+        ))),
+        Exit::TimedOut => unreachable!("baseline runs without a timeout"),
+    }
+}
+
+/// Run tests for each mutation inside `sandbox`: write the mutant to the sandbox copy of
+/// the source, run `argv` from the sandbox root (killing it after `timeout`), restore.
+/// The real source file is never modified.
 pub fn run_mutations(
+    sandbox: &Sandbox,
     source_path: &Path,
     original_content: &str,
     mutations: &[Mutation],
-    test_command: &str,
+    argv: &[String],
+    timeout: Duration,
 ) -> std::io::Result<Vec<MutationRun>> {
+    let sandbox_source = sandbox.map(source_path).ok_or_else(|| {
+        std::io::Error::other(format!(
+            "{} is outside the sandboxed project {}",
+            source_path.display(),
+            sandbox.project_root().display()
+        ))
+    })?;
+
     let mut results = Vec::with_capacity(mutations.len());
 
     for mutation in mutations {
@@ -22,51 +60,224 @@ pub fn run_mutations(
             continue;
         }
 
-        if let Err(e) = fs::write(source_path, &mutated_content) {
+        if let Err(e) = sandbox.write_mutant(&sandbox_source, original_content, &mutated_content) {
             results.push(MutationRun {
                 mutation: mutation.clone(),
-                killed: false,
+                status: MutantStatus::RuntimeError,
                 stdout: String::new(),
                 stderr: format!("Failed to write: {}", e),
+                duration: Duration::ZERO,
+                cached: false,
             });
             continue;
         }
 
-        let (killed, stdout, stderr) =
-            run_test_command(test_command, source_path.parent().unwrap_or(Path::new(".")));
+        let output = run_test_command(argv, sandbox.dir(), Some(timeout));
 
-        if fs::write(source_path, original_content).is_err() {
-            // Best-effort restore; continue
-        }
+        // A sandbox that can't be restored must not be reused for further mutants
+        sandbox.restore(&sandbox_source, original_content)?;
 
         results.push(MutationRun {
             mutation: mutation.clone(),
-            killed,
-            stdout,
-            stderr,
+            status: output.status(),
+            duration: output.duration,
+            stdout: output.stdout,
+            stderr: output.stderr,
+            cached: false,
         });
     }
 
     Ok(results)
 }
 
-fn run_test_command(cmd: &str, cwd: &Path) -> (bool, String, String) {
-    let parts: Vec<&str> = cmd.split_whitespace().collect();
-    let (binary, args) = if parts.is_empty() {
-        ("npm", vec!["test"])
-    } else {
-        (parts[0], parts[1..].to_vec())
+#[derive(Debug)]
+enum Exit {
+    Finished(ExitStatus),
+    TimedOut,
+    SpawnFailed(std::io::Error),
+}
+
+#[derive(Debug)]
+struct CommandOutput {
+    exit: Exit,
+    stdout: String,
+    stderr: String,
+    duration: Duration,
+}
+
+impl CommandOutput {
+    /// Classify a finished mutant run
+    fn status(&self) -> MutantStatus {
+        match &self.exit {
+            Exit::TimedOut => MutantStatus::Timeout,
+            Exit::SpawnFailed(_) => MutantStatus::RuntimeError,
+            Exit::Finished(status) if status.success() => MutantStatus::Survived,
+            // 126/127: the shell could not find or execute the command
+            Exit::Finished(status) if matches!(status.code(), Some(126 | 127)) => {
+                MutantStatus::RuntimeError
+            }
+            Exit::Finished(_)
+                if is_compile_error(&self.stdout) || is_compile_error(&self.stderr) =>
+            {
+                MutantStatus::CompileError
+            }
+            Exit::Finished(_) => MutantStatus::Killed,
+        }
+    }
+
+    /// Last lines of output, for error messages
+    fn tail(&self) -> String {
+        let combined = format!("{}{}", self.stdout, self.stderr);
+        let lines: Vec<&str> = combined.lines().collect();
+        lines[lines.len().saturating_sub(10)..].join("\n")
+    }
+}
+
+/// True if test output reports a TypeScript or transpiler error rather than a failing test
+fn is_compile_error(output: &str) -> bool {
+    // tsc / ts-jest: "error TS2322: Type 'number' is not assignable..."
+    let ts_error = output.match_indices("error TS").any(|(i, m)| {
+        output[i + m.len()..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_digit())
+    });
+    // esbuild (vitest, tsx): "Transform failed with 1 error"
+    ts_error || output.contains("Transform failed with")
+}
+
+fn run_test_command(argv: &[String], cwd: &Path, timeout: Option<Duration>) -> CommandOutput {
+    // An empty argv fails to spawn and is reported like a missing binary
+    let (binary, args) = argv
+        .split_first()
+        .map_or(("", &[][..]), |(binary, args)| (binary.as_str(), args));
+
+    let mut command = Command::new(binary);
+    command
+        .args(args)
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // Own process group, so a timeout also takes down test workers the runner spawned
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let started = Instant::now();
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            return CommandOutput {
+                stderr: e.to_string(),
+                exit: Exit::SpawnFailed(e),
+                stdout: String::new(),
+                duration: started.elapsed(),
+            }
+        }
+    };
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let exit = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Exit::Finished(status),
+            Ok(None) => {}
+            Err(e) => {
+                kill_process_group(&mut child);
+                break Exit::SpawnFailed(e);
+            }
+        }
+        if timeout.is_some_and(|t| started.elapsed() >= t) {
+            kill_process_group(&mut child);
+            break Exit::TimedOut;
+        }
+        thread::sleep(POLL_INTERVAL);
     };
+    let duration = started.elapsed();
+    // Workers left behind by the runner would keep the output pipes open
+    kill_process_group(&mut child);
 
-    let output = Command::new(binary).args(args).current_dir(cwd).output();
+    CommandOutput {
+        exit,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        duration,
+    }
+}
 
-    match output {
-        Ok(o) => {
-            let stdout = String::from_utf8_lossy(&o.stdout).to_string();
-            let stderr = String::from_utf8_lossy(&o.stderr).to_string();
-            let killed = !o.status.success();
-            (killed, stdout, stderr)
+/// Read a child pipe to completion on a separate thread so the child never blocks on a full pipe
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
         }
-        Err(e) => (false, String::new(), format!("{}", e)),
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+#[cfg(unix)]
+fn kill_process_group(child: &mut Child) {
+    if let Ok(pid) = libc::pid_t::try_from(child.id()) {
+        // A negative pid signals every process in the group created by `process_group(0)`
+        unsafe { libc::kill(-pid, libc::SIGKILL) };
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_error_detection() {
+        assert!(is_compile_error(
+            "src/age.ts(3,5): error TS2322: Type 'string' is not assignable to type 'number'."
+        ));
+        assert!(is_compile_error("Error: Transform failed with 1 error:"));
+        assert!(!is_compile_error(
+            "Expected: 18\nReceived: 17\nerror TSomething"
+        ));
+    }
+
+    fn argv(cmd: &str) -> Vec<String> {
+        cmd.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_outcomes_from_exit_status() {
+        let cwd = std::env::temp_dir();
+        let passed = run_test_command(&argv("true"), &cwd, Some(Duration::from_secs(10)));
+        assert_eq!(passed.status(), MutantStatus::Survived);
+        let failed = run_test_command(&argv("false"), &cwd, Some(Duration::from_secs(10)));
+        assert_eq!(failed.status(), MutantStatus::Killed);
+        let missing = run_test_command(&argv("rigor-no-such-binary"), &cwd, None);
+        assert_eq!(missing.status(), MutantStatus::RuntimeError);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timeout_kills_process_group() {
+        let cwd = std::env::temp_dir();
+        // The grandchild `sleep` holds stdout open; only a group kill lets the run return
+        let started = Instant::now();
+        let output = run_test_command(
+            &argv("sh -c sleep${IFS}30&sleep${IFS}30"),
+            &cwd,
+            Some(Duration::from_millis(200)),
+        );
+        assert_eq!(output.status(), MutantStatus::Timeout);
+        assert!(started.elapsed() < Duration::from_secs(10));
     }
 }
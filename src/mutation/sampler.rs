@@ -1,14 +1,56 @@
 //! Select a subset of mutations for fast mutation testing.
 
 use super::Mutation;
+use crate::coverage::FileCoverage;
+use std::collections::BTreeMap;
 
-/// Select up to `count` mutations, preferring boundary and boolean operators.
-pub fn select_mutations(mutations: &[Mutation], count: usize) -> Vec<Mutation> {
-    if mutations.is_empty() {
-        return vec![];
-    }
+/// Split mutations into those on executed lines and those on lines coverage shows no
+/// test reaches. Lines without coverage data (no statement starts there) count as covered.
+pub fn partition_by_coverage(
+    mutations: Vec<Mutation>,
+    coverage: Option<&FileCoverage>,
+) -> (Vec<Mutation>, Vec<Mutation>) {
+    mutations.into_iter().partition(|m| is_covered(m, coverage))
+}
+
+/// False when `coverage` shows no test executes the mutant's line
+pub fn is_covered(mutation: &Mutation, coverage: Option<&FileCoverage>) -> bool {
+    let line = mutation.line as u32;
+    coverage.is_none_or(|fc| fc.is_line_covered(line) || !fc.lines.contains_key(&line))
+}
+
+/// Select up to `count` mutations, spread across functions: functions take turns (in an
+/// order shuffled by `seed`), each giving up its best remaining mutant, so a large
+/// function can't use up the whole budget. Within a function boundary operators come
+/// first, then boolean/equality, with ties shuffled by `seed`. The same seed always
+/// picks the same mutants.
+pub fn select_mutations(mutations: &[Mutation], count: usize, seed: u64) -> Vec<Mutation> {
+    select_indices(mutations, count, seed)
+        .into_iter()
+        .map(|i| mutations[i].clone())
+        .collect()
+}
+
+/// Select `uncovered` mutants at the rate [`select_mutations`] samples `covered` of them
+/// with `count`, so a sampled run has the same share of uncovered mutants as the file and
+/// its score isn't skewed towards them
+pub fn select_uncovered(
+    uncovered: &[Mutation],
+    covered: usize,
+    count: usize,
+    seed: u64,
+) -> Vec<Mutation> {
+    let take = match covered {
+        n if n <= count => uncovered.len(),
+        n => (uncovered.len() * count + n / 2) / n,
+    };
+    select_mutations(uncovered, take, seed)
+}
+
+/// Indices of the mutations [`select_mutations`] picks, in ascending order
+pub fn select_indices(mutations: &[Mutation], count: usize, seed: u64) -> Vec<usize> {
     if mutations.len() <= count {
-        return mutations.to_vec();
+        return (0..mutations.len()).collect();
     }
 
     // Prefer >=, <=, >, < (boundary), then true/false, then arithmetic
@@ -22,6 +64,8 @@ pub fn select_mutations(mutations: &[Mutation], count: usize) -> Vec<Mutation> {
         } else if m.description.contains("true")
             || m.description.contains("false")
             || m.description.starts_with("=== ")
+            || m.description.starts_with("!== ")
+            || m.description.starts_with("== ")
             || m.description.starts_with("!= ")
         {
             2
@@ -30,13 +74,57 @@ pub fn select_mutations(mutations: &[Mutation], count: usize) -> Vec<Mutation> {
         }
     };
 
-    let mut indexed: Vec<(usize, u8)> = mutations
-        .iter()
-        .enumerate()
-        .map(|(i, m)| (i, priority(m)))
-        .collect();
-    indexed.sort_by_key(|b| std::cmp::Reverse(b.1));
+    let mut rng = SplitMix64(seed);
+    let mut by_function: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+    for (i, m) in mutations.iter().enumerate() {
+        by_function
+            .entry((m.scope.start, m.scope.end))
+            .or_default()
+            .push(i);
+    }
+    let mut strata: Vec<Vec<usize>> = by_function.into_values().collect();
+    for stratum in &mut strata {
+        rng.shuffle(stratum);
+        stratum.sort_by_key(|&i| std::cmp::Reverse(priority(&mutations[i])));
+    }
+    rng.shuffle(&mut strata);
+
+    let mut take = Vec::with_capacity(count);
+    for round in 0.. {
+        let before = take.len();
+        for stratum in &strata {
+            if take.len() == count {
+                break;
+            }
+            if let Some(&i) = stratum.get(round) {
+                take.push(i);
+            }
+        }
+        if take.len() == count || take.len() == before {
+            break;
+        }
+    }
+    take.sort_unstable();
+    take
+}
 
-    let take: Vec<usize> = indexed.into_iter().take(count).map(|(i, _)| i).collect();
-    take.into_iter().map(|i| mutations[i].clone()).collect()
+/// Small seeded PRNG (SplitMix64): reproducible across platforms and releases
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Fisher-Yates shuffle
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.next() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
 }
@@ -0,0 +1,299 @@
+//! Isolated project copies for running mutants.
+//!
+//! Mutants are never written to the user's working tree. Each worker gets its own copy of
+//! the project in the system temp dir (`node_modules` is symlinked rather than copied), and
+//! the test command runs inside that copy. Sandboxes are registered in the mutation journal
+//! so an interrupted run can be cleaned up on the next start.
+
+use super::journal::{Journal, JOURNAL_FILENAME};
+use super::store::STORE_FILENAME;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use walkdir::WalkDir;
+
+/// Directories and files at the project root never copied into a sandbox
+const SKIPPED_ENTRIES: &[&str] = &[
+    ".git",
+    ".nyc_output",
+    "coverage",
+    ".rigor-cache.json",
+    JOURNAL_FILENAME,
+    STORE_FILENAME,
+];
+
+static SANDBOX_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Root directory a source file's sandbox should mirror (nearest package.json / .git)
+pub(crate) fn project_root_for(source_path: &Path) -> PathBuf {
+    let canonical = source_path
+        .canonicalize()
+        .unwrap_or_else(|_| source_path.to_path_buf());
+    crate::history::find_project_root(&canonical)
+        .or_else(|| canonical.parent().map(Path::to_path_buf))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// A disposable copy of a project directory
+#[derive(Debug)]
+pub(crate) struct Sandbox {
+    project_root: PathBuf,
+    dir: PathBuf,
+    journal: Journal,
+}
+
+impl Sandbox {
+    /// Copy `project_root` into a fresh temp directory
+    pub(crate) fn create(project_root: &Path) -> io::Result<Self> {
+        let project_root = project_root.canonicalize()?;
+        let dir = std::env::temp_dir().join(format!(
+            "rigor-mutant-{}-{}",
+            std::process::id(),
+            SANDBOX_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+
+        let journal = Journal::new(&project_root);
+        journal.add_sandbox(&dir)?;
+        // From here on Drop cleans up the directory and the journal record
+        let sandbox = Self {
+            project_root,
+            dir,
+            journal,
+        };
+        sandbox.populate()?;
+        Ok(sandbox)
+    }
+
+    /// Directory of the copy
+    pub(crate) fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Project directory this sandbox mirrors
+    pub(crate) fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    /// Location of `path` (a file or directory in the real project) inside the sandbox
+    pub(crate) fn map(&self, path: &Path) -> Option<PathBuf> {
+        let canonical = path.canonicalize().ok()?;
+        let relative = canonical.strip_prefix(&self.project_root).ok()?;
+        Some(self.dir.join(relative))
+    }
+
+    /// Journal the original content, then write `mutated` to `path` (a sandbox path)
+    pub(crate) fn write_mutant(
+        &self,
+        path: &Path,
+        original: &str,
+        mutated: &str,
+    ) -> io::Result<()> {
+        self.journal.begin_mutation(&self.dir, path, original)?;
+        fs::write(path, mutated)
+    }
+
+    /// Put `original` back in `path` and clear the journal entry
+    pub(crate) fn restore(&self, path: &Path, original: &str) -> io::Result<()> {
+        fs::write(path, original)?;
+        self.journal.end_mutation(&self.dir)
+    }
+
+    fn populate(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let mut has_node_modules = false;
+
+        let mut entries = WalkDir::new(&self.project_root)
+            .follow_links(false)
+            .min_depth(1)
+            .into_iter();
+        while let Some(entry) = entries.next() {
+            let entry = entry.map_err(io::Error::other)?;
+            let name = entry.file_name().to_string_lossy();
+            let relative = entry
+                .path()
+                .strip_prefix(&self.project_root)
+                .map_err(io::Error::other)?;
+            // Only top-level entries: `src/coverage/` is source, `coverage/` is a report
+            if SKIPPED_ENTRIES
+                .iter()
+                .any(|skipped| relative == Path::new(skipped))
+            {
+                if entry.file_type().is_dir() {
+                    entries.skip_current_dir();
+                }
+                continue;
+            }
+
+            let target = self.dir.join(relative);
+
+            if entry.file_type().is_dir() && name == "node_modules" {
+                // Dependencies are read-only for tests; link instead of copying
+                link_dir(entry.path(), &target)?;
+                has_node_modules |= entry.depth() == 1;
+                entries.skip_current_dir();
+            } else if entry.file_type().is_dir() {
+                fs::create_dir_all(&target)?;
+            } else if entry.file_type().is_symlink() {
+                copy_symlink(entry.path(), &target)?;
+            } else {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+
+        // Workspace packages resolve hoisted dependencies from an ancestor directory,
+        // which the temp dir doesn't have
+        if !has_node_modules {
+            if let Some(hoisted) = self
+                .project_root
+                .ancestors()
+                .skip(1)
+                .map(|dir| dir.join("node_modules"))
+                .find(|dir| dir.is_dir())
+            {
+                link_dir(&hoisted, &self.dir.join("node_modules"))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        if fs::remove_dir_all(&self.dir).is_ok() || !self.dir.exists() {
+            let _ = self.journal.remove_sandbox(&self.dir);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn link_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(src, dst)
+}
+
+#[cfg(windows)]
+fn link_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_dir(src, dst)
+}
+
+#[cfg(unix)]
+fn copy_symlink(src: &Path, dst: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(src)?, dst)
+}
+
+#[cfg(windows)]
+fn copy_symlink(src: &Path, dst: &Path) -> io::Result<()> {
+    if src.is_dir() {
+        link_dir(&src.canonicalize()?, dst)
+    } else {
+        fs::copy(src, dst).map(|_| ())
+    }
+}
+
+/// Reusable sandboxes shared between parallel workers, one set per project root
+#[derive(Debug, Default)]
+pub(crate) struct SandboxPool {
+    idle: Mutex<HashMap<PathBuf, Vec<Sandbox>>>,
+}
+
+impl SandboxPool {
+    /// Take an idle sandbox for `project_root`, creating one if none is free
+    pub(crate) fn acquire(&self, project_root: &Path) -> io::Result<Sandbox> {
+        let key = project_root.canonicalize()?;
+        let reused = self
+            .idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_mut(&key)
+            .and_then(Vec::pop);
+        match reused {
+            Some(sandbox) => Ok(sandbox),
+            None => Sandbox::create(&key),
+        }
+    }
+
+    /// Return a sandbox for reuse (its files must already be restored)
+    pub(crate) fn release(&self, sandbox: Sandbox) {
+        self.idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(sandbox.project_root().to_path_buf())
+            .or_default()
+            .push(sandbox);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("package.json"), "{}").unwrap();
+        fs::create_dir_all(root.path().join("src")).unwrap();
+        fs::write(root.path().join("src/age.ts"), "return age >= 18;").unwrap();
+        fs::create_dir_all(root.path().join("node_modules/dep")).unwrap();
+        fs::write(root.path().join("node_modules/dep/index.js"), "").unwrap();
+        fs::create_dir_all(root.path().join(".git")).unwrap();
+        fs::create_dir_all(root.path().join("coverage")).unwrap();
+        fs::write(root.path().join("coverage/lcov.info"), "").unwrap();
+        fs::create_dir_all(root.path().join("src/coverage")).unwrap();
+        fs::write(root.path().join("src/coverage/parse.ts"), "").unwrap();
+        root
+    }
+
+    #[test]
+    fn test_sandbox_copies_project_without_touching_it() {
+        let root = project();
+        let sandbox = Sandbox::create(root.path()).unwrap();
+        let real = root.path().join("src/age.ts");
+        let copy = sandbox.map(&real).unwrap();
+
+        assert!(copy.starts_with(sandbox.dir()));
+        assert!(sandbox.dir().join("package.json").exists());
+        assert!(sandbox.dir().join("node_modules/dep/index.js").exists());
+        assert!(!sandbox.dir().join(".git").exists());
+        assert!(!sandbox.dir().join("coverage").exists());
+        assert!(sandbox.dir().join("src/coverage/parse.ts").exists());
+
+        sandbox
+            .write_mutant(&copy, "return age >= 18;", "return age > 18;")
+            .unwrap();
+        assert_eq!(fs::read_to_string(&real).unwrap(), "return age >= 18;");
+        assert!(root.path().join(JOURNAL_FILENAME).exists());
+
+        sandbox.restore(&copy, "return age >= 18;").unwrap();
+        assert_eq!(fs::read_to_string(&copy).unwrap(), "return age >= 18;");
+    }
+
+    #[test]
+    fn test_sandbox_drop_removes_dir_and_journal() {
+        let root = project();
+        let sandbox = Sandbox::create(root.path()).unwrap();
+        let dir = sandbox.dir().to_path_buf();
+        drop(sandbox);
+
+        assert!(!dir.exists());
+        assert!(!root.path().join(JOURNAL_FILENAME).exists());
+    }
+
+    #[test]
+    fn test_pool_reuses_released_sandbox() {
+        let root = project();
+        let pool = SandboxPool::default();
+        let first = pool.acquire(root.path()).unwrap();
+        let dir = first.dir().to_path_buf();
+        pool.release(first);
+
+        let second = pool.acquire(root.path()).unwrap();
+        assert_eq!(second.dir(), dir);
+        let third = pool.acquire(root.path()).unwrap();
+        assert_ne!(third.dir(), dir);
+    }
+}
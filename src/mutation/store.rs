@@ -0,0 +1,250 @@
+//! Persistent mutant outcomes for incremental mutation testing.
+//!
+//! A mutant is identified by the text of its enclosing function, its offset within it and
+//! the change it makes, so editing another function leaves its identity intact. Each
+//! outcome also records a hash of the test command and the test files it ran against; a
+//! stored outcome is reused only while both still match.
+
+use super::operators::Mutation;
+use super::MutantStatus;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub(crate) const STORE_FILENAME: &str = ".rigor-mutation-cache.json";
+const STORE_VERSION: u32 = 1;
+
+/// Outcome of one mutant in a previous run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct StoredRun {
+    pub status: MutantStatus,
+    pub duration_ms: u64,
+    pub tests_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoreData {
+    version: u32,
+    /// Source path (relative to the project root) -> mutant id -> outcome
+    files: HashMap<String, HashMap<String, StoredRun>>,
+}
+
+impl Default for StoreData {
+    fn default() -> Self {
+        Self {
+            version: STORE_VERSION,
+            files: HashMap::new(),
+        }
+    }
+}
+
+/// Stored outcomes for one project, kept in `.rigor-mutation-cache.json` at its root
+#[derive(Debug)]
+pub(crate) struct MutationStore {
+    project_root: PathBuf,
+    data: StoreData,
+    dirty: bool,
+}
+
+impl MutationStore {
+    /// Load the project's store; a missing, unreadable or outdated file starts empty
+    pub(crate) fn open(project_root: &Path) -> Self {
+        let data = fs::read_to_string(project_root.join(STORE_FILENAME))
+            .ok()
+            .and_then(|content| serde_json::from_str::<StoreData>(&content).ok())
+            .filter(|data| data.version == STORE_VERSION)
+            .unwrap_or_default();
+        Self {
+            project_root: project_root.to_path_buf(),
+            data,
+            dirty: false,
+        }
+    }
+
+    /// Prior outcome of `mutant_id` in `source_path`, if it ran against the same tests
+    pub(crate) fn lookup(
+        &self,
+        source_path: &Path,
+        mutant_id: &str,
+        tests_hash: &str,
+    ) -> Option<(MutantStatus, Duration)> {
+        let run = self
+            .data
+            .files
+            .get(&self.file_key(source_path))?
+            .get(mutant_id)?;
+        (run.tests_hash == tests_hash).then(|| (run.status, Duration::from_millis(run.duration_ms)))
+    }
+
+    /// Record new outcomes for `source_path` and drop mutants that no longer exist
+    pub(crate) fn update(
+        &mut self,
+        source_path: &Path,
+        live_ids: &HashSet<String>,
+        runs: impl IntoIterator<Item = (String, StoredRun)>,
+    ) {
+        let key = self.file_key(source_path);
+        let entries = self.data.files.entry(key).or_default();
+        entries.retain(|id, _| live_ids.contains(id));
+        entries.extend(runs);
+        self.dirty = true;
+    }
+
+    pub(crate) fn save(&self) -> std::io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let content = serde_json::to_string_pretty(&self.data).map_err(std::io::Error::other)?;
+        fs::write(self.project_root.join(STORE_FILENAME), content)
+    }
+
+    fn file_key(&self, source_path: &Path) -> String {
+        let canonical = source_path
+            .canonicalize()
+            .unwrap_or_else(|_| source_path.to_path_buf());
+        canonical
+            .strip_prefix(&self.project_root)
+            .unwrap_or(&canonical)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+/// Stores for every project touched by a run, opened on first use
+#[derive(Debug, Default)]
+pub(crate) struct StoreSet {
+    stores: Mutex<HashMap<PathBuf, MutationStore>>,
+}
+
+impl StoreSet {
+    pub(crate) fn with<R>(
+        &self,
+        project_root: &Path,
+        f: impl FnOnce(&mut MutationStore) -> R,
+    ) -> R {
+        let mut stores = self.stores.lock().unwrap_or_else(|e| e.into_inner());
+        let store = stores
+            .entry(project_root.to_path_buf())
+            .or_insert_with(|| MutationStore::open(project_root));
+        f(store)
+    }
+
+    /// Write every modified store; errors are ignored like analysis cache writes
+    pub(crate) fn save(&self) {
+        let stores = self.stores.lock().unwrap_or_else(|e| e.into_inner());
+        for store in stores.values() {
+            let _ = store.save();
+        }
+    }
+}
+
+/// Identity of `mutation` within `source`: stable while its enclosing function is unchanged
+pub(crate) fn mutant_id(source: &str, mutation: &Mutation) -> String {
+    let scope = &mutation.scope;
+    let mut hasher = Sha256::new();
+    hasher.update(source.get(scope.clone()).unwrap_or(source).as_bytes());
+    hasher.update(
+        format!(
+            "\0{}\0{}\0{:?}\0{}",
+            mutation.start.saturating_sub(scope.start),
+            mutation.end.saturating_sub(scope.start),
+            mutation.operator,
+            mutation.replacement
+        )
+        .as_bytes(),
+    );
+    hex::encode(hasher.finalize())
+}
+
+/// Hash of the test command and the content of the test files it runs. `None` when the
+/// tests are unknown (the whole suite runs), since then any file could affect the outcome.
+pub(crate) fn tests_hash(argv: &[String], test_files: &[PathBuf]) -> Option<String> {
+    if test_files.is_empty() {
+        return None;
+    }
+    let mut files: Vec<&PathBuf> = test_files.iter().collect();
+    files.sort();
+    let mut hasher = Sha256::new();
+    hasher.update(argv.join("\0").as_bytes());
+    for file in files {
+        hasher.update(b"\0");
+        hasher.update(fs::read(file).ok()?);
+    }
+    Some(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::operators::generate_mutations_for_path;
+
+    fn boundary_ids(source: &str) -> Vec<String> {
+        generate_mutations_for_path(Path::new("age.ts"), source)
+            .iter()
+            .filter(|m| m.description == ">= to >")
+            .map(|m| mutant_id(source, m))
+            .collect()
+    }
+
+    #[test]
+    fn test_mutant_id_survives_edits_to_other_functions() {
+        let before = "function a(x) { return x >= 1; }\nfunction b(y) { return y >= 2; }";
+        let after = "function a(x) { return x >= 1 && x < 9; }\nfunction b(y) { return y >= 2; }";
+        let (before, after) = (boundary_ids(before), boundary_ids(after));
+        assert_ne!(before[0], after[0], "a changed");
+        assert_eq!(before[1], after[1], "b unchanged");
+    }
+
+    #[test]
+    fn test_store_round_trip_and_prune() {
+        let root = tempfile::tempdir().unwrap();
+        let source = root.path().join("age.ts");
+        fs::write(&source, "").unwrap();
+        let root_path = root.path().canonicalize().unwrap();
+
+        let mut store = MutationStore::open(&root_path);
+        let live: HashSet<String> = ["m1".to_string()].into();
+        let run = |status| StoredRun {
+            status,
+            duration_ms: 120,
+            tests_hash: "t1".to_string(),
+        };
+        store.update(
+            &source,
+            &live,
+            [
+                ("m1".to_string(), run(MutantStatus::Killed)),
+                ("m2".to_string(), run(MutantStatus::Survived)),
+            ],
+        );
+        store.update(&source, &live, []);
+        store.save().unwrap();
+
+        let store = MutationStore::open(&root_path);
+        assert_eq!(
+            store.lookup(&source, "m1", "t1"),
+            Some((MutantStatus::Killed, Duration::from_millis(120)))
+        );
+        assert_eq!(store.lookup(&source, "m1", "t2"), None, "tests changed");
+        assert_eq!(store.lookup(&source, "m2", "t1"), None, "pruned");
+    }
+
+    #[test]
+    fn test_tests_hash_tracks_test_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let test = dir.path().join("age.test.ts");
+        let argv = vec!["npx".to_string(), "jest".to_string()];
+        fs::write(&test, "expect(isAdult(18)).toBe(true)").unwrap();
+        let first = tests_hash(&argv, std::slice::from_ref(&test)).unwrap();
+        fs::write(&test, "expect(isAdult(17)).toBe(false)").unwrap();
+        let second = tests_hash(&argv, std::slice::from_ref(&test)).unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(tests_hash(&argv, &[]), None);
+    }
+}
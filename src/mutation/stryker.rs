@@ -0,0 +1,555 @@
+//! Mutation results in the open mutation-testing-report-schema format (used by Stryker
+//! and the mutation-testing-elements viewer).
+//!
+//! Schema: https://github.com/stryker-mutator/mutation-testing-elements/tree/master/packages/report-schema
+//!
+//! Reports go both ways: rigor's own results can be exported, and a report from a full
+//! Stryker run can be imported in place of running mutants.
+
+use super::operators::{summarize, Mutation, MutationOperator};
+use super::{MutantStatus, MutationResult, MutationRun};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const SCHEMA_VERSION: &str = "2";
+
+/// Root of a mutation-testing-report-schema document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutationReport {
+    pub schema_version: String,
+    pub thresholds: Thresholds,
+    /// Source files keyed by path (relative to `project_root` when set)
+    pub files: BTreeMap<String, FileResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_root: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub framework: Option<FrameworkInfo>,
+}
+
+/// Score thresholds used by viewers for colouring
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thresholds {
+    pub high: u8,
+    pub low: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkInfo {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileResult {
+    pub language: String,
+    pub source: String,
+    pub mutants: Vec<MutantResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutantResult {
+    pub id: String,
+    pub mutator_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    pub location: Location,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Test run duration in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+}
+
+/// Source range; lines and columns are 1-based
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Location {
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl MutantStatus {
+    /// Status name in the report schema
+    pub fn schema_name(self) -> &'static str {
+        match self {
+            Self::Killed => "Killed",
+            Self::Survived => "Survived",
+            Self::Timeout => "Timeout",
+            Self::CompileError => "CompileError",
+            Self::RuntimeError => "RuntimeError",
+            Self::NoCoverage => "NoCoverage",
+        }
+    }
+
+    /// Parse a schema status; `Ignored` and `Pending` mutants have no outcome
+    pub fn from_schema_name(name: &str) -> Option<Self> {
+        match name {
+            "Killed" => Some(Self::Killed),
+            "Survived" => Some(Self::Survived),
+            "Timeout" => Some(Self::Timeout),
+            "CompileError" => Some(Self::CompileError),
+            "RuntimeError" => Some(Self::RuntimeError),
+            "NoCoverage" => Some(Self::NoCoverage),
+            _ => None,
+        }
+    }
+}
+
+/// Build a report for `results`. Paths are made relative to `project_root`; each source
+/// is read from disk so viewers can show it.
+pub fn build_report(results: &[MutationResult], project_root: &Path) -> MutationReport {
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    let mut files = BTreeMap::new();
+
+    for result in results {
+        let path = &result.source_path;
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let key = canonical
+            .strip_prefix(&root)
+            .unwrap_or(&canonical)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let source = std::fs::read_to_string(path).unwrap_or_default();
+        let mutants = result
+            .details
+            .iter()
+            .enumerate()
+            .map(|(i, run)| mutant_result(format!("{}:{}", key, i + 1), run, &source))
+            .collect();
+        let language = match path.extension().and_then(|e| e.to_str()) {
+            Some("js" | "jsx" | "mjs" | "cjs") => "javascript",
+            _ => "typescript",
+        };
+        files.insert(
+            key,
+            FileResult {
+                language: language.to_string(),
+                source,
+                mutants,
+            },
+        );
+    }
+
+    MutationReport {
+        schema_version: SCHEMA_VERSION.to_string(),
+        thresholds: Thresholds { high: 80, low: 60 },
+        files,
+        project_root: Some(root.to_string_lossy().into_owned()),
+        framework: Some(FrameworkInfo {
+            name: "rigor".to_string(),
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        }),
+    }
+}
+
+/// Report as pretty-printed JSON
+pub fn to_json(results: &[MutationResult], project_root: &Path) -> String {
+    serde_json::to_string_pretty(&build_report(results, project_root))
+        .unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Self-contained HTML viewer with the report embedded
+pub fn to_html(results: &[MutationResult], project_root: &Path) -> String {
+    let report = serde_json::to_string(&build_report(results, project_root))
+        .unwrap_or_else(|_| "{}".to_string());
+    // `<\/` is a valid JSON escape and keeps sources from closing the script block
+    let mut html = String::with_capacity(16_384 + report.len());
+    html.push_str(HTML_HEAD);
+    html.push_str("<script>const REPORT=");
+    html.push_str(&report.replace("</", "<\\/"));
+    html.push_str(";</script>\n");
+    html.push_str(HTML_SCRIPT);
+    html
+}
+
+/// Load a report (e.g. Stryker's `reports/mutation/mutation.json`) as mutation results.
+/// File paths resolve against the report's `projectRoot` when it exists, else `project_root`.
+pub fn load_results(path: &Path, project_root: &Path) -> io::Result<Vec<MutationResult>> {
+    let content = std::fs::read_to_string(path)?;
+    let report: MutationReport = serde_json::from_str(&content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(to_results(&report, project_root))
+}
+
+/// Convert a report into one result per source file
+pub fn to_results(report: &MutationReport, project_root: &Path) -> Vec<MutationResult> {
+    let root = report
+        .project_root
+        .as_deref()
+        .map(PathBuf::from)
+        .filter(|p| p.is_dir())
+        .unwrap_or_else(|| project_root.to_path_buf());
+    report
+        .files
+        .iter()
+        .map(|(key, file)| {
+            let runs = file
+                .mutants
+                .iter()
+                .filter_map(|m| imported_run(m, &file.source))
+                .collect();
+            MutationResult::from_runs(&root.join(key), runs)
+        })
+        .collect()
+}
+
+fn imported_run(mutant: &MutantResult, source: &str) -> Option<MutationRun> {
+    let status = MutantStatus::from_schema_name(&mutant.status)?;
+    let start = offset_at(source, mutant.location.start);
+    let end = offset_at(source, mutant.location.end).max(start);
+    let original = source[start..end].to_string();
+    let replacement = mutant.replacement.clone().unwrap_or_default();
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let operator = operator_for(&mutant.mutator_name, &original);
+    let description = if original.is_empty() {
+        mutant.mutator_name.clone()
+    } else {
+        format!("{} to {}", summarize(&original), summarize(&replacement))
+    };
+
+    Some(MutationRun {
+        mutation: Mutation {
+            start,
+            end,
+            line: mutant.location.start.line.max(1),
+            column: start - line_start + 1,
+            original,
+            replacement,
+            description,
+            operator,
+            scope: 0..source.len(),
+        },
+        status,
+        stdout: String::new(),
+        stderr: mutant.status_reason.clone().unwrap_or_default(),
+        duration: Duration::from_millis(mutant.duration.unwrap_or(0)),
+        cached: false,
+    })
+}
+
+/// Closest rigor operator for a Stryker mutator name
+fn operator_for(mutator_name: &str, original: &str) -> MutationOperator {
+    use MutationOperator::*;
+    match mutator_name {
+        "EqualityOperator" if matches!(original, "<" | "<=" | ">" | ">=") => Boundary,
+        "EqualityOperator" => Equality,
+        "ArithmeticOperator" => Arithmetic,
+        "ArithmeticOperand" => ArithmeticOperand,
+        "LogicalOperator" => Logical,
+        "BooleanLiteral" => Boolean,
+        "ConditionalExpression" => ConditionalNegation,
+        "UpdateOperator" | "AssignmentOperator" => Increment,
+        "StringLiteral" => String,
+        "ArrayDeclaration" | "ArrayIndex" => Array,
+        "ReturnValue" => Return,
+        "OptionalChaining" => OptionalChaining,
+        "NonNullAssertion" => NonNullAssertion,
+        "StatementRemoval" => CallStatement,
+        "Custom" => Custom,
+        _ => Other,
+    }
+}
+
+/// Byte offset of a 1-based line/column position, clamped to the source
+fn offset_at(source: &str, pos: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 1..pos.line {
+        match source[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return source.len(),
+        }
+    }
+    let line = source[line_start..].split('\n').next().unwrap_or("");
+    let column = line
+        .char_indices()
+        .nth(pos.column.saturating_sub(1))
+        .map_or(line.len(), |(i, _)| i);
+    line_start + column
+}
+
+fn mutant_result(id: String, run: &MutationRun, source: &str) -> MutantResult {
+    let m = &run.mutation;
+    // Fall back to the recorded start when the file changed since the run
+    let location = if source.get(m.start..m.end) == Some(m.original.as_str()) {
+        Location {
+            start: position_at(source, m.start),
+            end: position_at(source, m.end),
+        }
+    } else {
+        let start = Position {
+            line: m.line,
+            column: m.column,
+        };
+        Location { start, end: start }
+    };
+    let status_reason = match run.status {
+        MutantStatus::Timeout => Some(format!(
+            "Tests ran longer than the timeout ({:?})",
+            run.duration
+        )),
+        MutantStatus::CompileError | MutantStatus::RuntimeError => {
+            first_line(&run.stderr).or_else(|| first_line(&run.stdout))
+        }
+        _ => None,
+    };
+
+    MutantResult {
+        id,
+        mutator_name: m.mutator_name().to_string(),
+        replacement: Some(m.replacement.clone()),
+        location,
+        status: run.status.schema_name().to_string(),
+        status_reason,
+        description: Some(m.description.clone()),
+        duration: (!run.duration.is_zero()).then_some(run.duration.as_millis() as u64),
+    }
+}
+
+/// 1-based line and column (in characters) of byte offset `offset`
+fn position_at(source: &str, offset: usize) -> Position {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+fn first_line(output: &str) -> Option<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .map(str::to_string)
+}
+
+const HTML_HEAD: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="UTF-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Rigor – Mutation Report</title>
+<style>
+:root{--bg:#0d0d11;--surface:#16161b;--surface2:#1e1e24;--border:#2a2a32;--text:#e4e4e7;--muted:#71717a;--green:#22c55e;--yellow:#eab308;--orange:#f97316;--red:#ef4444;--blue:#3b82f6;--purple:#a855f7;--radius:8px}
+*{box-sizing:border-box;margin:0;padding:0}
+body{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',Roboto,Oxygen,sans-serif;background:var(--bg);color:var(--text);line-height:1.5;padding:24px}
+h1{font-size:1.25rem;margin-bottom:4px}
+.muted{color:var(--muted);font-size:.8125rem}
+.cards{display:flex;gap:12px;margin:16px 0;flex-wrap:wrap}
+.card{background:var(--surface);border:1px solid var(--border);border-radius:var(--radius);padding:12px 16px;min-width:120px}
+.card .v{font-size:1.5rem;font-weight:600}
+table{width:100%;border-collapse:collapse;background:var(--surface);border:1px solid var(--border);border-radius:var(--radius);overflow:hidden;margin-bottom:24px}
+th,td{text-align:left;padding:8px 12px;border-bottom:1px solid var(--border);font-size:.8125rem}
+th{color:var(--muted);font-weight:500}
+tbody tr{cursor:pointer}
+tbody tr:hover,tbody tr.active{background:var(--surface2)}
+.high{color:var(--green)}.mid{color:var(--yellow)}.low{color:var(--red)}
+pre{background:var(--surface);border:1px solid var(--border);border-radius:var(--radius);overflow:auto;font-family:'SF Mono',Consolas,monospace;font-size:.8125rem}
+.line{display:flex;white-space:pre}
+.line .no{color:var(--muted);min-width:48px;text-align:right;padding-right:12px;user-select:none}
+.line.undetected{background:rgba(239,68,68,.12)}
+.line.detected{background:rgba(34,197,94,.08)}
+.mutants{padding:2px 0 6px 60px;white-space:normal;font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif}
+.mutant{display:inline-block;margin:2px 6px 2px 0;padding:1px 8px;border-radius:4px;background:var(--surface2);font-size:.75rem}
+.s-Killed,.s-Timeout{color:var(--green)}.s-Survived,.s-NoCoverage{color:var(--red)}.s-CompileError,.s-RuntimeError{color:var(--muted)}
+</style>
+</head>
+<body>
+<h1>Mutation Report</h1>
+<div class="muted" id="meta"></div>
+<div class="cards" id="cards"></div>
+<table><thead><tr><th>File</th><th>Score</th><th>Killed</th><th>Survived</th><th>Timeout</th><th>No coverage</th><th>Errors</th></tr></thead><tbody id="files"></tbody></table>
+<div id="detail"></div>
+"##;
+
+const HTML_SCRIPT: &str = r##"<script>
+(function(){
+const esc=s=>String(s).replace(/[&<>"]/g,c=>({'&':'&amp;','<':'&lt;','>':'&gt;','"':'&quot;'}[c]));
+const DETECTED=['Killed','Timeout'],INVALID=['CompileError','RuntimeError'];
+function tally(mutants){
+  const t={Killed:0,Survived:0,Timeout:0,NoCoverage:0,CompileError:0,RuntimeError:0};
+  mutants.forEach(m=>{if(m.status in t)t[m.status]++});
+  const valid=mutants.length-t.CompileError-t.RuntimeError;
+  t.errors=t.CompileError+t.RuntimeError;
+  t.score=valid>0?(t.Killed+t.Timeout)*100/valid:100;
+  return t;
+}
+function cls(score){const th=REPORT.thresholds;return score>=th.high?'high':score>=th.low?'mid':'low'}
+const names=Object.keys(REPORT.files);
+const all=tally(names.flatMap(n=>REPORT.files[n].mutants));
+const fw=REPORT.framework;
+document.getElementById('meta').textContent=(fw?fw.name+(fw.version?' '+fw.version:'')+' · ':'')+'schema v'+REPORT.schemaVersion;
+document.getElementById('cards').innerHTML=[
+  ['Mutation score','<span class="'+cls(all.score)+'">'+all.score.toFixed(1)+'%</span>'],
+  ['Killed',all.Killed],['Survived',all.Survived],['Timeout',all.Timeout],['No coverage',all.NoCoverage],['Errors',all.errors]
+].map(([k,v])=>'<div class="card"><div class="muted">'+k+'</div><div class="v">'+v+'</div></div>').join('');
+const body=document.getElementById('files');
+body.innerHTML=names.map((n,i)=>{const t=tally(REPORT.files[n].mutants);
+  return '<tr data-i="'+i+'"><td>'+esc(n)+'</td><td class="'+cls(t.score)+'">'+t.score.toFixed(1)+'%</td><td>'+t.Killed+'</td><td>'+t.Survived+'</td><td>'+t.Timeout+'</td><td>'+t.NoCoverage+'</td><td>'+t.errors+'</td></tr>'}).join('');
+function show(i){
+  body.querySelectorAll('tr').forEach(r=>r.classList.toggle('active',r.dataset.i==i));
+  const name=names[i],file=REPORT.files[name],byLine={};
+  file.mutants.forEach(m=>(byLine[m.location.start.line]=byLine[m.location.start.line]||[]).push(m));
+  const lines=file.source.split('\n');
+  const html=lines.map((text,idx)=>{
+    const ms=byLine[idx+1]||[];
+    const state=ms.length?(ms.some(m=>!DETECTED.includes(m.status)&&!INVALID.includes(m.status))?' undetected':' detected'):'';
+    let out='<div class="line'+state+'"><span class="no">'+(idx+1)+'</span><span>'+esc(text)+'</span></div>';
+    if(ms.length)out+='<div class="mutants">'+ms.map(m=>'<span class="mutant" title="'+esc(m.statusReason||m.description||'')+'"><span class="s-'+esc(m.status)+'">'+esc(m.status)+'</span> '+esc(m.mutatorName)+(m.replacement!==undefined?': <code>'+esc(m.replacement)+'</code>':'')+'</span>').join('')+'</div>';
+    return out}).join('');
+  document.getElementById('detail').innerHTML='<h1>'+esc(name)+'</h1><pre>'+html+'</pre>';
+}
+body.addEventListener('click',e=>{const r=e.target.closest('tr');if(r)show(r.dataset.i)});
+if(names.length)show(0);
+})();
+</script>
+</body>
+</html>"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::operators::generate_mutations_for_path;
+    use std::time::Duration;
+
+    #[test]
+    fn test_report_follows_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_path = dir.path().join("src/age.ts");
+        std::fs::create_dir_all(source_path.parent().unwrap()).unwrap();
+        let source = "// ä\nexport const isAdult = (age: number) => age >= 18;\n";
+        std::fs::write(&source_path, source).unwrap();
+
+        let mutation = generate_mutations_for_path(&source_path, source)
+            .into_iter()
+            .find(|m| m.original == ">=")
+            .unwrap();
+        let run = MutationRun {
+            mutation,
+            status: MutantStatus::Survived,
+            stdout: String::new(),
+            stderr: String::new(),
+            duration: Duration::from_millis(250),
+            cached: false,
+        };
+        let result = MutationResult::from_runs(&source_path, vec![run]);
+
+        let json = to_json(&[result], dir.path());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schemaVersion"], "2");
+        let file = &value["files"]["src/age.ts"];
+        assert_eq!(file["language"], "typescript");
+        let mutant = &file["mutants"][0];
+        assert_eq!(mutant["mutatorName"], "EqualityOperator");
+        assert_eq!(mutant["status"], "Survived");
+        assert_eq!(mutant["replacement"], ">");
+        assert_eq!(mutant["duration"], 250);
+        assert_eq!(mutant["location"]["start"]["line"], 2);
+        assert_eq!(mutant["location"]["start"]["column"], 45);
+        assert_eq!(mutant["location"]["end"]["column"], 47);
+
+        let parsed: MutationReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.files["src/age.ts"].mutants.len(), 1);
+    }
+
+    #[test]
+    fn test_import_stryker_report() {
+        let json = r#"{
+            "schemaVersion": "1.0",
+            "thresholds": { "high": 80, "low": 60 },
+            "files": {
+                "src/age.ts": {
+                    "language": "typescript",
+                    "source": "export function isAdult(age) {\n  return age >= 18;\n}\n",
+                    "mutants": [
+                        { "id": "1", "mutatorName": "EqualityOperator", "replacement": "age > 18",
+                          "location": { "start": { "line": 2, "column": 10 }, "end": { "line": 2, "column": 19 } },
+                          "status": "Survived" },
+                        { "id": "2", "mutatorName": "BlockStatement", "replacement": "{}",
+                          "location": { "start": { "line": 1, "column": 30 }, "end": { "line": 3, "column": 2 } },
+                          "status": "Killed", "duration": 40 },
+                        { "id": "3", "mutatorName": "BooleanLiteral", "replacement": "true",
+                          "location": { "start": { "line": 2, "column": 3 }, "end": { "line": 2, "column": 9 } },
+                          "status": "Ignored" }
+                    ]
+                }
+            }
+        }"#;
+        let report: MutationReport = serde_json::from_str(json).unwrap();
+        let results = to_results(&report, Path::new("/project"));
+
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result.source_path, Path::new("/project/src/age.ts"));
+        assert_eq!((result.total, result.killed, result.survived), (2, 1, 1));
+
+        let survivor = &result.details[0].mutation;
+        assert_eq!(survivor.original, "age >= 18");
+        assert_eq!(survivor.description, "age >= 18 to age > 18");
+        assert_eq!(survivor.operator, MutationOperator::Equality);
+        assert_eq!((survivor.line, survivor.column), (2, 10));
+
+        let block = &result.details[1];
+        assert_eq!(block.mutation.operator, MutationOperator::Other);
+        assert_eq!(block.mutation.description, "{… to {}");
+        assert_eq!(block.duration, Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_path = dir.path().join("age.ts");
+        let source = "export const isAdult = (age: number) => age >= 18;\n";
+        std::fs::write(&source_path, source).unwrap();
+        let runs = generate_mutations_for_path(&source_path, source)
+            .into_iter()
+            .map(|mutation| MutationRun {
+                mutation,
+                status: MutantStatus::Killed,
+                stdout: String::new(),
+                stderr: String::new(),
+                duration: Duration::ZERO,
+                cached: false,
+            })
+            .collect();
+        let exported = MutationResult::from_runs(&source_path, runs);
+
+        let report = build_report(std::slice::from_ref(&exported), dir.path());
+        let imported = to_results(&report, dir.path());
+        let pairs = exported.details.iter().zip(&imported[0].details);
+        for (before, after) in pairs {
+            assert_eq!(before.mutation.start, after.mutation.start);
+            assert_eq!(before.mutation.original, after.mutation.original);
+            assert_eq!(before.mutation.operator, after.mutation.operator);
+        }
+        assert_eq!(imported[0].total, exported.total);
+    }
+
+    #[test]
+    fn test_html_embeds_report_safely() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_path = dir.path().join("view.ts");
+        std::fs::write(&source_path, "const s = \"</script>\";\n").unwrap();
+        let result = MutationResult::from_runs(&source_path, Vec::new());
+
+        let html = to_html(&[result], dir.path());
+        assert!(html.contains("const REPORT="));
+        assert_eq!(html.matches("</script>").count(), 2);
+    }
+}
@@ -107,34 +107,7 @@ fn parse_rule_list(s: &str) -> HashSet<Rule> {
 }
 
 fn rule_from_id(id: &str) -> Option<Rule> {
-    match id {
-        "weak-assertion" => Some(Rule::WeakAssertion),
-        "missing-error-test" => Some(Rule::MissingErrorTest),
-        "missing-boundary-test" => Some(Rule::MissingBoundaryTest),
-        "shared-state" => Some(Rule::SharedState),
-        "hardcoded-values" => Some(Rule::HardcodedValues),
-        "no-assertions" => Some(Rule::NoAssertions),
-        "skipped-test" => Some(Rule::SkippedTest),
-        "empty-test" => Some(Rule::EmptyTest),
-        "duplicate-test" => Some(Rule::DuplicateTest),
-        "limited-input-variety" => Some(Rule::LimitedInputVariety),
-        "debug-code" => Some(Rule::DebugCode),
-        "focused-test" => Some(Rule::FocusedTest),
-        "flaky-pattern" => Some(Rule::FlakyPattern),
-        "mock-abuse" => Some(Rule::MockAbuse),
-        "snapshot-overuse" => Some(Rule::SnapshotOveruse),
-        "vague-test-name" => Some(Rule::VagueTestName),
-        "missing-await" => Some(Rule::MissingAwait),
-        "rtl-prefer-screen" => Some(Rule::RtlPreferScreen),
-        "rtl-prefer-semantic" => Some(Rule::RtlPreferSemantic),
-        "rtl-prefer-user-event" => Some(Rule::RtlPreferUserEvent),
-        "mutation-resistant" => Some(Rule::MutationResistant),
-        "boundary-specificity" => Some(Rule::BoundarySpecificity),
-        "state-verification" => Some(Rule::StateVerification),
-        "assertion-intent-mismatch" => Some(Rule::AssertionIntentMismatch),
-        "trivial-assertion" => Some(Rule::TrivialAssertion),
-        _ => None,
-    }
+    crate::registry::rule_for_id(id)
 }
 
 #[cfg(test)]
@@ -18,6 +18,8 @@ use tree_sitter::{Language, Query, QueryCursor, StreamingIterator, Tree};
 /// Cache of compiled queries per query_id. Compile once per language, reuse per file.
 pub struct QueryCache {
     ts: Mutex<HashMap<QueryId, Query>>,
+    /// Queries given as source text (`customRules`), keyed by language name and query
+    custom: Mutex<HashMap<(Option<&'static str>, String), Query>>,
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
@@ -67,6 +69,7 @@ impl QueryCache {
     pub fn new() -> Self {
         Self {
             ts: Mutex::new(HashMap::new()),
+            custom: Mutex::new(HashMap::new()),
         }
     }
 
@@ -198,33 +201,54 @@ impl QueryCache {
             e.insert(q);
         }
         let query = guard.get(&query_id).unwrap();
-        let mut cursor = QueryCursor::new();
-        let mut results = Vec::new();
-        let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
-        while let Some(qm) = matches.next() {
-            let mut caps = Vec::new();
-            for cap in qm.captures {
-                let node = cap.node;
-                let start = node.start_byte();
-                let end = node.end_byte();
-                let s = node.start_position();
-                let e = node.end_position();
-                let text = source.get(start..end).unwrap_or("").to_string();
-                caps.push(QueryCaptureInfo {
-                    name: query.capture_names()[cap.index as usize].to_string(),
-                    start_byte: start,
-                    end_byte: end,
-                    start_point: (s.row + 1, s.column + 1),
-                    end_point: (e.row + 1, e.column + 1),
-                    text,
-                });
-            }
-            results.push(caps);
+        Ok(collect_matches(query, source, tree))
+    }
+
+    /// Run a query given as S-expression text (e.g. a `customRules` entry) in the tree's
+    /// own language. Predicates such as `#eq?` and `#match?` are applied.
+    pub fn run_query(
+        &self,
+        source: &str,
+        tree: &Tree,
+        query: &str,
+    ) -> Result<Vec<Vec<QueryCaptureInfo>>, tree_sitter::QueryError> {
+        let lang = tree.language();
+        let key = (lang.name(), query.to_string());
+        let mut guard = self.custom.lock().expect("query cache lock");
+        if let std::collections::hash_map::Entry::Vacant(e) = guard.entry(key.clone()) {
+            e.insert(Query::new(&lang, query)?);
         }
-        Ok(results)
+        Ok(collect_matches(&guard[&key], source, tree))
     }
 }
 
+fn collect_matches(query: &Query, source: &str, tree: &Tree) -> Vec<Vec<QueryCaptureInfo>> {
+    let mut cursor = QueryCursor::new();
+    let mut results = Vec::new();
+    let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+    while let Some(qm) = matches.next() {
+        let mut caps = Vec::new();
+        for cap in qm.captures {
+            let node = cap.node;
+            let start = node.start_byte();
+            let end = node.end_byte();
+            let s = node.start_position();
+            let e = node.end_position();
+            let text = source.get(start..end).unwrap_or("").to_string();
+            caps.push(QueryCaptureInfo {
+                name: query.capture_names()[cap.index as usize].to_string(),
+                start_byte: start,
+                end_byte: end,
+                start_point: (s.row + 1, s.column + 1),
+                end_point: (e.row + 1, e.column + 1),
+                text,
+            });
+        }
+        results.push(caps);
+    }
+    results
+}
+
 impl Default for QueryCache {
     fn default() -> Self {
         Self::new()
@@ -274,6 +298,19 @@ mod tests {
         assert!(!results.is_empty());
     }
 
+    #[test]
+    fn custom_query_applies_predicates() {
+        let cache = QueryCache::new();
+        let mut parser = TypeScriptParser::new().unwrap();
+        let source = "resetDatabase(); seed();";
+        let tree = parser.parse(source).unwrap();
+        let query = r#"(call_expression function: (identifier) @fn (#eq? @fn "resetDatabase"))"#;
+        let results = cache.run_query(source, &tree, query).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0][0].text, "resetDatabase");
+        assert!(cache.run_query(source, &tree, "(not_a_node) @x").is_err());
+    }
+
     #[test]
     fn cache_compiles_focused_test_query() {
         let cache = QueryCache::new();
@@ -0,0 +1,251 @@
+//! wasmtime host: compiles plugin modules once per process and runs each file in a fresh
+//! store with a memory limit and an epoch deadline.
+
+use super::{PluginIssue, DEFAULT_MEMORY_LIMIT_MB, DEFAULT_TIMEOUT_MS};
+use crate::config::PluginConfig;
+use crate::Severity;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+use wasmtime::{
+    Caller, Config, Engine, Extern, Linker, Module, Store, StoreLimits, StoreLimitsBuilder,
+};
+
+/// Epoch tick; time limits are rounded up to a whole number of ticks
+const TICK: Duration = Duration::from_millis(10);
+/// Issues kept per plugin and file; later reports are dropped, so a runaway plugin can't
+/// flood the report (or host memory)
+const MAX_ISSUES: usize = 1000;
+/// Longest rule id or message kept from a plugin, in bytes; the rest is dropped
+const MAX_STRING_BYTES: usize = 4096;
+
+struct State {
+    limits: StoreLimits,
+    issues: Vec<PluginIssue>,
+}
+
+fn engine() -> &'static Engine {
+    static ENGINE: OnceLock<Engine> = OnceLock::new();
+    ENGINE.get_or_init(|| {
+        let mut config = Config::new();
+        config.epoch_interruption(true);
+        let engine = Engine::new(&config).expect("wasmtime engine");
+        let ticker = engine.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(TICK);
+            ticker.increment_epoch();
+        });
+        engine
+    })
+}
+
+/// Compiled module for `plugin`, recompiled when the file changes
+pub(super) fn module(plugin: &PluginConfig) -> Result<Module> {
+    static MODULES: OnceLock<Mutex<HashMap<PathBuf, (SystemTime, Module)>>> = OnceLock::new();
+    let path = PathBuf::from(&plugin.path);
+    let modified = std::fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .with_context(|| format!("Plugin not found: {}", path.display()))?;
+    let mut modules = MODULES
+        .get_or_init(Default::default)
+        .lock()
+        .expect("plugin module lock");
+    if let Some((at, module)) = modules.get(&path) {
+        if *at == modified {
+            return Ok(module.clone());
+        }
+    }
+    let module = Module::from_file(engine(), &path)
+        .map_err(|e| anyhow::anyhow!("Invalid plugin {}: {}", path.display(), e))?;
+    modules.insert(path, (modified, module.clone()));
+    Ok(module)
+}
+
+/// Run `plugin` on one serialized [`PluginInput`](super::PluginInput)
+pub(super) fn run(plugin: &PluginConfig, input: &[u8]) -> Result<Vec<PluginIssue>> {
+    let module = module(plugin)?;
+    let memory_mb = plugin.memory_limit_mb.unwrap_or(DEFAULT_MEMORY_LIMIT_MB) as usize;
+    let timeout = Duration::from_millis(plugin.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+
+    let mut store = Store::new(
+        engine(),
+        State {
+            limits: StoreLimitsBuilder::new()
+                .memory_size(memory_mb << 20)
+                .instances(1)
+                .build(),
+            issues: Vec::new(),
+        },
+    );
+    store.limiter(|state| &mut state.limits);
+    store.set_epoch_deadline(timeout.as_millis().div_ceil(TICK.as_millis()).max(1) as u64);
+
+    let mut linker: Linker<State> = Linker::new(engine());
+    linker
+        .func_wrap(
+            "rigor",
+            "report_issue",
+            |mut caller: Caller<'_, State>,
+             rule_ptr: i32,
+             rule_len: i32,
+             message_ptr: i32,
+             message_len: i32,
+             line: i32,
+             column: i32,
+             severity: i32| {
+                if caller.data().issues.len() >= MAX_ISSUES {
+                    return Ok(());
+                }
+                let rule = read_string(&mut caller, rule_ptr, rule_len)?;
+                let message = read_string(&mut caller, message_ptr, message_len)?;
+                caller.data_mut().issues.push(PluginIssue {
+                    rule,
+                    message,
+                    line: line.max(1) as usize,
+                    column: column.max(1) as usize,
+                    severity: match severity {
+                        0 => Severity::Error,
+                        2 => Severity::Info,
+                        _ => Severity::Warning,
+                    },
+                });
+                Ok(())
+            },
+        )
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let outcome = (|| -> wasmtime::Result<i32> {
+        let instance = linker.instantiate(&mut store, &module)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("plugin does not export `memory`"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "rigor_alloc")?;
+        let analyze = instance.get_typed_func::<(i32, i32), i32>(&mut store, "rigor_analyze")?;
+        let len = i32::try_from(input.len())
+            .map_err(|_| wasmtime::Error::msg("input too large for a 32-bit plugin"))?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, ptr as usize, input)?;
+        analyze.call(&mut store, (ptr, len))
+    })();
+
+    match outcome {
+        Ok(0) => Ok(std::mem::take(&mut store.data_mut().issues)),
+        Ok(status) => anyhow::bail!("rigor_analyze returned {}", status),
+        Err(e) if e.downcast_ref::<wasmtime::Trap>() == Some(&wasmtime::Trap::Interrupt) => {
+            anyhow::bail!("timed out after {} ms", timeout.as_millis())
+        }
+        Err(e) => anyhow::bail!("{}", e),
+    }
+}
+
+fn read_string(caller: &mut Caller<'_, State>, ptr: i32, len: i32) -> wasmtime::Result<String> {
+    let Some(Extern::Memory(memory)) = caller.get_export("memory") else {
+        return Err(wasmtime::Error::msg("plugin does not export `memory`"));
+    };
+    let start = ptr as u32 as usize;
+    let len = (len.max(0) as usize).min(MAX_STRING_BYTES);
+    let bytes = memory
+        .data(&caller)
+        .get(start..start + len)
+        .ok_or_else(|| wasmtime::Error::msg("report_issue: string out of bounds"))?;
+    Ok(String::from_utf8_lossy(bytes).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin_from_wat(wat: &str) -> (tempfile::TempDir, PluginConfig) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("test-plugin.wat");
+        std::fs::write(&path, wat).unwrap();
+        let plugin = PluginConfig {
+            path: path.to_string_lossy().to_string(),
+            name: None,
+            memory_limit_mb: Some(1),
+            timeout_ms: Some(50),
+        };
+        (dir, plugin)
+    }
+
+    const PRELUDE: &str = r#"
+        (import "rigor" "report_issue" (func $report (param i32 i32 i32 i32 i32 i32 i32)))
+        (memory (export "memory") 1)
+        (func (export "rigor_alloc") (param i32) (result i32) (i32.const 1024))
+    "#;
+
+    #[test]
+    fn test_reports_issues() {
+        let (_dir, plugin) = plugin_from_wat(&format!(
+            r#"(module {}
+                (data (i32.const 0) "ruleboom")
+                (func (export "rigor_analyze") (param i32 i32) (result i32)
+                  (call $report (i32.const 0) (i32.const 4) (i32.const 4) (i32.const 4)
+                                (i32.const 3) (i32.const 5) (i32.const 0))
+                  (i32.const 0)))"#,
+            PRELUDE
+        ));
+        let issues = run(&plugin, b"{}").unwrap();
+        assert_eq!(
+            issues,
+            vec![PluginIssue {
+                rule: "rule".to_string(),
+                message: "boom".to_string(),
+                line: 3,
+                column: 5,
+                severity: Severity::Error,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_issue_count_and_length_limits() {
+        let (_dir, plugin) = plugin_from_wat(&format!(
+            r#"(module {}
+                (func (export "rigor_analyze") (param i32 i32) (result i32)
+                  (local $i i32)
+                  (loop $more
+                    (call $report (i32.const 0) (i32.const 4) (i32.const 0) (i32.const 60000)
+                                  (i32.const 1) (i32.const 1) (i32.const 1))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br_if $more (i32.lt_u (local.get $i) (i32.const 5000))))
+                  (i32.const 0)))"#,
+            PRELUDE
+        ));
+        let plugin = PluginConfig {
+            timeout_ms: Some(10_000),
+            ..plugin
+        };
+        let issues = run(&plugin, b"{}").unwrap();
+        assert_eq!(issues.len(), MAX_ISSUES);
+        assert_eq!(issues[0].message.len(), MAX_STRING_BYTES);
+    }
+
+    #[test]
+    fn test_time_limit() {
+        let (_dir, plugin) = plugin_from_wat(&format!(
+            r#"(module {}
+                (func (export "rigor_analyze") (param i32 i32) (result i32)
+                  (loop $spin (br $spin))
+                  (i32.const 0)))"#,
+            PRELUDE
+        ));
+        let err = run(&plugin, b"{}").unwrap_err().to_string();
+        assert!(err.contains("timed out after 50 ms"), "{}", err);
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let (_dir, plugin) = plugin_from_wat(&format!(
+            r#"(module {}
+                (func (export "rigor_analyze") (param i32 i32) (result i32)
+                  (if (i32.eq (memory.grow (i32.const 64)) (i32.const -1))
+                    (then (unreachable)))
+                  (i32.const 0)))"#,
+            PRELUDE
+        ));
+        assert!(run(&plugin, b"{}").is_err());
+    }
+}
@@ -0,0 +1,365 @@
+//! Third-party rules as sandboxed WASM plugins (`plugins` in config).
+//!
+//! Each plugin gets a JSON view of the test file ([`PluginInput`]): the extracted tests and
+//! assertions, a simplified AST, the framework and the mapped source file's exports. It
+//! reports issues through a host import; their rule ids are namespaced as
+//! `plugin-name/rule`. Plugins have no access to the filesystem, network or clock, and run
+//! under a memory and a time limit.
+//!
+//! ## ABI
+//!
+//! A plugin module exports:
+//! - `memory`
+//! - `rigor_alloc(len: i32) -> i32`: reserve `len` bytes for the input and return a pointer
+//! - `rigor_analyze(ptr: i32, len: i32) -> i32`: analyze the UTF-8 JSON input at `ptr`;
+//!   return 0 on success
+//!
+//! and may import `rigor.report_issue(rule_ptr, rule_len, message_ptr, message_len, line,
+//! column, severity)` (all `i32`; severity 0 = error, 1 = warning, 2 = info).
+//!
+//! The runtime is behind the `plugins` cargo feature; without it, configured plugins are
+//! reported as unavailable.
+
+#[cfg(feature = "plugins")]
+mod host;
+
+use crate::analyzer::rules::AnalysisRule;
+use crate::config::PluginConfig;
+use crate::{registry, Issue, Location, Severity, TestCase, TestFramework};
+use serde::Serialize;
+use tree_sitter::{Node, Tree};
+
+/// Whether this build can run plugins
+pub const ENABLED: bool = cfg!(feature = "plugins");
+
+/// Default memory limit per plugin instance
+pub const DEFAULT_MEMORY_LIMIT_MB: u32 = 64;
+/// Default time limit per file
+pub const DEFAULT_TIMEOUT_MS: u64 = 2000;
+
+/// What a plugin sees of one test file
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginInput {
+    pub file: String,
+    pub framework: TestFramework,
+    pub tests: Vec<PluginTest>,
+    /// Exported names of the mapped source file (empty without source analysis)
+    pub source_exports: Vec<String>,
+    pub ast: AstNode,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginTest {
+    pub name: String,
+    pub line: usize,
+    pub end_line: Option<usize>,
+    pub is_async: bool,
+    pub is_skipped: bool,
+    pub describe_block: Option<String>,
+    pub assertions: Vec<PluginAssertion>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginAssertion {
+    /// e.g. `ToBe`, `ToBeTruthy`
+    pub kind: String,
+    /// `Strong`, `Moderate`, `Weak` or `None`
+    pub quality: String,
+    pub line: usize,
+    pub column: usize,
+    pub raw: String,
+}
+
+/// Named tree-sitter node. Leaves carry their source text.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AstNode {
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<AstNode>,
+}
+
+impl AstNode {
+    pub fn from_node(node: Node, source: &str) -> Self {
+        let mut cursor = node.walk();
+        let children: Vec<AstNode> = node
+            .named_children(&mut cursor)
+            .map(|child| AstNode::from_node(child, source))
+            .collect();
+        let start = node.start_position();
+        let end = node.end_position();
+        Self {
+            kind: node.kind(),
+            text: children
+                .is_empty()
+                .then(|| source.get(node.byte_range()).unwrap_or("").to_string()),
+            line: start.row + 1,
+            column: start.column + 1,
+            end_line: end.row + 1,
+            end_column: end.column + 1,
+            children,
+        }
+    }
+}
+
+impl PluginInput {
+    pub fn new(
+        file: String,
+        framework: TestFramework,
+        tests: &[TestCase],
+        source_exports: Vec<String>,
+        source: &str,
+        tree: &Tree,
+    ) -> Self {
+        let tests = tests
+            .iter()
+            .map(|t| PluginTest {
+                name: t.name.clone(),
+                line: t.location.line,
+                end_line: t.location.end_line,
+                is_async: t.is_async,
+                is_skipped: t.is_skipped,
+                describe_block: t.describe_block.clone(),
+                assertions: t
+                    .assertions
+                    .iter()
+                    .map(|a| PluginAssertion {
+                        kind: format!("{:?}", a.kind),
+                        quality: format!("{:?}", a.quality),
+                        line: a.location.line,
+                        column: a.location.column,
+                        raw: a.raw.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            file,
+            framework,
+            tests,
+            source_exports,
+            ast: AstNode::from_node(tree.root_node(), source),
+        }
+    }
+}
+
+/// An issue reported by a plugin, before namespacing
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginIssue {
+    pub rule: String,
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    pub severity: Severity,
+}
+
+/// Name a plugin's rules are namespaced under: `name`, or the file stem of `path`
+pub fn plugin_name(plugin: &PluginConfig) -> String {
+    plugin.name.clone().unwrap_or_else(|| {
+        std::path::Path::new(&plugin.path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| plugin.path.clone())
+    })
+}
+
+/// Compile every configured plugin, so a missing or invalid module fails up front
+pub fn check(plugins: &[PluginConfig]) -> anyhow::Result<()> {
+    #[cfg(feature = "plugins")]
+    for plugin in plugins {
+        host::module(plugin)?;
+    }
+    #[cfg(not(feature = "plugins"))]
+    if !plugins.is_empty() {
+        anyhow::bail!("config lists plugins but rigor was built without the `plugins` feature");
+    }
+    Ok(())
+}
+
+/// Runs the configured plugins on a test file
+pub struct PluginsRule<'a> {
+    plugins: &'a [PluginConfig],
+    file: String,
+    framework: TestFramework,
+    source_exports: Vec<String>,
+}
+
+impl<'a> PluginsRule<'a> {
+    pub fn new(plugins: &'a [PluginConfig], file: String, framework: TestFramework) -> Self {
+        Self {
+            plugins,
+            file,
+            framework,
+            source_exports: Vec::new(),
+        }
+    }
+
+    pub fn with_source_exports(mut self, exports: Vec<String>) -> Self {
+        self.source_exports = exports;
+        self
+    }
+}
+
+impl AnalysisRule for PluginsRule<'_> {
+    fn name(&self) -> &'static str {
+        "plugins"
+    }
+
+    fn analyze(&self, tests: &[TestCase], source: &str, tree: &Tree) -> Vec<Issue> {
+        if self.plugins.is_empty() {
+            return Vec::new();
+        }
+        let input = PluginInput::new(
+            self.file.clone(),
+            self.framework,
+            tests,
+            self.source_exports.clone(),
+            source,
+            tree,
+        );
+        let Ok(input) = serde_json::to_vec(&input) else {
+            return Vec::new();
+        };
+
+        let mut issues = Vec::new();
+        for plugin in self.plugins {
+            let name = plugin_name(plugin);
+            match run(plugin, &input) {
+                Ok(reported) => issues.extend(reported.into_iter().map(|issue| {
+                    let id = format!("{}/{}", name, issue.rule);
+                    // One stable description per rule; the per-issue text stays in the message
+                    let description = format!("Reported by plugin {}", name);
+                    Issue {
+                        rule: registry::register_custom(&id, None, &description),
+                        severity: issue.severity,
+                        message: issue.message,
+                        location: Location::new(issue.line.max(1), issue.column.max(1)),
+                        suggestion: None,
+                        fix: None,
+                    }
+                })),
+                Err(e) => {
+                    let id = format!("{}/plugin-error", name);
+                    issues.push(Issue {
+                        rule: registry::register_custom(&id, None, "The plugin failed"),
+                        severity: Severity::Info,
+                        message: format!("Plugin '{}' failed: {:#}", name, e),
+                        location: Location::new(1, 1),
+                        suggestion: Some(format!(
+                            "Check the plugin, raise its limits, or set \"{}\": \"off\"",
+                            id
+                        )),
+                        fix: None,
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    fn calculate_score(&self, _tests: &[TestCase], _issues: &[Issue]) -> u8 {
+        25
+    }
+}
+
+#[cfg(feature = "plugins")]
+fn run(plugin: &PluginConfig, input: &[u8]) -> anyhow::Result<Vec<PluginIssue>> {
+    host::run(plugin, input)
+}
+
+#[cfg(not(feature = "plugins"))]
+fn run(_plugin: &PluginConfig, _input: &[u8]) -> anyhow::Result<Vec<PluginIssue>> {
+    anyhow::bail!("rigor was built without the `plugins` feature")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{TestFileParser, TypeScriptParser};
+
+    #[test]
+    fn test_input_view() {
+        let source =
+            "it('waits', async () => {\n  await sleep(10);\n  expect(done).toBe(true);\n});\n";
+        let mut parser = TypeScriptParser::new().unwrap();
+        let tree = parser.parse(source).unwrap();
+        let tests = TestFileParser::new(source).extract_tests(&tree);
+        let input = PluginInput::new(
+            "a.test.ts".to_string(),
+            TestFramework::Jest,
+            &tests,
+            vec!["sleep".to_string()],
+            source,
+            &tree,
+        );
+        let json = serde_json::to_string(&input).unwrap();
+
+        assert!(json.contains(r#""file":"a.test.ts","framework":"jest""#));
+        assert!(json.contains(r#""kind":"ToBe","quality":"Strong","line":3"#));
+        assert!(json.contains(r#""sourceExports":["sleep"]"#));
+        // Leaves carry their text ahead of their position
+        assert!(json.contains(r#"{"kind":"identifier","text":"sleep","line":2,"column":9"#));
+    }
+
+    #[test]
+    fn test_plugin_name_defaults_to_file_stem() {
+        let plugin: PluginConfig = serde_json::from_str(r#""plugins/no-sleep.wasm""#).unwrap();
+        assert_eq!(plugin_name(&plugin), "no-sleep");
+        let plugin: PluginConfig =
+            serde_json::from_str(r#"{ "path": "x.wasm", "name": "house" }"#).unwrap();
+        assert_eq!(plugin_name(&plugin), "house");
+    }
+
+    #[cfg(feature = "plugins")]
+    #[test]
+    fn test_plugin_rules_keep_a_stable_description() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("stable.wat");
+        // Reports rule `rule` twice, with messages "boom" and "bang"
+        std::fs::write(
+            &path,
+            r#"(module
+                (import "rigor" "report_issue" (func $report (param i32 i32 i32 i32 i32 i32 i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "ruleboombang")
+                (func (export "rigor_alloc") (param i32) (result i32) (i32.const 1024))
+                (func (export "rigor_analyze") (param i32 i32) (result i32)
+                  (call $report (i32.const 0) (i32.const 4) (i32.const 4) (i32.const 4)
+                                (i32.const 1) (i32.const 1) (i32.const 1))
+                  (call $report (i32.const 0) (i32.const 4) (i32.const 8) (i32.const 4)
+                                (i32.const 2) (i32.const 1) (i32.const 1))
+                  (i32.const 0)))"#,
+        )
+        .unwrap();
+        let plugins = vec![PluginConfig {
+            path: path.to_string_lossy().to_string(),
+            name: Some("plugin-test-stable".to_string()),
+            memory_limit_mb: None,
+            timeout_ms: None,
+        }];
+        let source = "it('works', () => {});\n";
+        let tree = TypeScriptParser::new().unwrap().parse(source).unwrap();
+
+        let issues = PluginsRule::new(&plugins, "a.test.ts".to_string(), TestFramework::Jest)
+            .analyze(&[], source, &tree);
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(messages, vec!["boom", "bang"]);
+        let crate::Rule::Custom(id) = issues[1].rule else {
+            panic!("expected a custom rule, got {:?}", issues[1].rule);
+        };
+        assert_eq!(id.as_str(), "plugin-test-stable/rule");
+        assert_eq!(
+            registry::custom(id).unwrap().description,
+            "Reported by plugin plugin-test-stable"
+        );
+    }
+}
@@ -0,0 +1,925 @@
+//! Rule registry: one entry per [`Rule`] with its id, scoring category, default severity,
+//! fixability, inputs and documentation.
+//!
+//! `Display` for `Rule`, [`crate::rule_scoring_category`], ignore directives, the engine,
+//! the SARIF rule table, MCP `explain_rule` and `rigor rules` all read from [`RULES`].
+//! Custom rules from config are registered at runtime (see [`register_custom`]).
+
+use crate::{Rule, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock, RwLock};
+
+/// Input beyond the test file that a rule reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Input {
+    /// The mapped source file (see source mapping)
+    Source,
+    /// A coverage report (`--coverage`)
+    Coverage,
+    /// The detected test framework or testing library
+    Framework,
+    /// Mutation results (`--mutate`, `--mutation-report`)
+    MutationResults,
+}
+
+impl Input {
+    pub fn id(self) -> &'static str {
+        match self {
+            Input::Source => "source",
+            Input::Coverage => "coverage",
+            Input::Framework => "framework",
+            Input::MutationResults => "mutation-results",
+        }
+    }
+}
+
+/// How complete a rule's detection is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Maturity {
+    Stable,
+    /// Detects some cases; penalty-only
+    Partial,
+    /// Minimal detection; penalty-only
+    Stub,
+}
+
+/// Metadata for one rule
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleInfo {
+    #[serde(skip)]
+    pub rule: Rule,
+    /// Kebab-case id used in config, ignore directives and output
+    pub id: &'static str,
+    /// Section of the rules reference the rule is listed under
+    pub section: &'static str,
+    /// Scoring category the rule reduces; `None` for penalty-only rules
+    pub category: Option<&'static str>,
+    /// Severity issues are reported with unless configured otherwise
+    pub default_severity: Severity,
+    /// Whether `--fix` can fix its issues
+    pub fixable: bool,
+    pub maturity: Maturity,
+    /// Inputs the rule reads; without them it finds less (or, for mutation rules, nothing)
+    pub inputs: &'static [Input],
+    /// Name of the [`AnalysisRule`](crate::analyzer::rules::AnalysisRule) that reports it
+    #[serde(skip)]
+    pub analyzer: &'static str,
+    pub description: &'static str,
+    pub example_bad: &'static str,
+    pub example_good: &'static str,
+}
+
+const ASSERTION_QUALITY: Option<&str> = Some("Assertion Quality");
+const ERROR_COVERAGE: Option<&str> = Some("Error Coverage");
+const BOUNDARY_CONDITIONS: Option<&str> = Some("Boundary Conditions");
+const TEST_ISOLATION: Option<&str> = Some("Test Isolation");
+const INPUT_VARIETY: Option<&str> = Some("Input Variety");
+const AI_SMELLS: Option<&str> = Some("AI Smells");
+
+/// Every rule, in the order of the rules reference
+pub static RULES: &[RuleInfo] = &[
+    // Assertion Quality
+    RuleInfo {
+        rule: Rule::WeakAssertion,
+        id: "weak-assertion",
+        section: "Assertion Quality",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "assertion-quality",
+        description: "Flags assertions that are too vague (e.g. toBeTruthy, toBeDefined) instead of asserting the exact expected value.",
+        example_bad: "expect(result).toBeTruthy()",
+        example_good: "expect(result).toBe(true) or expect(result).toEqual(expected)",
+    },
+    RuleInfo {
+        rule: Rule::NoAssertions,
+        id: "no-assertions",
+        section: "Assertion Quality",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Error,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "assertion-quality",
+        description: "Test has no assertions, so it does not verify behavior.",
+        example_bad: "it('works', () => { doSomething(); });",
+        example_good: "it('works', () => { expect(doSomething()).toBe(expected); });",
+    },
+    RuleInfo {
+        rule: Rule::EmptyTest,
+        id: "empty-test",
+        section: "Assertion Quality",
+        category: None,
+        default_severity: Severity::Error,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "assertion-quality",
+        description: "Test block has no body.",
+        example_bad: "it('handles refunds', () => {});",
+        example_good: "it.todo('handles refunds');",
+    },
+    RuleInfo {
+        rule: Rule::SnapshotOveruse,
+        id: "snapshot-overuse",
+        section: "Assertion Quality",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "assertion-quality",
+        description: "More than half of the assertions are snapshots, or a test only asserts a snapshot.",
+        example_bad: "expect(render(<Cart />)).toMatchSnapshot()",
+        example_good: "expect(screen.getByRole('status')).toHaveTextContent('2 items')",
+    },
+    RuleInfo {
+        rule: Rule::TrivialAssertion,
+        id: "trivial-assertion",
+        section: "Assertion Quality",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "trivial-assertion",
+        description: "Assertion always passes regardless of the code under test (e.g. expect(1).toBe(1)).",
+        example_bad: "expect(1).toBe(1)",
+        example_good: "expect(actualResult).toBe(expected)",
+    },
+    // Coverage
+    RuleInfo {
+        rule: Rule::MissingErrorTest,
+        id: "missing-error-test",
+        section: "Coverage",
+        category: ERROR_COVERAGE,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source, Input::Coverage],
+        analyzer: "error-coverage",
+        description: "The source can throw but no test expects the error (toThrow/rejects).",
+        example_bad: "No it('throws...') or expect(() => fn()).toThrow()",
+        example_good: "it('throws for invalid input', () => { expect(() => parse(invalid)).toThrow(ValidationError); })",
+    },
+    RuleInfo {
+        rule: Rule::MissingBoundaryTest,
+        id: "missing-boundary-test",
+        section: "Coverage",
+        category: BOUNDARY_CONDITIONS,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source, Input::Coverage],
+        analyzer: "boundary-conditions",
+        description: "A numeric comparison in the source (>=, <, etc.) is not tested at its boundary.",
+        example_bad: "Source checks age >= 18; tests only use 30",
+        example_good: "expect(isAdult(17)).toBe(false); expect(isAdult(18)).toBe(true);",
+    },
+    RuleInfo {
+        rule: Rule::ReturnPathCoverage,
+        id: "return-path-coverage",
+        section: "Coverage",
+        category: ERROR_COVERAGE,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source, Input::Coverage],
+        analyzer: "return-path-coverage",
+        description: "Return paths in the source are not covered by tests.",
+        example_bad: "Source returns early for an empty cart; no test passes an empty cart",
+        example_good: "expect(total([])).toBe(0)",
+    },
+    RuleInfo {
+        rule: Rule::BehavioralCompleteness,
+        id: "behavioral-completeness",
+        section: "Coverage",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source],
+        analyzer: "behavioral-completeness",
+        description: "Test only verifies part of the behavior (e.g. one field of the returned object).",
+        example_bad: "expect(user.name).toBe('Ada')",
+        example_good: "expect(user).toEqual({ name: 'Ada', role: 'admin', active: true })",
+    },
+    RuleInfo {
+        rule: Rule::SideEffectNotVerified,
+        id: "side-effect-not-verified",
+        section: "Coverage",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source],
+        analyzer: "side-effect-verification",
+        description: "Function has side effects but the test doesn't verify them.",
+        example_bad: "cart.add(item); expect(cart.add).toBeDefined();",
+        example_good: "cart.add(item); expect(cart.items).toContain(item);",
+    },
+    // Test Quality
+    RuleInfo {
+        rule: Rule::SharedState,
+        id: "shared-state",
+        section: "Test Quality",
+        category: TEST_ISOLATION,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "test-isolation",
+        description: "Mutable state shared between tests can cause order-dependent failures.",
+        example_bad: "let counter = 0; used in multiple tests without reset",
+        example_good: "beforeEach(() => { counter = 0; })",
+    },
+    RuleInfo {
+        rule: Rule::DuplicateTest,
+        id: "duplicate-test",
+        section: "Test Quality",
+        category: INPUT_VARIETY,
+        default_severity: Severity::Error,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "test-isolation",
+        description: "Multiple tests have the same name.",
+        example_bad: "it('adds items', ...); it('adds items', ...);",
+        example_good: "it('adds one item', ...); it('adds items in bulk', ...);",
+    },
+    RuleInfo {
+        rule: Rule::SkippedTest,
+        id: "skipped-test",
+        section: "Test Quality",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "assertion-quality",
+        description: "Test is marked with .skip or .todo.",
+        example_bad: "it.skip('handles refunds', ...)",
+        example_good: "it('handles refunds', ...)",
+    },
+    RuleInfo {
+        rule: Rule::LimitedInputVariety,
+        id: "limited-input-variety",
+        section: "Test Quality",
+        category: INPUT_VARIETY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source],
+        analyzer: "input-variety",
+        description: "Test inputs lack diversity.",
+        example_bad: "expect(double(2)).toBe(4); expect(double(3)).toBe(6);",
+        example_good: "it.each([[0, 0], [-1, -2], [2.5, 5]])('double(%d)', ...)",
+    },
+    RuleInfo {
+        rule: Rule::HardcodedValues,
+        id: "hardcoded-values",
+        section: "Test Quality",
+        category: INPUT_VARIETY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "input-variety",
+        description: "Tests use hardcoded real-looking data (e.g. emails) instead of fixtures.",
+        example_bad: "expect(email).toBe('user@example.com')",
+        example_good: "Use faker or test fixtures",
+    },
+    RuleInfo {
+        rule: Rule::VagueTestName,
+        id: "vague-test-name",
+        section: "Test Quality",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "naming-quality",
+        description: "Test name does not describe the scenario or expected outcome.",
+        example_bad: "it('test 1', ...)",
+        example_good: "it('returns 404 when user not found', ...)",
+    },
+    // Debug & Focus
+    RuleInfo {
+        rule: Rule::DebugCode,
+        id: "debug-code",
+        section: "Debug & Focus",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: true,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "debug-code",
+        description: "Debug code (console.log, debugger) left in tests.",
+        example_bad: "console.log(result);",
+        example_good: "Remove console.log and debugger before committing",
+    },
+    RuleInfo {
+        rule: Rule::FocusedTest,
+        id: "focused-test",
+        section: "Debug & Focus",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: true,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "debug-code",
+        description: "Focused test (.only, fit) skips the other tests when run.",
+        example_bad: "it.only('...', () => ...)",
+        example_good: "it('...', () => ...)",
+    },
+    // Async
+    RuleInfo {
+        rule: Rule::MissingAwait,
+        id: "missing-await",
+        section: "Async",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "async-patterns",
+        description: "expect().resolves/.rejects without await, so the assertion may never run.",
+        example_bad: "expect(load()).resolves.toEqual(data);",
+        example_good: "await expect(load()).resolves.toEqual(data);",
+    },
+    RuleInfo {
+        rule: Rule::FlakyPattern,
+        id: "flaky-pattern",
+        section: "Async",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Framework],
+        analyzer: "flaky-patterns",
+        description: "Non-deterministic or slow patterns (Date.now(), Math.random(), timers, unmocked fetch).",
+        example_bad: "expect(Date.now()).toBeGreaterThan(0)",
+        example_good: "Use fake timers and mock fetch/axios",
+    },
+    // Mocking
+    RuleInfo {
+        rule: Rule::MockAbuse,
+        id: "mock-abuse",
+        section: "Mocking",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "mock-abuse",
+        description: "Too many mocks (more than 5), or mocking the standard library.",
+        example_bad: "jest.mock('fs'); jest.mock('path'); ... six modules mocked",
+        example_good: "Mock only the boundary (network, clock) and use real collaborators",
+    },
+    // React Testing Library
+    RuleInfo {
+        rule: Rule::RtlPreferScreen,
+        id: "rtl-prefer-screen",
+        section: "React Testing Library",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Framework],
+        analyzer: "react-testing-library",
+        description: "container.querySelector instead of screen queries.",
+        example_bad: "container.querySelector('.submit')",
+        example_good: "screen.getByRole('button', { name: 'Submit' })",
+    },
+    RuleInfo {
+        rule: Rule::RtlPreferSemantic,
+        id: "rtl-prefer-semantic",
+        section: "React Testing Library",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Framework],
+        analyzer: "react-testing-library",
+        description: "getByTestId where a semantic query would work.",
+        example_bad: "screen.getByTestId('submit')",
+        example_good: "screen.getByRole('button', { name: 'Submit' })",
+    },
+    RuleInfo {
+        rule: Rule::RtlPreferUserEvent,
+        id: "rtl-prefer-user-event",
+        section: "React Testing Library",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Framework],
+        analyzer: "react-testing-library",
+        description: "fireEvent instead of userEvent.",
+        example_bad: "fireEvent.click(button)",
+        example_good: "await userEvent.click(button)",
+    },
+    // Mutation Resistance
+    RuleInfo {
+        rule: Rule::MutationResistant,
+        id: "mutation-resistant",
+        section: "Mutation Resistance",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "mutation-resistant",
+        description: "Assertion may let mutants survive (e.g. toBeGreaterThan(0) instead of toBe(3)).",
+        example_bad: "expect(items.length).toBeGreaterThan(0)",
+        example_good: "expect(items).toHaveLength(3)",
+    },
+    RuleInfo {
+        rule: Rule::BoundarySpecificity,
+        id: "boundary-specificity",
+        section: "Mutation Resistance",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "boundary-specificity",
+        description: "Boundary test doesn't assert the exact value.",
+        example_bad: "expect(clamp(101, 0, 100)).toBeLessThanOrEqual(100)",
+        example_good: "expect(clamp(101, 0, 100)).toBe(100)",
+    },
+    RuleInfo {
+        rule: Rule::StateVerification,
+        id: "state-verification",
+        section: "Mutation Resistance",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "state-verification",
+        description: "Test only checks the return value, not the state changes.",
+        example_bad: "expect(cart.add(item)).toBe(true)",
+        example_good: "cart.add(item); expect(cart.items).toEqual([item]);",
+    },
+    RuleInfo {
+        rule: Rule::AssertionIntentMismatch,
+        id: "assertion-intent-mismatch",
+        section: "Mutation Resistance",
+        category: ASSERTION_QUALITY,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "assertion-intent",
+        description: "Test name suggests an outcome but no assertion verifies it.",
+        example_bad: "it('throws on invalid input', () => { expect(parse('x')).toBeDefined(); })",
+        example_good: "it('throws on invalid input', () => { expect(() => parse('x')).toThrow(); })",
+    },
+    RuleInfo {
+        rule: Rule::SurvivingMutant,
+        id: "surviving-mutant",
+        section: "Mutation Resistance",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::Source, Input::MutationResults],
+        analyzer: "surviving-mutant",
+        description: "A mutant in the mapped source survived the tests or was never covered.",
+        example_bad: "Source: age >= 18; mutant age > 18 survives because no test uses 18",
+        example_good: "expect(isAdult(18)).toBe(true)",
+    },
+    RuleInfo {
+        rule: Rule::WeightlessAssertion,
+        id: "weightless-assertion",
+        section: "Mutation Resistance",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[Input::MutationResults],
+        analyzer: "weightless-assertion",
+        description: "An assertion the tests still pass with when it is inverted, so it never runs or checks nothing.",
+        example_bad: "items.forEach(item => { expect(item.id).toBeDefined(); }) on an empty list",
+        example_good: "expect(items).toHaveLength(2); expect(items[0].id).toBe('a')",
+    },
+    // AI Smells
+    RuleInfo {
+        rule: Rule::TautologicalAssertion,
+        id: "ai-smell-tautological-assertion",
+        section: "AI Smells",
+        category: AI_SMELLS,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "ai-smells",
+        description: "Tautological assertion (e.g. expect(x).toBe(x)).",
+        example_bad: "expect(result).toBe(result)",
+        example_good: "expect(result).toBe(42)",
+    },
+    RuleInfo {
+        rule: Rule::OverMocking,
+        id: "ai-smell-over-mocking",
+        section: "AI Smells",
+        category: AI_SMELLS,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "ai-smells",
+        description: "Too many mocks; the test checks the implementation rather than behavior.",
+        example_bad: "Every collaborator mocked and only toHaveBeenCalled asserted",
+        example_good: "Mock the boundary and assert on the result",
+    },
+    RuleInfo {
+        rule: Rule::ShallowVariety,
+        id: "ai-smell-shallow-variety",
+        section: "AI Smells",
+        category: AI_SMELLS,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "ai-smells",
+        description: "Inputs cover a narrow range.",
+        example_bad: "add(1, 2); add(2, 3); add(3, 4);",
+        example_good: "add(0, 0); add(-1, 1); add(Number.MAX_SAFE_INTEGER, 1);",
+    },
+    RuleInfo {
+        rule: Rule::HappyPathOnly,
+        id: "ai-smell-happy-path-only",
+        section: "AI Smells",
+        category: AI_SMELLS,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "ai-smells",
+        description: "No error or edge-case tests.",
+        example_bad: "Only it('parses a valid date', ...)",
+        example_good: "Add it('rejects an invalid date', ...) and empty-input tests",
+    },
+    RuleInfo {
+        rule: Rule::ParrotAssertion,
+        id: "ai-smell-parrot-assertion",
+        section: "AI Smells",
+        category: AI_SMELLS,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "ai-smells",
+        description: "Repeats the spec wording without a real check.",
+        example_bad: "it('returns the total', () => { expect(getTotal).toBeDefined(); })",
+        example_good: "it('returns the total', () => { expect(getTotal([1, 2])).toBe(3); })",
+    },
+    RuleInfo {
+        rule: Rule::BoilerplatePadding,
+        id: "ai-smell-boilerplate-padding",
+        section: "AI Smells",
+        category: AI_SMELLS,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stable,
+        inputs: &[],
+        analyzer: "ai-smells",
+        description: "Generic setup with little signal.",
+        example_bad: "beforeEach(() => { jest.clearAllMocks(); }) in a file without mocks",
+        example_good: "Keep only the setup the tests rely on",
+    },
+    // Planned
+    RuleInfo {
+        rule: Rule::TestComplexity,
+        id: "test-complexity",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "test-complexity",
+        description: "Test is too complex (many branches or assertions).",
+        example_bad: "A test with nested if/for blocks and 20 assertions",
+        example_good: "Split into focused tests, one behavior each",
+    },
+    RuleInfo {
+        rule: Rule::ImplementationCoupling,
+        id: "implementation-coupling",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "implementation-coupling",
+        description: "Test is coupled to implementation details (private fields, internal calls).",
+        example_bad: "expect(service['cache'].size).toBe(1)",
+        example_good: "expect(service.get('key')).toBe(value)",
+    },
+    RuleInfo {
+        rule: Rule::VacuousTest,
+        id: "vacuous-test",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Partial,
+        inputs: &[],
+        analyzer: "vacuous-test",
+        description: "Test always passes or does not meaningfully verify behavior.",
+        example_bad: "it('works', () => { expect(true).toBe(true); })",
+        example_good: "it('works', () => { expect(run()).toBe(expected); })",
+    },
+    RuleInfo {
+        rule: Rule::IncompleteMockVerification,
+        id: "incomplete-mock-verification",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "incomplete-mock-verification",
+        description: "Mock is used but its calls are not verified.",
+        example_bad: "const send = jest.fn(); notify(send);",
+        example_good: "expect(send).toHaveBeenCalledWith('ada@test', 'Welcome')",
+    },
+    RuleInfo {
+        rule: Rule::AsyncErrorMishandling,
+        id: "async-error-mishandling",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "async-error-mishandling",
+        description: "Async error path not properly tested (rejects, catch).",
+        example_bad: "try { await load(); } catch (e) { expect(e).toBeDefined(); }",
+        example_good: "await expect(load()).rejects.toThrow(NotFoundError)",
+    },
+    RuleInfo {
+        rule: Rule::RedundantTest,
+        id: "redundant-test",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "redundant-test",
+        description: "Test duplicates another test's coverage.",
+        example_bad: "Two tests asserting expect(add(1, 2)).toBe(3)",
+        example_good: "Keep one and test a different case in the other",
+    },
+    RuleInfo {
+        rule: Rule::UnreachableTestCode,
+        id: "unreachable-test-code",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Warning,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "unreachable-test-code",
+        description: "Code in a test after return or throw never runs.",
+        example_bad: "return; expect(x).toBe(1);",
+        example_good: "expect(x).toBe(1);",
+    },
+    RuleInfo {
+        rule: Rule::ExcessiveSetup,
+        id: "excessive-setup",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "excessive-setup",
+        description: "beforeEach/beforeAll does too much.",
+        example_bad: "A 40-line beforeEach building every fixture",
+        example_good: "Build the fixtures each test needs with small helpers",
+    },
+    RuleInfo {
+        rule: Rule::TypeAssertionAbuse,
+        id: "type-assertion-abuse",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "type-assertion-abuse",
+        description: "Type assertions (as Type) used instead of real checks.",
+        example_bad: "const user = response as User;",
+        example_good: "expect(response).toMatchObject({ id: expect.any(Number) })",
+    },
+    RuleInfo {
+        rule: Rule::MissingCleanup,
+        id: "missing-cleanup",
+        section: "Planned",
+        category: None,
+        default_severity: Severity::Info,
+        fixable: false,
+        maturity: Maturity::Stub,
+        inputs: &[],
+        analyzer: "missing-cleanup",
+        description: "Mocks, timers or listeners are not cleaned up (afterEach, mockReset).",
+        example_bad: "jest.useFakeTimers() without jest.useRealTimers()",
+        example_good: "afterEach(() => { jest.useRealTimers(); })",
+    },
+];
+
+/// Scoring categories a rule can reduce
+pub const CATEGORIES: &[&str] = &[
+    "Assertion Quality",
+    "Error Coverage",
+    "Boundary Conditions",
+    "Test Isolation",
+    "Input Variety",
+    "AI Smells",
+];
+
+impl Rule {
+    /// Registry entry for this rule; `None` for [`Rule::Custom`]
+    pub fn info(self) -> Option<&'static RuleInfo> {
+        RULES.iter().find(|info| info.rule == self)
+    }
+
+    /// Kebab-case id, for built-in and custom rules alike
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::Custom(id) => id.as_str(),
+            rule => rule.info().map_or("unknown", |info| info.id),
+        }
+    }
+
+    /// Scoring category; for custom rules, the one they were last registered with (scoring
+    /// takes it from the file's own config instead, see `ScoringModel::for_config`)
+    pub fn category(self) -> Option<&'static str> {
+        match self {
+            Rule::Custom(id) => custom(id).and_then(|c| c.category),
+            rule => rule.info().and_then(|info| info.category),
+        }
+    }
+}
+
+/// Registry entry for a rule id (e.g. `weak-assertion`)
+pub fn find(id: &str) -> Option<&'static RuleInfo> {
+    RULES.iter().find(|info| info.id == id)
+}
+
+/// Built-in or registered custom rule for an id
+pub fn rule_for_id(id: &str) -> Option<Rule> {
+    find(id).map(|info| info.rule).or_else(|| {
+        custom_rules()
+            .read()
+            .ok()?
+            .get(id)
+            .map(|c| Rule::Custom(c.id))
+    })
+}
+
+/// Id of a rule declared outside [`RULES`] (`customRules` in config). Interned, so `Rule`
+/// stays `Copy`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleId(&'static str);
+
+impl RuleId {
+    pub fn new(id: &str) -> Self {
+        static IDS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+        let mut ids = IDS
+            .get_or_init(Default::default)
+            .lock()
+            .expect("rule id lock");
+        match ids.get(id) {
+            Some(interned) => Self(interned),
+            None => {
+                let interned: &'static str = Box::leak(id.to_string().into_boxed_str());
+                ids.insert(interned);
+                Self(interned)
+            }
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for RuleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl Serialize for RuleId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for RuleId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|id| RuleId::new(&id))
+    }
+}
+
+/// Metadata for a custom rule, registered when its analyzer is built
+#[derive(Debug, Clone, Copy)]
+pub struct CustomRuleInfo {
+    pub id: RuleId,
+    pub category: Option<&'static str>,
+    pub description: &'static str,
+}
+
+fn custom_rules() -> &'static RwLock<HashMap<&'static str, CustomRuleInfo>> {
+    static CUSTOM: OnceLock<RwLock<HashMap<&'static str, CustomRuleInfo>>> = OnceLock::new();
+    CUSTOM.get_or_init(Default::default)
+}
+
+/// Register (or update) a custom rule. `category` must be one of [`CATEGORIES`] to count
+/// towards that category; anything else makes the rule penalty-only.
+pub fn register_custom(id: &str, category: Option<&str>, description: &str) -> Rule {
+    let id = RuleId::new(id);
+    let category = category.and_then(|c| CATEGORIES.iter().copied().find(|known| *known == c));
+    let mut custom = custom_rules().write().expect("custom rule lock");
+    let unchanged = custom
+        .get(id.as_str())
+        .is_some_and(|c| c.category == category && c.description == description);
+    if !unchanged {
+        let description: &'static str = Box::leak(description.to_string().into_boxed_str());
+        custom.insert(
+            id.as_str(),
+            CustomRuleInfo {
+                id,
+                category,
+                description,
+            },
+        );
+    }
+    Rule::Custom(id)
+}
+
+/// Metadata of a registered custom rule
+pub fn custom(id: RuleId) -> Option<CustomRuleInfo> {
+    custom_rules().read().ok()?.get(id.as_str()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_registry_is_complete_and_unique() {
+        let ids: HashSet<&str> = RULES.iter().map(|r| r.id).collect();
+        let rules: HashSet<Rule> = RULES.iter().map(|r| r.rule).collect();
+        assert_eq!(ids.len(), RULES.len());
+        assert_eq!(rules.len(), RULES.len());
+        for info in RULES {
+            assert_eq!(find(info.id).map(|r| r.rule), Some(info.rule));
+            assert_eq!(rule_for_id(info.id), Some(info.rule));
+            assert_eq!(info.rule.to_string(), info.id);
+        }
+    }
+
+    #[test]
+    fn test_custom_rules_are_interned_and_registered() {
+        assert_eq!(rule_for_id("registry-test-no-reset-db"), None);
+        let rule = register_custom(
+            "registry-test-no-reset-db",
+            Some("Test Isolation"),
+            "Do not reset the database inside a test",
+        );
+        assert_eq!(rule, Rule::Custom(RuleId::new("registry-test-no-reset-db")));
+        assert_eq!(rule_for_id("registry-test-no-reset-db"), Some(rule));
+        assert_eq!(rule.to_string(), "registry-test-no-reset-db");
+        assert_eq!(rule.category(), Some("Test Isolation"));
+        assert_eq!(
+            serde_json::to_string(&rule).unwrap(),
+            "\"registry-test-no-reset-db\""
+        );
+        let back: Rule = serde_json::from_str("\"registry-test-no-reset-db\"").unwrap();
+        assert_eq!(back, rule);
+        let builtin: Rule = serde_json::from_str("\"weak-assertion\"").unwrap();
+        assert_eq!(builtin, Rule::WeakAssertion);
+
+        register_custom("registry-test-no-reset-db", Some("Speed"), "changed");
+        assert_eq!(rule.category(), None);
+    }
+
+    #[test]
+    fn test_fixable_rules_have_fixes() {
+        let fixable: Vec<&str> = RULES.iter().filter(|r| r.fixable).map(|r| r.id).collect();
+        assert_eq!(fixable, vec!["debug-code", "focused-test"]);
+    }
+}
@@ -127,6 +127,17 @@ impl ConsoleReporter {
             for line in Self::format_breakdown_summary(tb) {
                 println!("   {}", line);
             }
+            if self.verbose && !tb.category_overrides.is_empty() {
+                let moves: Vec<String> = tb
+                    .category_overrides
+                    .iter()
+                    .map(|(rule, category)| format!("{} → {}", rule, category))
+                    .collect();
+                println!(
+                    "   {}",
+                    format!("Rule categories from config: {}", moves.join(", ")).dimmed()
+                );
+            }
         } else {
             let categories = [
                 ("Assertion Quality", result.breakdown.assertion_quality),
@@ -232,16 +243,16 @@ impl ConsoleReporter {
             .collect();
 
         for issue in errors {
-            self.print_issue(issue);
+            self.print_issue(issue, result);
         }
         for issue in warnings {
-            self.print_issue(issue);
+            self.print_issue(issue, result);
         }
 
         // Only show info issues in verbose mode or if there are few total issues
         if self.verbose || result.issues.len() <= 5 {
             for issue in infos {
-                self.print_issue(issue);
+                self.print_issue(issue, result);
             }
         } else if !infos.is_empty() {
             println!(
@@ -254,7 +265,7 @@ impl ConsoleReporter {
         println!();
     }
 
-    fn print_issue(&self, issue: &Issue) {
+    fn print_issue(&self, issue: &Issue, result: &AnalysisResult) {
         let icon = match issue.severity {
             Severity::Error => "✗".red(),
             Severity::Warning => "⚠".yellow(),
@@ -271,7 +282,19 @@ impl ConsoleReporter {
         );
 
         if self.verbose {
-            let category_note = match rule_scoring_category(&issue.rule) {
+            // `scoring.categories` in config may have moved the rule, and a custom rule takes
+            // the category its own config declares
+            let moved = result.transparent_breakdown.as_ref().and_then(|tb| {
+                tb.category_overrides
+                    .get(issue.rule.id())
+                    .or_else(|| tb.custom_categories.get(issue.rule.id()))
+            });
+            let category = match moved {
+                Some(category) if category == "penalty" => None,
+                Some(category) => Some(category.as_str()),
+                None => rule_scoring_category(&issue.rule),
+            };
+            let category_note = match category {
                 Some(cat) => format!("affects category: {}", cat),
                 None => "affects penalty only".to_string(),
             };
@@ -318,7 +341,43 @@ impl ConsoleReporter {
         );
         println!("   Total tests:    {}", stats.total_tests);
         println!("   Total issues:   {}", stats.total_issues);
+        if !stats.packages.is_empty() {
+            self.print_packages(stats);
+        }
+        println!();
+    }
+
+    fn print_packages(&self, stats: &AggregateStats) {
+        let width = stats
+            .packages
+            .iter()
+            .map(|package| package.name.len())
+            .max()
+            .unwrap_or(0);
         println!();
+        println!("   {}", "Packages".bold());
+        for package in &stats.packages {
+            let threshold = match package.threshold {
+                Some(threshold) if package.passed() => format!("  ≥ {}", threshold).green(),
+                Some(threshold) => format!("  < {}", threshold).red().bold(),
+                None => "".normal(),
+            };
+            println!(
+                "   {:<width$}  {:>3} ({})  {} file{}, {} issues{}",
+                package.name,
+                package.stats.average_score.value,
+                self.colorize_grade(&package.stats.average_score.grade),
+                package.stats.files_analyzed,
+                if package.stats.files_analyzed == 1 {
+                    ""
+                } else {
+                    "s"
+                },
+                package.stats.total_issues,
+                threshold,
+                width = width
+            );
+        }
     }
 
     fn colorize_grade(&self, grade: &Grade) -> colored::ColoredString {
@@ -387,6 +446,9 @@ mod tests {
             penalty_from_errors: 0,
             penalty_from_warnings: 0,
             penalty_from_info: 0,
+            penalty_model: Default::default(),
+            category_overrides: Default::default(),
+            custom_categories: Default::default(),
             final_score,
             per_test_aggregated: per_test_agg,
         }
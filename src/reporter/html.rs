@@ -607,6 +607,7 @@ mod tests {
             framework: TestFramework::Jest,
             test_type: TestType::Unit,
             source_file: None,
+            package: None,
         }
     }
 
@@ -620,6 +621,7 @@ mod tests {
             average_score: Score::new(68),
             total_tests: 9,
             total_issues: 5,
+            packages: Vec::new(),
         };
         let reporter = HtmlReporter::new();
         let html = reporter.report(&[r1, r2, r3], &stats);
@@ -658,6 +660,7 @@ mod tests {
             average_score: Score::new(60),
             total_tests: 3,
             total_issues: 1,
+            packages: Vec::new(),
         };
         let reporter = HtmlReporter::new();
         let html = reporter.report(&[r], &stats);
@@ -672,6 +675,7 @@ mod tests {
             average_score: Score::new(0),
             total_tests: 0,
             total_issues: 0,
+            packages: Vec::new(),
         };
         let reporter = HtmlReporter::new();
         let html = reporter.report(&[], &stats);
@@ -48,13 +48,7 @@ impl JsonReporter {
     ) -> String {
         let output = JsonOutput {
             results,
-            summary: JsonSummary {
-                files_analyzed: stats.files_analyzed,
-                average_score: stats.average_score.value,
-                average_grade: stats.average_score.grade.to_string(),
-                total_tests: stats.total_tests,
-                total_issues: stats.total_issues,
-            },
+            summary: JsonSummary::new(stats),
         };
 
         if self.pretty {
@@ -86,6 +80,43 @@ struct JsonSummary {
     average_grade: String,
     total_tests: usize,
     total_issues: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    packages: Vec<JsonPackageSummary>,
+}
+
+impl JsonSummary {
+    fn new(stats: &AggregateStats) -> Self {
+        Self {
+            files_analyzed: stats.files_analyzed,
+            average_score: stats.average_score.value,
+            average_grade: stats.average_score.grade.to_string(),
+            total_tests: stats.total_tests,
+            total_issues: stats.total_issues,
+            packages: stats
+                .packages
+                .iter()
+                .map(|package| JsonPackageSummary {
+                    name: package.name.clone(),
+                    path: package.path.to_string_lossy().replace('\\', "/"),
+                    threshold: package.threshold,
+                    passed: package.passed(),
+                    summary: Self::new(&package.stats),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonPackageSummary {
+    name: String,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    threshold: Option<u8>,
+    passed: bool,
+    #[serde(flatten)]
+    summary: JsonSummary,
 }
 
 #[cfg(test)]
@@ -118,6 +149,7 @@ mod tests {
             framework: TestFramework::Jest,
             test_type: TestType::Unit,
             source_file: None,
+            package: None,
         }
     }
 
@@ -196,6 +228,7 @@ mod tests {
             average_score: Score::new(80),
             total_tests: 6,
             total_issues: 0,
+            packages: Vec::new(),
         };
 
         let reporter = JsonReporter::new();
@@ -223,4 +256,40 @@ mod tests {
         let arr = parsed.as_array().unwrap();
         assert!(arr.is_empty());
     }
+
+    #[test]
+    fn test_json_summary_lists_packages() {
+        use crate::analyzer::engine::PackageStats;
+
+        let package = |name: &str, score: u8, threshold: Option<u8>| PackageStats {
+            name: name.to_string(),
+            path: PathBuf::from("packages").join(name),
+            stats: AggregateStats {
+                files_analyzed: 1,
+                average_score: Score::new(score),
+                total_tests: 3,
+                ..AggregateStats::default()
+            },
+            threshold,
+        };
+        let stats = AggregateStats {
+            files_analyzed: 2,
+            average_score: Score::new(80),
+            total_tests: 6,
+            total_issues: 0,
+            packages: vec![package("api", 90, None), package("legacy", 70, Some(75))],
+        };
+
+        let json = JsonReporter::new().report_with_summary(&[], &stats);
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let packages = parsed["summary"]["packages"].as_array().unwrap();
+        assert_eq!(packages[0]["name"], "api");
+        assert_eq!(packages[0]["path"], "packages/api");
+        assert_eq!(packages[0]["passed"], true);
+        assert!(packages[0].get("threshold").is_none());
+        assert_eq!(packages[1]["averageScore"], 70);
+        assert_eq!(packages[1]["threshold"], 75);
+        assert_eq!(packages[1]["passed"], false);
+        assert!(packages[1].get("packages").is_none());
+    }
 }
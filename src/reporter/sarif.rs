@@ -1,7 +1,7 @@
 //! SARIF 2.1 reporter for GitHub Code Scanning / VS Code SARIF viewer
 
 use crate::analyzer::engine::AggregateStats;
-use crate::{AnalysisResult, Severity};
+use crate::{AnalysisResult, Rule, Severity};
 use serde::Serialize;
 use std::path::Path;
 
@@ -58,6 +58,7 @@ struct SarifDriver {
 struct SarifRule {
     id: String,
     short_description: SarifMessage,
+    #[serde(skip_serializing_if = "Option::is_none")]
     full_description: Option<SarifMessage>,
     default_configuration: SarifDefaultConfig,
 }
@@ -140,26 +141,47 @@ impl SarifReporter {
 
     /// Produce SARIF 2.1 JSON for one or more analysis results
     pub fn report(&self, results: &[AnalysisResult], _stats: Option<&AggregateStats>) -> String {
-        let mut rule_ids = std::collections::HashSet::new();
-        for r in results {
-            for i in &r.issues {
-                rule_ids.insert(i.rule.to_string());
-            }
-        }
+        let used: std::collections::HashSet<Rule> = results
+            .iter()
+            .flat_map(|r| r.issues.iter().map(|i| i.rule))
+            .collect();
 
-        let rules: Vec<SarifRule> = rule_ids
+        let mut rules: Vec<SarifRule> = crate::registry::RULES
             .iter()
-            .map(|id| SarifRule {
-                id: id.clone(),
+            .filter(|info| used.contains(&info.rule))
+            .map(|info| SarifRule {
+                id: info.id.to_string(),
                 short_description: SarifMessage {
-                    text: id.replace('-', " ").to_string(),
+                    text: info.id.replace('-', " "),
                 },
-                full_description: None,
+                full_description: Some(SarifMessage {
+                    text: info.description.to_string(),
+                }),
                 default_configuration: SarifDefaultConfig {
-                    level: "warning".to_string(),
+                    level: severity_to_level(info.default_severity).to_string(),
                 },
             })
             .collect();
+        let mut custom: Vec<crate::registry::RuleId> = used
+            .iter()
+            .filter_map(|rule| match rule {
+                Rule::Custom(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        custom.sort_by_key(|id| id.as_str());
+        rules.extend(custom.into_iter().map(|id| SarifRule {
+            id: id.as_str().to_string(),
+            short_description: SarifMessage {
+                text: id.as_str().replace('-', " "),
+            },
+            full_description: crate::registry::custom(id).map(|info| SarifMessage {
+                text: info.description.to_string(),
+            }),
+            default_configuration: SarifDefaultConfig {
+                level: "warning".to_string(),
+            },
+        }));
 
         let mut sarif_results = Vec::new();
         for result in results {
@@ -266,6 +288,7 @@ mod tests {
             framework: TestFramework::Jest,
             test_type: TestType::Unit,
             source_file: None,
+            package: None,
         }
     }
 
@@ -134,14 +134,20 @@ The code should be complete and runnable.
         let mut output = format!(
             r#"
 ## Mutation Testing Results
-**Mutation Score:** {}% ({}/{} mutants killed)
+**Mutation Score:** {}% ({}/{} mutants detected)
 
 "#,
-            score, mutation.killed, mutation.total
+            score,
+            mutation.detected(),
+            mutation.valid()
         );
 
         // List survived mutants (these are opportunities for better tests)
-        let survivors: Vec<_> = mutation.details.iter().filter(|r| !r.killed).collect();
+        let survivors: Vec<_> = mutation
+            .details
+            .iter()
+            .filter(|r| r.status.is_undetected())
+            .collect();
 
         if !survivors.is_empty() {
             output.push_str("### Survived Mutants (Tests didn't catch these changes)\n");
@@ -170,7 +176,11 @@ The code should be complete and runnable.
             return String::new();
         };
 
-        let survivors: Vec<_> = mutation.details.iter().filter(|r| !r.killed).collect();
+        let survivors: Vec<_> = mutation
+            .details
+            .iter()
+            .filter(|r| r.status.is_undetected())
+            .collect();
 
         if survivors.is_empty() {
             return String::new();
@@ -568,6 +578,7 @@ mod tests {
             framework: TestFramework::Jest,
             test_type: TestType::Unit,
             source_file: None,
+            package: None,
         };
 
         let generator = AiSuggestionGenerator::new();
@@ -0,0 +1,421 @@
+//! Monorepo workspace detection: packages from `package.json` `workspaces`,
+//! `pnpm-workspace.yaml`, Nx projects (`project.json`, `workspace.json`) and `turbo.json`
+
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Package globs Turborepo repos use when no package manager workspaces are declared
+const TURBO_DEFAULT_PACKAGES: &[&str] = &["apps/*", "packages/*"];
+
+/// A package (or Nx project) in a workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    /// `name` from its package.json or project.json, else its path
+    pub name: String,
+    /// Package directory, absolute
+    pub dir: PathBuf,
+    /// Package directory relative to the workspace root
+    pub path: PathBuf,
+}
+
+/// A monorepo and its packages
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// Directory holding the workspace manifest, absolute
+    pub root: PathBuf,
+    /// Manifests packages were read from, e.g. `pnpm-workspace.yaml`
+    pub sources: Vec<&'static str>,
+    /// Packages sorted by name
+    pub packages: Vec<Package>,
+}
+
+#[derive(Deserialize)]
+struct PackageJson {
+    name: Option<String>,
+    workspaces: Option<Workspaces>,
+}
+
+/// `workspaces` as an array (npm, yarn, bun) or `{ "packages": [...] }` (yarn classic)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Workspaces {
+    Globs(Vec<String>),
+    Object {
+        #[serde(default)]
+        packages: Vec<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct PnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct NxProject {
+    name: Option<String>,
+}
+
+/// Legacy Nx `workspace.json`: project name to its root (a path or `{ "root": ... }`)
+#[derive(Deserialize)]
+struct NxWorkspaceJson {
+    #[serde(default)]
+    projects: std::collections::BTreeMap<String, NxProjectRef>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NxProjectRef {
+    Path(String),
+    Config { root: String },
+}
+
+impl Workspace {
+    /// Find the workspace containing `start`: the nearest directory at or above it (up to
+    /// the repository root) with `pnpm-workspace.yaml`, a package.json with `workspaces`,
+    /// `nx.json` or `turbo.json`. A directory whose manifests can't be read is passed over;
+    /// its error is returned alongside, for the caller to warn about.
+    pub fn detect(start: &Path) -> Result<(Option<Workspace>, Vec<anyhow::Error>)> {
+        let start = start
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {}", start.display()))?;
+        let mut skipped = Vec::new();
+        for dir in start.ancestors() {
+            match Self::load(dir) {
+                Ok(Some(workspace)) => return Ok((Some(workspace), skipped)),
+                Ok(None) => {}
+                Err(e) => skipped.push(e),
+            }
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+        Ok((None, skipped))
+    }
+
+    /// Workspace rooted at `root`, if it has a workspace manifest
+    pub fn load(root: &Path) -> Result<Option<Workspace>> {
+        let mut sources = Vec::new();
+        let mut globs = Vec::new();
+
+        let pnpm = root.join("pnpm-workspace.yaml");
+        if pnpm.is_file() {
+            let workspace: PnpmWorkspace = read_yaml(&pnpm)?;
+            globs.extend(workspace.packages);
+            sources.push("pnpm-workspace.yaml");
+        }
+        let package_json = root.join("package.json");
+        if package_json.is_file() {
+            let package: PackageJson = read_json(&package_json)?;
+            match package.workspaces {
+                Some(Workspaces::Globs(packages) | Workspaces::Object { packages }) => {
+                    globs.extend(packages);
+                    sources.push("package.json");
+                }
+                None => {}
+            }
+        }
+        let turbo = root.join("turbo.json").is_file();
+        if turbo {
+            if globs.is_empty() {
+                globs.extend(TURBO_DEFAULT_PACKAGES.iter().map(|g| g.to_string()));
+            }
+            sources.push("turbo.json");
+        }
+        let nx = root.join("nx.json").is_file();
+        if nx {
+            sources.push("nx.json");
+        }
+        if sources.is_empty() {
+            return Ok(None);
+        }
+
+        let mut packages = package_dirs(root, &globs)?
+            .into_iter()
+            .map(|dir| {
+                let name = read_json::<PackageJson>(&dir.join("package.json"))
+                    .ok()
+                    .and_then(|p| p.name);
+                (dir, name)
+            })
+            .collect::<Vec<_>>();
+        if nx {
+            for (dir, name) in nx_projects(root)? {
+                match packages.iter_mut().find(|(known, _)| *known == dir) {
+                    // A project.json name is what Nx calls the project
+                    Some((_, known_name)) => *known_name = name.or(known_name.take()),
+                    None => packages.push((dir, name)),
+                }
+            }
+        }
+
+        let mut packages: Vec<Package> = packages
+            .into_iter()
+            .map(|(dir, name)| {
+                let path = dir.strip_prefix(root).unwrap_or(&dir).to_path_buf();
+                Package {
+                    name: name.unwrap_or_else(|| path.to_string_lossy().replace('\\', "/")),
+                    dir,
+                    path,
+                }
+            })
+            .collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Some(Workspace {
+            root: root.to_path_buf(),
+            sources,
+            packages,
+        }))
+    }
+
+    /// The innermost package containing `file`
+    pub fn package_for(&self, file: &Path) -> Option<&Package> {
+        let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+        self.packages
+            .iter()
+            .filter(|package| file.starts_with(&package.dir))
+            .max_by_key(|package| package.dir.components().count())
+    }
+
+    /// Packages whose name or path matches any of `patterns` (globs, e.g. `@acme/*` or
+    /// `apps/**`)
+    pub fn filter(&self, patterns: &[String]) -> Result<Vec<&Package>> {
+        let set = glob_set(patterns)?;
+        Ok(self
+            .packages
+            .iter()
+            .filter(|package| set.is_match(&package.name) || set.is_match(&package.path))
+            .collect())
+    }
+}
+
+/// Directories under `root` matching the workspace globs (`!` excludes) that have a
+/// package.json
+fn package_dirs(root: &Path, globs: &[String]) -> Result<Vec<PathBuf>> {
+    let normalize = |glob: &str| {
+        glob.trim_start_matches("./")
+            .trim_end_matches('/')
+            .to_string()
+    };
+    let (excludes, includes): (Vec<&String>, Vec<&String>) =
+        globs.iter().partition(|glob| glob.starts_with('!'));
+    let includes: Vec<String> = includes.iter().map(|g| normalize(g)).collect();
+    let excludes: Vec<String> = excludes.iter().map(|g| normalize(&g[1..])).collect();
+    if includes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let includes = glob_set(&includes)?;
+    let excludes = glob_set(&excludes)?;
+
+    Ok(project_dirs(root)
+        .filter(|dir| {
+            let relative = dir.strip_prefix(root).unwrap_or(dir);
+            includes.is_match(relative)
+                && !excludes.is_match(relative)
+                && dir.join("package.json").is_file()
+        })
+        .collect())
+}
+
+/// Nx projects declared by `project.json` files and a legacy `workspace.json`
+fn nx_projects(root: &Path) -> Result<Vec<(PathBuf, Option<String>)>> {
+    let mut projects: Vec<(PathBuf, Option<String>)> = project_dirs(root)
+        .filter(|dir| dir.join("project.json").is_file())
+        .map(|dir| {
+            let name = read_json::<NxProject>(&dir.join("project.json"))
+                .ok()
+                .and_then(|p| p.name);
+            (dir, name)
+        })
+        .collect();
+    let workspace_json = root.join("workspace.json");
+    if workspace_json.is_file() {
+        let workspace: NxWorkspaceJson = read_json(&workspace_json)?;
+        for (name, project) in workspace.projects {
+            let dir = match project {
+                NxProjectRef::Path(path) | NxProjectRef::Config { root: path } => root.join(path),
+            };
+            projects.retain(|(known, _)| *known != dir);
+            projects.push((dir, Some(name)));
+        }
+    }
+    Ok(projects)
+}
+
+/// Directories below `root`, skipping dependencies, build output and hidden directories
+fn project_dirs(root: &Path) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.file_type().is_dir()
+                && !name.starts_with('.')
+                && !matches!(
+                    name.as_ref(),
+                    "node_modules" | "dist" | "build" | "coverage"
+                )
+        })
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .with_context(|| format!("Invalid package glob: {}", pattern))?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid JSON in {}", path.display()))
+}
+
+fn read_yaml<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_yaml_ng::from_str(&content).with_context(|| format!("Invalid YAML in {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn names(workspace: &Workspace) -> Vec<&str> {
+        workspace.packages.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_detects_package_manager_workspaces() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        write(
+            &root,
+            "package.json",
+            r#"{ "workspaces": ["packages/*", "!packages/internal"] }"#,
+        );
+        write(
+            &root,
+            "packages/ui/package.json",
+            r#"{ "name": "@acme/ui" }"#,
+        );
+        write(
+            &root,
+            "packages/api/package.json",
+            r#"{ "name": "@acme/api" }"#,
+        );
+        write(
+            &root,
+            "packages/internal/package.json",
+            r#"{ "name": "internal" }"#,
+        );
+        write(&root, "packages/docs/README.md", "");
+        write(&root, "packages/ui/node_modules/dep/package.json", "{}");
+
+        let workspace = Workspace::detect(&root.join("packages/ui"))
+            .unwrap()
+            .0
+            .unwrap();
+        assert_eq!(workspace.root, root);
+        assert_eq!(workspace.sources, vec!["package.json"]);
+        assert_eq!(names(&workspace), vec!["@acme/api", "@acme/ui"]);
+
+        let file = root.join("packages/ui/src/button.test.ts");
+        assert_eq!(workspace.package_for(&file).unwrap().name, "@acme/ui");
+        assert!(workspace
+            .package_for(&root.join("scripts/a.test.ts"))
+            .is_none());
+
+        let filtered = workspace.filter(&["@acme/a*".to_string()]).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(
+            workspace.filter(&["packages/*".to_string()]).unwrap().len(),
+            2
+        );
+
+        // pnpm globs are combined with package.json ones
+        write(&root, "pnpm-workspace.yaml", "packages:\n  - 'apps/*'\n");
+        write(&root, "apps/web/package.json", r#"{ "name": "web" }"#);
+        let workspace = Workspace::load(&root).unwrap().unwrap();
+        assert_eq!(
+            workspace.sources,
+            vec!["pnpm-workspace.yaml", "package.json"]
+        );
+        assert_eq!(names(&workspace), vec!["@acme/api", "@acme/ui", "web"]);
+    }
+
+    #[test]
+    fn test_detects_nx_and_turbo_workspaces() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        write(&root, "nx.json", "{}");
+        write(&root, "apps/shop/project.json", r#"{ "name": "shop" }"#);
+        write(&root, "libs/cart/project.json", "{}");
+        let workspace = Workspace::detect(&root.join("apps")).unwrap().0.unwrap();
+        assert_eq!(workspace.sources, vec!["nx.json"]);
+        assert_eq!(names(&workspace), vec!["libs/cart", "shop"]);
+
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        write(&root, "turbo.json", "{}");
+        write(&root, "package.json", r#"{ "name": "monorepo" }"#);
+        write(&root, "apps/web/package.json", r#"{ "name": "web" }"#);
+        write(
+            &root,
+            "packages/config/package.json",
+            r#"{ "name": "config" }"#,
+        );
+        let workspace = Workspace::load(&root).unwrap().unwrap();
+        assert_eq!(names(&workspace), vec!["config", "web"]);
+
+        let plain = TempDir::new().unwrap();
+        fs::create_dir(plain.path().join(".git")).unwrap();
+        write(plain.path(), "package.json", r#"{ "name": "app" }"#);
+        assert!(Workspace::detect(plain.path()).unwrap().0.is_none());
+    }
+
+    #[test]
+    fn test_malformed_manifests_are_passed_over() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        fs::create_dir(root.join(".git")).unwrap();
+        write(
+            &root,
+            "pnpm-workspace.yaml",
+            "packages:\n  - 'packages/*'\n",
+        );
+        write(&root, "packages/app/package.json", r#"{ "name": "app", "#);
+        write(&root, "packages/app/src/pnpm-workspace.yaml", "packages: [");
+
+        let (workspace, skipped) = Workspace::detect(&root.join("packages/app/src")).unwrap();
+        assert_eq!(workspace.unwrap().root, root);
+        assert_eq!(skipped.len(), 2);
+        assert!(skipped[0].to_string().contains("Invalid YAML"));
+        assert!(skipped[1].to_string().contains("Invalid JSON"));
+        assert!(Workspace::load(&root.join("packages/app")).is_err());
+
+        write(&root, "pnpm-workspace.yaml", "packages: {");
+        let (workspace, skipped) = Workspace::detect(&root).unwrap();
+        assert!(workspace.is_none());
+        assert_eq!(skipped.len(), 1);
+    }
+}
@@ -5,6 +5,8 @@ use rigor::cache::AnalysisCache;
 use rigor::{Score, ScoreBreakdown, TestFramework, TestStats, TestType};
 use std::path::{Path, PathBuf};
 
+const CONFIG: &str = "config-hash";
+
 fn make_result(path: &str) -> rigor::AnalysisResult {
     rigor::AnalysisResult {
         file_path: PathBuf::from(path),
@@ -27,6 +29,7 @@ fn make_result(path: &str) -> rigor::AnalysisResult {
         framework: TestFramework::Jest,
         test_type: TestType::Unit,
         source_file: None,
+        package: None,
     }
 }
 
@@ -39,12 +42,14 @@ fn cache_cleanup_evicts_entries_not_in_existing_files() {
         Path::new("a.test.ts"),
         "content a",
         None,
+        CONFIG,
         make_result("a.test.ts"),
     );
     cache.set(
         Path::new("b.test.ts"),
         "content b",
         None,
+        CONFIG,
         make_result("b.test.ts"),
     );
     assert_eq!(cache.stats().entries, 2);
@@ -53,10 +58,10 @@ fn cache_cleanup_evicts_entries_not_in_existing_files() {
 
     assert_eq!(cache.stats().entries, 1);
     assert!(cache
-        .get(Path::new("a.test.ts"), "content a", None)
+        .get(Path::new("a.test.ts"), "content a", None, CONFIG)
         .is_some());
     assert!(cache
-        .get(Path::new("b.test.ts"), "content b", None)
+        .get(Path::new("b.test.ts"), "content b", None, CONFIG)
         .is_none());
 }
 
@@ -70,6 +75,7 @@ fn cache_save_and_reload_persists_entries() {
             Path::new("persist.test.ts"),
             "const x = 1;",
             None,
+            CONFIG,
             make_result("persist.test.ts"),
         );
         cache.save().unwrap();
@@ -77,7 +83,7 @@ fn cache_save_and_reload_persists_entries() {
 
     {
         let cache = AnalysisCache::new(dir.path());
-        let cached = cache.get(Path::new("persist.test.ts"), "const x = 1;", None);
+        let cached = cache.get(Path::new("persist.test.ts"), "const x = 1;", None, CONFIG);
         assert!(cached.is_some(), "cache should persist after save/load");
         assert_eq!(cached.unwrap().score.value, 85);
     }
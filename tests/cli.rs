@@ -220,3 +220,136 @@ fn quiet_flag_reduces_output() {
     // At minimum it should still report the score
     let _ = stdout; // Just verify it doesn't panic
 }
+
+#[test]
+fn nested_config_applies_to_files_under_it() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let root = dir.path();
+    let legacy = root.join("packages/legacy-app");
+    fs::create_dir(root.join(".git")).unwrap();
+    fs::create_dir_all(&legacy).unwrap();
+    fs::copy(WEAK_TEST, root.join("weak.test.ts")).unwrap();
+    fs::copy(WEAK_TEST, legacy.join("weak.test.ts")).unwrap();
+    fs::write(
+        root.join(".rigorrc.json"),
+        r#"{ "rules": { "weak-assertion": "error" } }"#,
+    )
+    .unwrap();
+    fs::write(
+        legacy.join(".rigorrc.json"),
+        r#"{ "rules": { "weak-assertion": "off" } }"#,
+    )
+    .unwrap();
+
+    let output = rigor_cmd()
+        .arg(root)
+        .arg("--json")
+        .arg("--no-cache")
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let has_weak_assertion = |suffix: &str| {
+        json["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|r| r["filePath"].as_str().unwrap().ends_with(suffix))
+            .unwrap()["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|issue| issue["rule"] == "weak-assertion")
+    };
+    let top = format!(
+        "{}/weak.test.ts",
+        root.file_name().unwrap().to_string_lossy()
+    );
+    assert!(has_weak_assertion(&top));
+    assert!(!has_weak_assertion("legacy-app/weak.test.ts"));
+}
+
+#[test]
+fn workspace_packages_filter_and_thresholds() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir(root.join(".git")).unwrap();
+    fs::write(
+        root.join("package.json"),
+        r#"{ "workspaces": ["packages/*"] }"#,
+    )
+    .unwrap();
+    for (name, test) in [("api", AUTH_TEST), ("legacy", WEAK_TEST)] {
+        let package = root.join("packages").join(name);
+        fs::create_dir_all(&package).unwrap();
+        fs::write(
+            package.join("package.json"),
+            format!(r#"{{ "name": "@acme/{}" }}"#, name),
+        )
+        .unwrap();
+        fs::copy(test, package.join("a.test.ts")).unwrap();
+    }
+    fs::write(
+        root.join("packages/legacy/.rigorrc.json"),
+        r#"{ "threshold": 99 }"#,
+    )
+    .unwrap();
+
+    let output = rigor_cmd()
+        .arg(root)
+        .args(["--json", "--no-cache"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "legacy is below its threshold"
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let packages = json["summary"]["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 2);
+    assert_eq!(packages[0]["name"], "@acme/api");
+    assert_eq!(packages[1]["threshold"], 99);
+    assert_eq!(packages[1]["passed"], false);
+    assert!(json["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|r| r["package"].as_str().unwrap().starts_with("@acme/")));
+
+    // Only the selected package is analyzed
+    let output = rigor_cmd()
+        .arg(root)
+        .args(["--json", "--no-cache", "--filter", "@acme/api"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["package"], "@acme/api");
+
+    rigor_cmd()
+        .arg(root)
+        .args(["--filter", "nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No workspace packages match --filter nope",
+        ));
+
+    // A malformed manifest is a warning, unless --filter needs the workspace
+    fs::write(root.join("package.json"), r#"{ "workspaces": ["#).unwrap();
+    let output = rigor_cmd()
+        .arg(root)
+        .args(["--json", "--no-cache"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Ignoring workspace manifest"));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["results"].as_array().unwrap().len(), 2);
+    assert!(json["summary"]["packages"].is_null());
+    rigor_cmd()
+        .arg(root)
+        .args(["--filter", "@acme/api"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid JSON in"));
+}
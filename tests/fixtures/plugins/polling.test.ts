@@ -0,0 +1,12 @@
+import { sleep } from './helpers';
+import { poll } from './poll';
+
+describe('poll', () => {
+  it('retries until the check passes', async () => {
+    let calls = 0;
+    const result = poll(() => ++calls === 3, { interval: 10 });
+    await sleep(50);
+    expect(await result).toBe(true);
+    expect(calls).toBe(3);
+  });
+});
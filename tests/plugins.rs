@@ -0,0 +1,51 @@
+//! WASM plugins: the example plugin run through the CLI on a fixture.
+#![cfg(feature = "plugins")]
+
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/plugins/polling.test.ts";
+
+#[test]
+fn example_plugin_reports_namespaced_issues() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rigor"))
+        .arg(FIXTURE)
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let lines: Vec<u64> = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|i| i["rule"] == "no-sleep/sleep-call")
+        .map(|i| i["location"]["line"].as_u64().unwrap())
+        .collect();
+    // The import and the call
+    assert_eq!(lines, vec![1, 8]);
+}
+
+#[test]
+fn plugin_rules_can_be_turned_off() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let plugin = std::fs::canonicalize("examples/plugins/no-sleep/no-sleep.wat").unwrap();
+    std::fs::write(
+        dir.path().join(".rigorrc.json"),
+        serde_json::json!({
+            "plugins": [{ "path": plugin, "name": "timers" }],
+            "rules": { "timers/sleep-call": "off" }
+        })
+        .to_string(),
+    )
+    .unwrap();
+    let test = dir.path().join("polling.test.ts");
+    std::fs::copy(FIXTURE, &test).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rigor"))
+        .arg(&test)
+        .arg("--json")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("sleep-call"), "{}", stdout);
+}
@@ -36,7 +36,9 @@ export function activate(context: { subscriptions: { push(item: { dispose(): voi
       { scheme: "file", pattern: "**/*.cy.{ts,tsx,js,jsx}" },
     ],
     synchronize: {
-      fileEvents: workspace.createFileSystemWatcher("**/.rigorrc.json"),
+      fileEvents: workspace.createFileSystemWatcher(
+        "**/{.rigorrc.json,.rigorrc.yaml,.rigorrc.yml,rigor.toml,package.json}"
+      ),
     },
   };
 